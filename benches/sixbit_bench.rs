@@ -15,6 +15,7 @@
 
 const SHORT_INPUT: &str = "HELLO WORLD";
 const MEDIUM_INPUT: &str = "THE QUICK BROWN FOX JUMPS OVER THE LAZY DOG";
+const FORTY_CHAR_INPUT: &str = "THE QUICK BROWN FOX JUMPS OVER THE LAZY.";
 const LONG_INPUT: &str = "SPHINX OF BLACK QUARTZ, JUDGE MY VOW! THE QUICK BROWN FOX JUMPS OVER THE LAZY DOG 1234567890";
 
 #[cfg(feature = "nightly")]
@@ -22,8 +23,32 @@ mod benches {
     extern crate test;
     use super::*;
     use dec_sixbit::{decode, decode_unchecked, encode, encode_unchecked};
+    #[cfg(feature = "with-struct")]
+    use dec_sixbit::{DecSixbit, SharedDecSixbit};
+    use std::alloc::{GlobalAlloc, Layout, System};
+    use std::sync::atomic::{AtomicUsize, Ordering};
     use test::Bencher;
 
+    /// Number of heap allocations made so far, so benchmarks can check allocation counts.
+    static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+    /// The system allocator, counting every allocation in `ALLOCATIONS`.
+    struct CountingAlloc;
+
+    unsafe impl GlobalAlloc for CountingAlloc {
+        unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+            ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+            System.alloc(layout)
+        }
+
+        unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+            System.dealloc(ptr, layout)
+        }
+    }
+
+    #[global_allocator]
+    static GLOBAL: CountingAlloc = CountingAlloc;
+
     #[bench]
     fn bench_string_clone_short(b: &mut Bencher) {
         b.iter(|| {
@@ -159,4 +184,143 @@ mod benches {
             decode_unchecked(&input, len)
         });
     }
+
+    #[cfg(feature = "with-struct")]
+    #[bench]
+    fn bench_sixbit_char_frequencies_long(b: &mut Bencher) {
+        let sixbit = DecSixbit::new(LONG_INPUT).unwrap();
+        b.iter(|| {
+            sixbit.char_frequencies()
+        });
+    }
+
+    #[cfg(feature = "with-struct")]
+    #[bench]
+    fn bench_string_char_frequencies_long(b: &mut Bencher) {
+        let sixbit = DecSixbit::new(LONG_INPUT).unwrap();
+        b.iter(|| {
+            let mut frequencies = [0usize; 64];
+            for c in sixbit.to_string().bytes() {
+                frequencies[(c - 32) as usize] += 1;
+            }
+            frequencies
+        });
+    }
+
+    #[cfg(feature = "with-struct")]
+    #[bench]
+    fn bench_sixbit_map_chars_in_place_long(b: &mut Bencher) {
+        let mut sixbit = DecSixbit::new(LONG_INPUT).unwrap();
+        b.iter(|| {
+            sixbit.map_chars_in_place(|c| if c.is_ascii_digit() { '#' } else { c }).unwrap();
+        });
+    }
+
+    #[cfg(feature = "with-struct")]
+    #[bench]
+    fn bench_string_map_chars_long(b: &mut Bencher) {
+        let sixbit = DecSixbit::new(LONG_INPUT).unwrap();
+        b.iter(|| {
+            let mapped: String = sixbit.to_string().chars().map(|c| if c.is_ascii_digit() { '#' } else { c }).collect();
+            DecSixbit::new(&mapped).unwrap()
+        });
+    }
+
+    /// Generates `count` pseudo-random 8-character SIXBIT strings with a fixed seed.
+    #[cfg(feature = "with-struct")]
+    fn random_keys(count: usize) -> Vec<String> {
+        let mut state: u64 = 0x2545_f491_4f6c_dd1d;
+        (0..count)
+            .map(|_| {
+                (0..8)
+                    .map(|_| {
+                        state ^= state << 13;
+                        state ^= state >> 7;
+                        state ^= state << 17;
+                        char::from((state % 64) as u8 + 32)
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    #[cfg(feature = "with-struct")]
+    #[bench]
+    fn bench_sixbit_sort_slice_million(b: &mut Bencher) {
+        let items: Vec<DecSixbit> = random_keys(1_000_000).iter().map(|s| DecSixbit::new(s).unwrap()).collect();
+        b.iter(|| {
+            let mut items = items.clone();
+            DecSixbit::sort_slice(&mut items);
+            items
+        });
+    }
+
+    #[cfg(feature = "with-struct")]
+    #[bench]
+    fn bench_string_decode_and_sort_million(b: &mut Bencher) {
+        let items: Vec<DecSixbit> = random_keys(1_000_000).iter().map(|s| DecSixbit::new(s).unwrap()).collect();
+        b.iter(|| {
+            let mut decoded: Vec<String> = items.iter().map(|s| s.to_string()).collect();
+            decoded.sort_unstable();
+            decoded
+        });
+    }
+
+    /// Allocations made by `f`, which on this benchmark's single thread are all its own.
+    #[cfg(feature = "with-struct")]
+    fn count_allocations<T>(f: impl FnOnce() -> T) -> (T, usize) {
+        let before = ALLOCATIONS.load(Ordering::Relaxed);
+        let result = f();
+        (result, ALLOCATIONS.load(Ordering::Relaxed) - before)
+    }
+
+    #[cfg(feature = "with-struct")]
+    #[bench]
+    fn bench_sixbit_new_short_values(b: &mut Bencher) {
+        let keys = random_keys(10_000);
+        let mut items = Vec::with_capacity(keys.len());
+        b.iter(|| {
+            items.clear();
+            let ((), allocations) = count_allocations(|| {
+                items.extend(keys.iter().map(|s| DecSixbit::new(s).unwrap()));
+            });
+            // Short values are stored inline
+            assert_eq!(allocations, 0);
+            items.len()
+        });
+    }
+
+    #[cfg(feature = "with-struct")]
+    #[bench]
+    fn bench_sixbit_encode_short_values(b: &mut Bencher) {
+        let keys = random_keys(10_000);
+        let mut items = Vec::with_capacity(keys.len());
+        b.iter(|| {
+            items.clear();
+            let ((), allocations) = count_allocations(|| {
+                items.extend(keys.iter().map(|s| encode(s).unwrap()));
+            });
+            // One allocation per value
+            assert_eq!(allocations, keys.len());
+            items.len()
+        });
+    }
+
+    #[cfg(feature = "with-struct")]
+    #[bench]
+    fn bench_sixbit_clone_forty_chars(b: &mut Bencher) {
+        let sixbit = DecSixbit::new(FORTY_CHAR_INPUT).unwrap();
+        b.iter(|| {
+            test::black_box(&sixbit).clone()
+        });
+    }
+
+    #[cfg(feature = "with-struct")]
+    #[bench]
+    fn bench_shared_sixbit_clone_forty_chars(b: &mut Bencher) {
+        let shared = SharedDecSixbit::new(FORTY_CHAR_INPUT).unwrap();
+        b.iter(|| {
+            test::black_box(&shared).clone()
+        });
+    }
 }
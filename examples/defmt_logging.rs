@@ -0,0 +1,35 @@
+//! Logs SIXBIT values with defmt.
+//!
+//! This only needs to compile on the host; on a device, a defmt transport such as `defmt-rtt` provides
+//! the global logger and the probe tooling decodes the frames. Build it with
+//! `cargo build --example defmt_logging --features defmt`.
+
+use dec_sixbit::{DecSixbit, DecSixbit6};
+
+/// A logger that discards every frame, standing in for a real transport on the host.
+#[defmt::global_logger]
+struct DiscardLogger;
+
+unsafe impl defmt::Logger for DiscardLogger {
+    fn acquire() {}
+
+    unsafe fn flush() {}
+
+    unsafe fn release() {}
+
+    unsafe fn write(_bytes: &[u8]) {}
+}
+
+defmt::timestamp!("{=u32}", 0);
+
+#[defmt::panic_handler]
+fn defmt_panic() -> ! {
+    core::panic!()
+}
+
+fn main() {
+    let device = DecSixbit::new("DSK").unwrap();
+    let name = DecSixbit6::new("LOGIN ").unwrap();
+    defmt::info!("mounted {} as {}", device, name);
+    defmt::info!("path {}", *DecSixbit::new("SYS:LOGIN.SAV").unwrap());
+}
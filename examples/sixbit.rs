@@ -0,0 +1,259 @@
+//! A command-line tool for packing, unpacking, and inspecting SIXBIT data from shell scripts.
+//!
+//! ```text
+//! cargo run --example sixbit -- encode [--hex|--raw] <TEXT|->
+//! cargo run --example sixbit -- decode --len N [--hex] <FILE|->
+//! cargo run --example sixbit -- inspect [--len N [--hex]] <TEXT|FILE|->
+//! ```
+//!
+//! `encode` prints the packed bytes as hex unless `--raw` is given. `decode` reads raw packed bytes, or
+//! hex digits with `--hex`. `inspect` prints the annotated bit layout of text, or of packed bytes when
+//! `--len` is given. `-` reads standard input, without its trailing newline for text. Invalid input
+//! exits with status 1 and the error message, and invalid arguments with status 2 and the usage.
+
+use dec_sixbit::DecSixbit;
+use std::fmt;
+use std::io::{self, Read, Write};
+use std::process::ExitCode;
+
+const USAGE: &str = "usage: sixbit encode [--hex|--raw] <TEXT|->
+       sixbit decode --len N [--hex] <FILE|->
+       sixbit inspect [--len N [--hex]] <TEXT|FILE|->";
+
+/// Why a command failed.
+#[derive(Debug)]
+enum CliError {
+    /// The arguments could not be parsed.
+    Usage(String),
+    /// The input was not valid SIXBIT data.
+    Sixbit(dec_sixbit::Error),
+    /// Reading the input or writing the output failed.
+    Io(io::Error),
+}
+
+impl fmt::Display for CliError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CliError::Usage(message) => write!(f, "{message}\n{USAGE}"),
+            CliError::Sixbit(error) => error.fmt(f),
+            CliError::Io(error) => error.fmt(f),
+        }
+    }
+}
+
+impl From<dec_sixbit::Error> for CliError {
+    fn from(error: dec_sixbit::Error) -> Self {
+        CliError::Sixbit(error)
+    }
+}
+
+impl From<io::Error> for CliError {
+    fn from(error: io::Error) -> Self {
+        CliError::Io(error)
+    }
+}
+
+/// The parsed options of a command.
+#[derive(Debug, Default)]
+struct Options {
+    hex: bool,
+    raw: bool,
+    len: Option<usize>,
+    input: Option<String>,
+}
+
+fn parse_options(args: &[String]) -> Result<Options, CliError> {
+    let mut options = Options::default();
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--hex" => options.hex = true,
+            "--raw" => options.raw = true,
+            "--len" => {
+                let value = args.next().ok_or_else(|| CliError::Usage("--len needs a value".into()))?;
+                let len = value.parse().map_err(|_| CliError::Usage(format!("invalid length: {value}")))?;
+                options.len = Some(len);
+            },
+            flag if flag.starts_with("--") => return Err(CliError::Usage(format!("unknown option: {flag}"))),
+            _ if options.input.is_some() => return Err(CliError::Usage(format!("unexpected argument: {arg}"))),
+            _ => options.input = Some(arg.clone()),
+        }
+    }
+    if options.input.is_none() {
+        return Err(CliError::Usage("missing input".into()));
+    }
+    Ok(options)
+}
+
+/// Returns the text argument, or standard input without its line ending for `-`.
+fn read_text(input: &str, stdin: &mut impl Read) -> Result<String, CliError> {
+    if input != "-" {
+        return Ok(input.to_string());
+    }
+    let mut text = String::new();
+    stdin.read_to_string(&mut text)?;
+    let trimmed = text.trim_end_matches(['\n', '\r']).len();
+    text.truncate(trimmed);
+    Ok(text)
+}
+
+/// Returns the packed bytes in the file, or standard input for `-`, parsing them as hex if `hex` is set.
+fn read_packed(input: &str, hex: bool, stdin: &mut impl Read) -> Result<Vec<u8>, CliError> {
+    let bytes = match input {
+        "-" => {
+            let mut bytes = Vec::new();
+            stdin.read_to_end(&mut bytes)?;
+            bytes
+        },
+        path => std::fs::read(path)?,
+    };
+    if hex {
+        parse_hex(&bytes)
+    } else {
+        Ok(bytes)
+    }
+}
+
+/// Parses pairs of hex digits, ignoring whitespace between them.
+fn parse_hex(text: &[u8]) -> Result<Vec<u8>, CliError> {
+    let digits: Vec<u8> = text.iter().copied().filter(|c| !c.is_ascii_whitespace()).collect();
+    let invalid = || CliError::Usage("input is not a sequence of hex byte pairs".into());
+    if digits.len() % 2 != 0 {
+        return Err(invalid());
+    }
+    digits
+        .chunks_exact(2)
+        .map(|pair| {
+            let pair = std::str::from_utf8(pair).map_err(|_| invalid())?;
+            u8::from_str_radix(pair, 16).map_err(|_| invalid())
+        })
+        .collect()
+}
+
+/// Runs the command in `args`, which excludes the program name.
+fn run(args: &[String], stdin: &mut impl Read, stdout: &mut impl Write) -> Result<(), CliError> {
+    let (command, rest) = args.split_first().ok_or_else(|| CliError::Usage("missing command".into()))?;
+    if !["encode", "decode", "inspect"].contains(&command.as_str()) {
+        return Err(CliError::Usage(format!("unknown command: {command}")));
+    }
+    let options = parse_options(rest)?;
+    let input = options.input.as_deref().unwrap_or_default();
+    match command.as_str() {
+        "encode" => {
+            if options.hex && options.raw || options.len.is_some() {
+                return Err(CliError::Usage("encode takes one of --hex or --raw".into()));
+            }
+            let (bytes, _) = dec_sixbit::encode(&read_text(input, stdin)?)?;
+            if options.raw {
+                stdout.write_all(&bytes)?;
+            } else {
+                let hex: String = bytes.iter().map(|byte| format!("{byte:02x}")).collect();
+                writeln!(stdout, "{hex}")?;
+            }
+        },
+        "decode" => {
+            let len = options.len.ok_or_else(|| CliError::Usage("decode needs --len".into()))?;
+            if options.raw {
+                return Err(CliError::Usage("decode does not take --raw".into()));
+            }
+            let bytes = read_packed(input, options.hex, stdin)?;
+            writeln!(stdout, "{}", dec_sixbit::decode(&bytes, len)?)?;
+        },
+        "inspect" => {
+            let sixbit = match options.len {
+                Some(len) => DecSixbit::from_parts_lenient(read_packed(input, options.hex, stdin)?, len)?,
+                None if options.hex => return Err(CliError::Usage("--hex needs --len".into())),
+                None => DecSixbit::new(&read_text(input, stdin)?)?,
+            };
+            writeln!(stdout, "{}", sixbit.dump())?;
+        },
+        _ => unreachable!(),
+    }
+    Ok(())
+}
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    match run(&args, &mut io::stdin().lock(), &mut io::stdout().lock()) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(error) => {
+            eprintln!("error: {error}");
+            match error {
+                CliError::Usage(_) => ExitCode::from(2),
+                _ => ExitCode::FAILURE,
+            }
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{run, CliError};
+
+    fn run_with(args: &[&str], stdin: &[u8]) -> Result<Vec<u8>, CliError> {
+        let args: Vec<String> = args.iter().map(|arg| arg.to_string()).collect();
+        let mut stdout = Vec::new();
+        run(&args, &mut &stdin[..], &mut stdout)?;
+        Ok(stdout)
+    }
+
+    fn run_ok(args: &[&str], stdin: &[u8]) -> String {
+        String::from_utf8(run_with(args, stdin).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn test_encode() {
+        assert_eq!(run_ok(&["encode", "HELLO"], b""), "a25b2cbc\n");
+        assert_eq!(run_ok(&["encode", "--hex", "-"], b"HELLO\n"), "a25b2cbc\n");
+        assert_eq!(run_with(&["encode", "--raw", "HELLO"], b"").unwrap(), [0xa2, 0x5b, 0x2c, 0xbc]);
+    }
+
+    #[test]
+    fn test_decode() {
+        assert_eq!(run_ok(&["decode", "--len", "5", "--hex", "-"], b"a2 5b\n2c bc\n"), "HELLO\n");
+        assert_eq!(run_ok(&["decode", "--len", "5", "-"], &[0xa2, 0x5b, 0x2c, 0xbc]), "HELLO\n");
+    }
+
+    #[test]
+    fn test_decode_file() {
+        let path = std::env::temp_dir().join(format!("sixbit-cli-{}.bin", std::process::id()));
+        std::fs::write(&path, [0xa2, 0x5b, 0x2c, 0xbc]).unwrap();
+        let output = run_ok(&["decode", "--len", "5", path.to_str().unwrap()], b"");
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(output, "HELLO\n");
+    }
+
+    #[test]
+    fn test_inspect() {
+        let expected = "bytes:  100001 10|0010 1000|11 100100\n\
+                        fields: 100001|10 0010|1000 11|100100\n\
+                        chars:    A       B      C       D\n";
+        assert_eq!(run_ok(&["inspect", "ABCD"], b""), expected);
+        assert_eq!(run_ok(&["inspect", "--len", "4", "--hex", "-"], b"8628e4"), expected);
+    }
+
+    #[test]
+    fn test_invalid_input() {
+        let error = run_with(&["encode", "lower"], b"").unwrap_err();
+        assert!(matches!(error, CliError::Sixbit(dec_sixbit::Error::InvalidCharacter)));
+        let error = run_with(&["decode", "--len", "4", "--hex", "-"], b"0000").unwrap_err();
+        assert_eq!(error.to_string(), dec_sixbit::Error::InvalidBytesLength.to_string());
+        assert!(matches!(run_with(&["decode", "--len", "1", "--hex", "-"], b"0"), Err(CliError::Usage(_))));
+    }
+
+    #[test]
+    fn test_usage_errors() {
+        for args in [
+            &[][..],
+            &["frobnicate", "x"],
+            &["encode"],
+            &["encode", "--hex", "--raw", "A"],
+            &["encode", "A", "B"],
+            &["decode", "-"],
+            &["decode", "--len", "x", "-"],
+            &["inspect", "--hex", "-"],
+        ] {
+            assert!(matches!(run_with(args, b""), Err(CliError::Usage(_))), "{args:?}");
+        }
+    }
+}
@@ -0,0 +1,3 @@
+#![no_main]
+
+libfuzzer_sys::fuzz_target!(|data: &[u8]| dec_sixbit_fuzz::harness::try_from_slice(data));
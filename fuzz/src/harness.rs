@@ -0,0 +1,109 @@
+//! The body of each fuzz target. Every function panics if it finds a bug and returns normally otherwise.
+
+use crate::reference;
+use dec_sixbit::{DecSixbit, Error, SixbitDecoder};
+
+/// Returns `bytes` with the unused bits after the last of `len` characters cleared.
+fn clear_padding(bytes: &[u8], len: usize) -> Vec<u8> {
+    let mut canonical = bytes.to_vec();
+    let used_bits = len * 6 % 8;
+    if let Some(last) = canonical.last_mut().filter(|_| used_bits != 0) {
+        *last &= 0xFF << (8 - used_bits);
+    }
+    canonical
+}
+
+/// Feeds arbitrary `(bytes, len)` pairs to the decoders. The first 8 bytes are the length, little-endian,
+/// so huge lengths whose bit count overflows are reached too.
+pub fn decode(data: &[u8]) {
+    let Some((len, bytes)) = data.split_first_chunk::<8>() else { return };
+    let Ok(len) = usize::try_from(u64::from_le_bytes(*len)) else { return };
+    let payload_len = len.checked_mul(6).map(|bits| bits.div_ceil(8));
+
+    let blocks = DecSixbit::from_blocks([bytes], len);
+    assert_eq!(blocks.is_ok(), payload_len.is_some_and(|payload_len| payload_len <= bytes.len()));
+    let streamed = decode_streaming(bytes, len);
+    assert_eq!(streamed.is_some(), payload_len.is_some());
+
+    let result = dec_sixbit::decode(bytes, len);
+    assert_eq!(result.is_ok(), payload_len == Some(bytes.len()));
+    let Ok(decoded) = result else {
+        assert_eq!(result, Err(Error::InvalidBytesLength));
+        return;
+    };
+    assert_eq!(decoded.len(), len);
+    assert!(decoded.chars().all(dec_sixbit::is_sixbit_char));
+
+    // Re-encoding reproduces the input, minus any padding bits
+    let canonical = clear_padding(bytes, len);
+    assert_eq!(dec_sixbit::encode(&decoded), Ok((canonical.clone(), len)));
+
+    let mut buf = vec![0; len];
+    assert_eq!(dec_sixbit::decode_to_slice(bytes, len, &mut buf), Ok(decoded.as_str()));
+    for (index, c) in decoded.chars().enumerate() {
+        assert_eq!(dec_sixbit::char_at(bytes, len, index), Ok(c));
+    }
+
+    // The lenient constructor normalizes, and the strict one only accepts what it would produce
+    let sixbit = DecSixbit::from_parts_lenient(bytes.to_vec(), len).unwrap();
+    assert_eq!(sixbit.to_string(), decoded);
+    assert_eq!(&sixbit.as_bytes()[..canonical.len()], &canonical[..]);
+    assert_eq!(DecSixbit::from_parts(bytes.to_vec(), len).is_ok(), sixbit.as_bytes() == bytes);
+    assert_eq!(blocks, Ok(sixbit));
+    assert_eq!(streamed.as_deref(), Some(decoded.as_str()));
+}
+
+/// Decodes `bytes` a byte at a time with `SixbitDecoder`, or returns `None` if it rejects `len`.
+fn decode_streaming(bytes: &[u8], len: usize) -> Option<String> {
+    let mut decoder = SixbitDecoder::new(len).ok()?;
+    let mut decoded = String::new();
+    for &byte in bytes {
+        let remaining = decoder.bytes_remaining();
+        match decoder.push(byte) {
+            Some((chars, count)) => decoded.extend(chars[..count].iter().map(|&c| c as char)),
+            None if remaining == 0 => assert!(decoder.is_finished()),
+            None => assert_eq!(decoder.bytes_remaining(), remaining - 1),
+        }
+    }
+    Some(decoded)
+}
+
+/// Feeds arbitrary bytes to `DecSixbit::try_from_slice`, which infers the length.
+pub fn try_from_slice(data: &[u8]) {
+    let strict = DecSixbit::try_from_slice_strict(data);
+    let Ok(sixbit) = DecSixbit::try_from_slice(data) else {
+        assert!(strict.is_err());
+        return;
+    };
+    let decoded = sixbit.to_string();
+    assert_eq!(decoded.len(), sixbit.len());
+    assert_eq!(DecSixbit::new(&decoded).as_ref(), Ok(&sixbit));
+    assert_eq!(DecSixbit::try_from_slice_strict(sixbit.as_bytes()).as_ref(), Ok(&sixbit));
+    if let Ok(strict) = strict {
+        assert_eq!(strict, sixbit);
+        assert_eq!(strict.as_bytes(), data);
+    }
+}
+
+/// Feeds arbitrary bytes to the serde deserializer through bincode.
+pub fn bincode(data: &[u8]) {
+    let Ok(sixbit) = bincode::deserialize::<DecSixbit>(data) else { return };
+    let decoded = sixbit.to_string();
+    assert_eq!(decoded.len(), sixbit.len());
+    assert_eq!(DecSixbit::new(&decoded).as_ref(), Ok(&sixbit));
+    let encoded = bincode::serialize(&sixbit).unwrap();
+    assert_eq!(bincode::deserialize::<DecSixbit>(&encoded).unwrap(), sixbit);
+}
+
+/// Compares `encode` with the reference encoder on arbitrary strings.
+pub fn encode_differential(data: &[u8]) {
+    let Ok(input) = std::str::from_utf8(data) else { return };
+    match (dec_sixbit::encode(input), reference::encode(input)) {
+        (Ok((bytes, len)), Some(expected)) => {
+            assert_eq!(bytes, expected);
+            assert_eq!(len, input.len());
+        },
+        (Err(error), None) => assert_eq!(error, Error::InvalidCharacter),
+        (actual, expected) => panic!("{input:?}: encode returned {actual:?}, reference {expected:?}"),
+    }
+}
@@ -0,0 +1,5 @@
+//! Fuzzing harnesses for `dec-sixbit`, shared by the libFuzzer targets in `fuzz_targets/` and by the
+//! smoke test in the main crate's `tests/fuzz_smoke.rs`.
+
+pub mod harness;
+pub mod reference;
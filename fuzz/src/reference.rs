@@ -0,0 +1,21 @@
+//! A deliberately naive SIXBIT encoder to check the optimized one against.
+
+/// Encodes `s` one bit at a time, or returns `None` if it has a character outside the range 32-95.
+pub fn encode(s: &str) -> Option<Vec<u8>> {
+    let mut bits = Vec::new();
+    for c in s.chars() {
+        let code = c as u32;
+        if !(32..=95).contains(&code) {
+            return None;
+        }
+        let value = code - 32;
+        for shift in (0..6).rev() {
+            bits.push((value >> shift) & 1 == 1);
+        }
+    }
+    let bytes = bits
+        .chunks(8)
+        .map(|chunk| chunk.iter().enumerate().fold(0u8, |byte, (i, &bit)| byte | (u8::from(bit) << (7 - i))))
+        .collect();
+    Some(bytes)
+}
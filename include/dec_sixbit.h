@@ -0,0 +1,56 @@
+#ifndef DEC_SIXBIT_H
+#define DEC_SIXBIT_H
+
+#include <stddef.h>
+#include <stdint.h>
+
+/**
+ * The call succeeded.
+ */
+#define DEC_SIXBIT_OK 0
+
+/**
+ * The input contains a character outside the range 32-95.
+ */
+#define DEC_SIXBIT_ERR_INVALID_CHARACTER -1
+
+/**
+ * The number of packed bytes is inconsistent with the number of characters.
+ */
+#define DEC_SIXBIT_ERR_INVALID_BYTES_LENGTH -2
+
+/**
+ * The output buffer is too small for the result.
+ */
+#define DEC_SIXBIT_ERR_BUFFER_TOO_SMALL -3
+
+/**
+ * A pointer that must not be null was null.
+ */
+#define DEC_SIXBIT_ERR_NULL_POINTER -4
+
+/**
+ * An unexpected internal error occurred.
+ */
+#define DEC_SIXBIT_ERR_INTERNAL -5
+
+/**
+ * Returns the number of packed bytes needed to encode `chars` characters.
+ */
+size_t dec_sixbit_encoded_len(size_t chars);
+
+/**
+ * Encodes the `s_len` characters at `s` into `out`, storing the number of bytes written in `*out_len`.
+ *
+ * `s` need not be NUL-terminated. `*out_len` is only written on success.
+ */
+int32_t dec_sixbit_encode(const char *s, size_t s_len, uint8_t *out, size_t out_cap, size_t *out_len);
+
+/**
+ * Decodes `char_len` characters from the `byte_len` packed bytes at `bytes` into `out`.
+ *
+ * Exactly `char_len` characters are written, without a NUL terminator.
+ */
+int32_t dec_sixbit_decode(const uint8_t *bytes, size_t byte_len, size_t char_len, char *out, size_t out_cap);
+
+#endif /* DEC_SIXBIT_H */
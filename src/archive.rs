@@ -0,0 +1,224 @@
+//! Zero-copy [rkyv](https://docs.rs/rkyv) support for `DecSixbit`.
+//!
+//! A `DecSixbit` archives as an [`ArchivedDecSixbit`], holding the length and the packed bytes. The
+//! archived value dereferences to a [`DecSixbitStr`], so it can be read, displayed, and compared
+//! against `DecSixbit` values without deserializing. Validation with `rkyv::access` rejects archives
+//! whose length and bytes are inconsistent.
+
+use crate::{bits, display, DecSixbit, DecSixbitStr, Error};
+use rkyv::bytecheck::{CheckBytes, Verify};
+use rkyv::munge::munge;
+use rkyv::rancor::{Fallible, Source};
+use rkyv::ser::{Allocator, Writer};
+use rkyv::vec::{ArchivedVec, VecResolver};
+use rkyv::{Archive, Archived, Deserialize, Place, Portable, Serialize};
+use std::cmp::Ordering;
+use std::fmt;
+use std::ops::Deref;
+
+/// The archived form of a [`DecSixbit`].
+///
+/// # Examples
+///
+/// ```rust
+/// use dec_sixbit::{ArchivedDecSixbit, DecSixbit};
+///
+/// let values = vec![DecSixbit::new("HELLO").unwrap(), DecSixbit::new("WORLD").unwrap()];
+/// let bytes = rkyv::to_bytes::<rkyv::rancor::Error>(&values).unwrap();
+///
+/// let archived = rkyv::access::<rkyv::Archived<Vec<DecSixbit>>, rkyv::rancor::Error>(&bytes).unwrap();
+/// assert_eq!(archived[0].len(), 5);
+/// assert_eq!(archived[1].to_string(), "WORLD");
+/// assert_eq!(archived[0], values[0]);
+/// ```
+#[derive(Portable, CheckBytes)]
+#[bytecheck(crate = rkyv::bytecheck, verify)]
+#[repr(C)]
+pub struct ArchivedDecSixbit {
+    len: Archived<u64>,
+    bytes: ArchivedVec<u8>,
+}
+
+impl ArchivedDecSixbit {
+    /// Returns the archived value as a `DecSixbitStr`.
+    #[inline]
+    pub fn as_sixbit_str(&self) -> &DecSixbitStr {
+        // The view infers its length from the bytes, so even an unvalidated archive reads in bounds
+        DecSixbitStr::from_bytes_unchecked(self.bytes.as_slice())
+    }
+}
+
+// SAFETY: `verify` only checks that the already validated length and bytes agree.
+unsafe impl<C> Verify<C> for ArchivedDecSixbit
+where
+    C: Fallible + ?Sized,
+    C::Error: Source,
+{
+    fn verify(&self, _context: &mut C) -> Result<(), C::Error> {
+        let consistent = usize::try_from(self.len.to_native())
+            .is_ok_and(|len| bits::is_canonical(self.bytes.as_slice(), len));
+        if consistent {
+            Ok(())
+        } else {
+            Err(C::Error::new(Error::InvalidBytesLength))
+        }
+    }
+}
+
+impl Archive for DecSixbit {
+    type Archived = ArchivedDecSixbit;
+    type Resolver = VecResolver;
+
+    fn resolve(&self, resolver: Self::Resolver, out: Place<Self::Archived>) {
+        munge!(let ArchivedDecSixbit { len, bytes } = out);
+        (self.len() as u64).resolve((), len);
+        ArchivedVec::resolve_from_slice(self.as_bytes(), resolver, bytes);
+    }
+}
+
+impl<S: Fallible + Allocator + Writer + ?Sized> Serialize<S> for DecSixbit {
+    fn serialize(&self, serializer: &mut S) -> Result<Self::Resolver, S::Error> {
+        ArchivedVec::<u8>::serialize_from_slice(self.as_bytes(), serializer)
+    }
+}
+
+impl<D: Fallible + ?Sized> Deserialize<DecSixbit, D> for ArchivedDecSixbit {
+    fn deserialize(&self, _deserializer: &mut D) -> Result<DecSixbit, D::Error> {
+        Ok(self.as_sixbit_str().to_owned())
+    }
+}
+
+impl Deref for ArchivedDecSixbit {
+    type Target = DecSixbitStr;
+
+    #[inline]
+    fn deref(&self) -> &DecSixbitStr {
+        self.as_sixbit_str()
+    }
+}
+
+impl AsRef<DecSixbitStr> for ArchivedDecSixbit {
+    #[inline]
+    fn as_ref(&self) -> &DecSixbitStr {
+        self.as_sixbit_str()
+    }
+}
+
+impl PartialEq for ArchivedDecSixbit {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.as_sixbit_str() == other.as_sixbit_str()
+    }
+}
+
+impl Eq for ArchivedDecSixbit {}
+
+impl PartialEq<DecSixbit> for ArchivedDecSixbit {
+    #[inline]
+    fn eq(&self, other: &DecSixbit) -> bool {
+        self.as_sixbit_str() == other.as_sixbit_str()
+    }
+}
+
+impl PartialEq<ArchivedDecSixbit> for DecSixbit {
+    #[inline]
+    fn eq(&self, other: &ArchivedDecSixbit) -> bool {
+        self.as_sixbit_str() == other.as_sixbit_str()
+    }
+}
+
+impl PartialOrd<DecSixbit> for ArchivedDecSixbit {
+    #[inline]
+    fn partial_cmp(&self, other: &DecSixbit) -> Option<Ordering> {
+        Some(self.as_sixbit_str().cmp(other.as_sixbit_str()))
+    }
+}
+
+impl PartialOrd<ArchivedDecSixbit> for DecSixbit {
+    #[inline]
+    fn partial_cmp(&self, other: &ArchivedDecSixbit) -> Option<Ordering> {
+        Some(self.as_sixbit_str().cmp(other.as_sixbit_str()))
+    }
+}
+
+impl fmt::Debug for ArchivedDecSixbit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        display::debug_packed(f, "ArchivedDecSixbit", self.as_bytes(), self.len())
+    }
+}
+
+impl fmt::Display for ArchivedDecSixbit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self.as_sixbit_str(), f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ArchivedDecSixbit;
+    use crate::test_fixtures::values;
+    use crate::DecSixbit;
+    use rkyv::rancor::Error;
+    use rkyv::Archived;
+
+    #[test]
+    fn test_access_zero_copy() {
+        let values = values();
+        let bytes = rkyv::to_bytes::<Error>(&values).unwrap();
+        let archived = rkyv::access::<Archived<Vec<DecSixbit>>, Error>(&bytes).unwrap();
+        assert_eq!(archived.len(), values.len());
+        for (archived, value) in archived.iter().zip(&values) {
+            assert_eq!(archived, value);
+            assert_eq!(value, archived);
+            assert_eq!(archived.len(), value.len());
+            assert_eq!(archived.as_bytes(), value.as_bytes());
+            assert_eq!(archived.to_string(), value.to_string());
+            assert_eq!(format!("{:>50}", archived), format!("{:>50}", value));
+            assert_eq!(format!("{:?}", archived).replacen("ArchivedDecSixbit", "DecSixbit", 1), format!("{:?}", value));
+            // The archived bytes live in the archive buffer, not in a copy
+            assert!(bytes.as_ptr_range().contains(&archived.as_bytes().as_ptr()) || value.is_empty());
+        }
+    }
+
+    #[test]
+    fn test_ordering_matches_owned() {
+        let values = values();
+        let bytes = rkyv::to_bytes::<Error>(&values).unwrap();
+        let archived = rkyv::access::<Archived<Vec<DecSixbit>>, Error>(&bytes).unwrap();
+        for a in archived.iter() {
+            for b in &values {
+                let expected = a.as_sixbit_str().cmp(b.as_sixbit_str());
+                assert_eq!(a.partial_cmp(b), Some(expected));
+                assert_eq!(b.partial_cmp(a), Some(expected.reverse()));
+            }
+        }
+    }
+
+    #[test]
+    fn test_deserialize() {
+        let values = values();
+        let bytes = rkyv::to_bytes::<Error>(&values).unwrap();
+        assert_eq!(rkyv::from_bytes::<Vec<DecSixbit>, Error>(&bytes).unwrap(), values);
+    }
+
+    #[test]
+    fn test_corrupted_archive_fails_validation() {
+        let value = DecSixbit::new("HELLO").unwrap();
+        let mut bytes = rkyv::to_bytes::<Error>(&value).unwrap();
+        assert!(rkyv::access::<ArchivedDecSixbit, Error>(&bytes).is_ok());
+
+        // The root value sits at the end of the buffer, starting with its little-endian length
+        let root = bytes.len() - std::mem::size_of::<ArchivedDecSixbit>();
+        for len in [0u64, 4, 6, 7, u64::MAX] {
+            bytes[root..root + 8].copy_from_slice(&len.to_le_bytes());
+            assert!(rkyv::access::<ArchivedDecSixbit, Error>(&bytes).is_err(), "len={len}");
+        }
+        bytes[root..root + 8].copy_from_slice(&5u64.to_le_bytes());
+        assert!(rkyv::access::<ArchivedDecSixbit, Error>(&bytes).is_ok());
+
+        // Setting a padding bit in the last packed byte
+        let start = bytes.windows(4).position(|window| window == value.as_bytes()).unwrap();
+        bytes[start + 3] |= 1;
+        assert!(rkyv::access::<ArchivedDecSixbit, Error>(&bytes).is_err());
+    }
+}
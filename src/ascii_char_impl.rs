@@ -0,0 +1,136 @@
+//! Support for the unstable [`core::ascii::Char`] type, behind the `nightly` feature.
+//!
+//! `ascii::Char` carries the guarantee that a character is ASCII in its type, so input given as
+//! `&[ascii::Char]` only needs checking against the SIXBIT window of 32-95, and decoded output can be
+//! handed out as `ascii::Char` without going through UTF-8 at all. This needs a nightly toolchain.
+
+use crate::encode::encode_ascii_into;
+use crate::{bits, Error, ASCII_OFFSET};
+#[cfg(feature = "with-struct")]
+use crate::DecSixbitStr;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::ascii::Char as AsciiChar;
+
+/// Encodes ASCII characters like [`encode`](crate::encode), skipping the check that they are ASCII.
+///
+/// # Parameters
+/// - `ascii`: The characters to encode. Must all be in the range 32-95.
+///
+/// # Errors
+/// Returns an [`Error::InvalidCharacter`] if the input contains characters outside the valid range.
+///
+/// # Examples
+///
+/// ```rust
+/// #![feature(ascii_char)]
+/// use dec_sixbit::{encode, encode_from_ascii};
+///
+/// let ascii = "HELLO".as_ascii().unwrap();
+/// assert_eq!(encode_from_ascii(ascii), encode("HELLO"));
+/// ```
+pub fn encode_from_ascii(ascii: &[AsciiChar]) -> Result<(Vec<u8>, usize), Error> {
+    let len = ascii.len();
+    let mut bytes = vec![0u8; bits::bytes_for_chars(len)];
+    encode_ascii_into(ascii.as_bytes(), &mut bytes)?;
+    Ok((bytes, len))
+}
+
+/// Decodes SIXBIT bytes like [`decode`](crate::decode), into `ascii::Char` values.
+///
+/// # Parameters
+/// - `bytes`: A slice of bytes containing SIXBIT-encoded data.
+/// - `len`: The length of the original string.
+///
+/// # Errors
+/// Returns an [`Error::InvalidBytesLength`] if `bytes.len()` and `len` are inconsistent.
+///
+/// # Examples
+///
+/// ```rust
+/// #![feature(ascii_char)]
+/// use dec_sixbit::{decode_to_ascii, encode};
+///
+/// let (bytes, len) = encode("HELLO").unwrap();
+/// assert_eq!(decode_to_ascii(&bytes, len).unwrap().as_str(), "HELLO");
+/// ```
+pub fn decode_to_ascii(bytes: &[u8], len: usize) -> Result<Vec<AsciiChar>, Error> {
+    if bits::checked_bytes_for_chars(len) != Some(bytes.len()) {
+        return Err(Error::InvalidBytesLength);
+    }
+    let mut decoded = Vec::with_capacity(len);
+    bits::for_each_value(bytes, len, |value| decoded.push(ascii_char(value)));
+    Ok(decoded)
+}
+
+/// Returns the ASCII character of a 6-bit SIXBIT value.
+#[inline(always)]
+fn ascii_char(value: u8) -> AsciiChar {
+    // SAFETY: 6-bit values map to the printable characters 32-95
+    unsafe { AsciiChar::from_u8_unchecked(value + ASCII_OFFSET) }
+}
+
+#[cfg(feature = "with-struct")]
+impl DecSixbitStr {
+    /// Returns an iterator over the characters as `ascii::Char` values.
+    ///
+    /// This method requires the `nightly` feature and a nightly toolchain.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// #![feature(ascii_char)]
+    /// use dec_sixbit::DecSixbit;
+    ///
+    /// let sixbit = DecSixbit::new("DSK:").unwrap();
+    /// let ascii: Vec<_> = sixbit.as_ascii_chars().collect();
+    /// assert_eq!(ascii.as_str(), "DSK:");
+    /// ```
+    #[inline]
+    pub fn as_ascii_chars(&self) -> impl DoubleEndedIterator<Item = AsciiChar> + ExactSizeIterator + '_ {
+        self.sixbit_values().map(ascii_char)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{decode_to_ascii, encode_from_ascii};
+    use crate::test_fixtures::INPUTS;
+    use crate::{decode, encode, Error};
+    use core::ascii::Char as AsciiChar;
+
+    #[test]
+    fn test_matches_stable_api() {
+        for input in INPUTS {
+            let ascii = input.as_ascii().unwrap();
+            let (bytes, len) = encode(input).unwrap();
+            assert_eq!(encode_from_ascii(ascii), Ok((bytes.clone(), len)), "{input:?}");
+            let decoded = decode_to_ascii(&bytes, len).unwrap();
+            assert_eq!(decoded.as_str(), decode(&bytes, len).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_errors() {
+        assert_eq!(encode_from_ascii("lower".as_ascii().unwrap()), Err(Error::InvalidCharacter));
+        assert_eq!(encode_from_ascii(&[AsciiChar::from_u8(0).unwrap()]), Err(Error::InvalidCharacter));
+        assert_eq!(encode_from_ascii(&[AsciiChar::from_u8(0x7F).unwrap()]), Err(Error::InvalidCharacter));
+        assert_eq!(decode_to_ascii(&[0; 3], 5), Err(Error::InvalidBytesLength));
+        // The bit count of this length wraps around to 2, which would otherwise fit in one byte
+        assert_eq!(decode_to_ascii(&[0], usize::MAX / 6 + 1), Err(Error::InvalidBytesLength));
+    }
+
+    #[cfg(feature = "with-struct")]
+    #[test]
+    fn test_as_ascii_chars() {
+        use crate::DecSixbit;
+
+        for input in INPUTS {
+            let sixbit = DecSixbit::new(input).unwrap();
+            let ascii: Vec<AsciiChar> = sixbit.as_ascii_chars().collect();
+            assert_eq!(ascii.as_str(), input);
+            assert_eq!(sixbit.as_ascii_chars().len(), input.len());
+            assert!(sixbit.as_ascii_chars().rev().map(AsciiChar::to_char).eq(input.chars().rev()));
+        }
+    }
+}
@@ -0,0 +1,128 @@
+//! Interop with the [ascii](https://docs.rs/ascii) crate's `AsciiStr` and `AsciiString`.
+//!
+//! Input that is already known to be ASCII only needs its characters checked against the SIXBIT
+//! range, and decoded output is ASCII by construction, so neither direction goes through UTF-8
+//! validation.
+
+use crate::decode::decode_into;
+use crate::encode::encode_ascii_into;
+use crate::{bits, DecSixbit, DecSixbitStr, Error};
+use ascii::{AsciiStr, AsciiString};
+use std::convert::TryFrom;
+
+/// Encodes an ASCII string like [`encode`](crate::encode), skipping the check that it is ASCII.
+///
+/// # Parameters
+/// - `ascii`: The input string to encode. Must contain only characters in the range 32-95.
+///
+/// # Errors
+/// Returns an [`Error::InvalidCharacter`] if the input contains characters outside the valid range.
+///
+/// # Examples
+///
+/// ```rust
+/// use ascii::AsciiStr;
+/// use dec_sixbit::{encode, encode_ascii};
+///
+/// let ascii = AsciiStr::from_ascii("HELLO").unwrap();
+/// assert_eq!(encode_ascii(ascii), encode("HELLO"));
+/// ```
+pub fn encode_ascii(ascii: &AsciiStr) -> Result<(Vec<u8>, usize), Error> {
+    let len = ascii.len();
+    let mut bytes = vec![0u8; (len * 3).div_ceil(4)];
+    encode_ascii_into(ascii.as_bytes(), &mut bytes)?;
+    Ok((bytes, len))
+}
+
+/// Decodes SIXBIT bytes like [`decode`](crate::decode), into an `AsciiString`.
+///
+/// # Parameters
+/// - `bytes`: A slice of bytes containing SIXBIT-encoded data.
+/// - `len`: The length of the original string.
+///
+/// # Errors
+/// Returns an [`Error::InvalidBytesLength`] if `bytes.len()` and `len` are inconsistent.
+///
+/// # Examples
+///
+/// ```rust
+/// use dec_sixbit::{decode_to_ascii_string, encode};
+///
+/// let (bytes, len) = encode("HELLO").unwrap();
+/// assert_eq!(decode_to_ascii_string(&bytes, len).unwrap(), "HELLO");
+/// ```
+pub fn decode_to_ascii_string(bytes: &[u8], len: usize) -> Result<AsciiString, Error> {
+    if bits::checked_bytes_for_chars(len) != Some(bytes.len()) {
+        return Err(Error::InvalidBytesLength);
+    }
+    Ok(decode_ascii(bytes, len))
+}
+
+/// Decodes the first `len` characters of `bytes` into an `AsciiString`.
+fn decode_ascii(bytes: &[u8], len: usize) -> AsciiString {
+    let mut result = vec![0u8; len];
+    decode_into(bytes, len, &mut result);
+    // SAFETY: Each byte of result is a printable ASCII character
+    unsafe { AsciiString::from_ascii_unchecked(result) }
+}
+
+impl TryFrom<&AsciiStr> for DecSixbit {
+    type Error = Error;
+
+    /// Encodes the string, returning an [`Error::InvalidCharacter`] if it contains characters outside
+    /// the SIXBIT range.
+    fn try_from(ascii: &AsciiStr) -> Result<Self, Error> {
+        let (bytes, len) = encode_ascii(ascii)?;
+        Ok(Self::from_encoded(bytes, len))
+    }
+}
+
+impl From<&DecSixbitStr> for AsciiString {
+    fn from(sixbit: &DecSixbitStr) -> Self {
+        decode_ascii(sixbit.as_bytes(), sixbit.len())
+    }
+}
+
+impl From<&DecSixbit> for AsciiString {
+    fn from(sixbit: &DecSixbit) -> Self {
+        Self::from(sixbit.as_sixbit_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{decode_to_ascii_string, encode_ascii};
+    use crate::{decode, encode, DecSixbit, Error};
+    use ascii::{AsciiStr, AsciiString};
+    use std::convert::TryFrom;
+
+    #[test]
+    fn test_round_trip() {
+        for input in ["", "A", "AB", "ABC", "ABC ", "    ", "DSK:FOO.BAR[1,2]", "THE QUICK BROWN FOX JUMPS OVER THE LAZY DOG"] {
+            let ascii = AsciiStr::from_ascii(input).unwrap();
+            let (bytes, len) = encode_ascii(ascii).unwrap();
+            assert_eq!((bytes.clone(), len), encode(input).unwrap());
+            let decoded = decode_to_ascii_string(&bytes, len).unwrap();
+            assert_eq!(decoded, input);
+            assert_eq!(decoded.as_str(), decode(&bytes, len).unwrap());
+
+            let sixbit = DecSixbit::try_from(ascii).unwrap();
+            assert_eq!(sixbit, DecSixbit::new(input).unwrap());
+            assert_eq!(sixbit.as_bytes(), DecSixbit::new(input).unwrap().as_bytes());
+            assert_eq!(AsciiString::from(&sixbit), input);
+            assert_eq!(AsciiString::from(sixbit.as_sixbit_str()), input);
+        }
+    }
+
+    #[test]
+    fn test_errors() {
+        for input in ["abc", "ABc", "TAB\t", "~", "`"] {
+            let ascii = AsciiStr::from_ascii(input).unwrap();
+            assert_eq!(encode_ascii(ascii), Err(Error::InvalidCharacter), "{input:?}");
+            assert_eq!(DecSixbit::try_from(ascii), Err(Error::InvalidCharacter));
+            assert_eq!(encode(input), Err(Error::InvalidCharacter));
+        }
+        assert_eq!(decode_to_ascii_string(&[0x86], 2), Err(Error::InvalidBytesLength));
+        assert_eq!(decode_to_ascii_string(&[0x80], usize::MAX / 6 + 1), Err(Error::InvalidBytesLength));
+    }
+}
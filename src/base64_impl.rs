@@ -0,0 +1,205 @@
+//! Base64 conversions of the packed payload with the [base64](https://docs.rs/base64) crate.
+//!
+//! The base64 text always holds the payload as [`encode`](crate::encode) returns it, without the
+//! trailing-space marker, so it only round-trips together with the character count. The standard
+//! variants use the standard alphabet with `=` padding; the URL-safe variants use the URL-safe alphabet
+//! without padding, so the text can go in a URL as is.
+
+use crate::decode::decode_into;
+use crate::encode::encode_into;
+use crate::{bits, DecSixbit, DecSixbitStr, Error};
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+use base64::engine::general_purpose::{STANDARD, URL_SAFE_NO_PAD};
+use base64::engine::GeneralPurpose;
+use base64::Engine;
+
+/// Encodes a string and returns the packed bytes as standard base64, along with the character count.
+///
+/// # Parameters
+/// - `str`: The input string to encode. Must contain only ASCII characters in the range 32-95.
+///
+/// # Errors
+/// Returns an [`Error::InvalidCharacter`] if the input contains characters outside the valid range.
+///
+/// # Examples
+///
+/// ```rust
+/// use dec_sixbit::{decode_base64, encode_base64};
+///
+/// let (text, len) = encode_base64("HELLO").unwrap();
+/// assert_eq!(text, "olssvA==");
+/// assert_eq!(decode_base64(&text, len).unwrap(), "HELLO");
+/// ```
+pub fn encode_base64(str: &str) -> Result<(String, usize), Error> {
+    encode_with(&STANDARD, str)
+}
+
+/// Encodes a string like [`encode_base64`], as URL-safe base64 without padding.
+///
+/// # Errors
+/// Returns an [`Error::InvalidCharacter`] if the input contains characters outside the valid range.
+pub fn encode_base64_url(str: &str) -> Result<(String, usize), Error> {
+    encode_with(&URL_SAFE_NO_PAD, str)
+}
+
+/// Decodes standard base64 text holding packed bytes into the original string.
+///
+/// # Parameters
+/// - `text`: The base64 text, as returned by [`encode_base64`].
+/// - `len`: The length of the original string.
+///
+/// # Errors
+/// - Returns an [`Error::InvalidBase64`] if `text` is not valid standard base64.
+/// - Returns an [`Error::InvalidBytesLength`] if the number of decoded bytes and `len` are inconsistent.
+pub fn decode_base64(text: &str, len: usize) -> Result<String, Error> {
+    decode_with(&STANDARD, text, len)
+}
+
+/// Decodes URL-safe base64 text without padding like [`decode_base64`].
+///
+/// # Errors
+/// - Returns an [`Error::InvalidBase64`] if `text` is not valid unpadded URL-safe base64.
+/// - Returns an [`Error::InvalidBytesLength`] if the number of decoded bytes and `len` are inconsistent.
+pub fn decode_base64_url(text: &str, len: usize) -> Result<String, Error> {
+    decode_with(&URL_SAFE_NO_PAD, text, len)
+}
+
+fn encode_with(engine: &GeneralPurpose, str: &str) -> Result<(String, usize), Error> {
+    let mut bytes = vec![0; bits::bytes_for_chars(str.len())];
+    encode_into(str, &mut bytes)?;
+    Ok((engine.encode(bytes), str.len()))
+}
+
+fn decode_with(engine: &GeneralPurpose, text: &str, len: usize) -> Result<String, Error> {
+    let bytes = payload_with(engine, text, len)?;
+    let mut decoded = vec![0; len];
+    decode_into(&bytes, len, &mut decoded);
+    // SAFETY: decoded SIXBIT characters are always ASCII
+    Ok(unsafe { String::from_utf8_unchecked(decoded) })
+}
+
+/// Decodes base64 text into a payload of exactly the size `len` characters need.
+fn payload_with(engine: &GeneralPurpose, text: &str, len: usize) -> Result<Vec<u8>, Error> {
+    let bytes = engine.decode(text).map_err(|_| Error::InvalidBase64)?;
+    if bits::checked_bytes_for_chars(len) != Some(bytes.len()) {
+        return Err(Error::InvalidBytesLength);
+    }
+    Ok(bytes)
+}
+
+impl DecSixbitStr {
+    /// Returns the packed payload, without the trailing-space marker, as standard base64.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use dec_sixbit::DecSixbit;
+    ///
+    /// let sixbit = DecSixbit::new("HELLO").unwrap();
+    /// assert_eq!(sixbit.to_base64(), "olssvA==");
+    /// assert_eq!(DecSixbit::from_base64(&sixbit.to_base64(), 5), Ok(sixbit));
+    /// ```
+    pub fn to_base64(&self) -> String {
+        STANDARD.encode(self.payload())
+    }
+
+    /// Returns the packed payload, without the trailing-space marker, as URL-safe base64 without padding.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use dec_sixbit::DecSixbit;
+    ///
+    /// let sixbit = DecSixbit::new("HELLO").unwrap();
+    /// assert_eq!(sixbit.to_base64_url(), "olssvA");
+    /// ```
+    pub fn to_base64_url(&self) -> String {
+        URL_SAFE_NO_PAD.encode(self.payload())
+    }
+}
+
+impl DecSixbit {
+    /// Creates a `DecSixbit` from the standard base64 text written by [`DecSixbitStr::to_base64`].
+    ///
+    /// # Parameters
+    /// - `text`: The base64 text of the packed payload.
+    /// - `len`: The number of characters encoded in the payload.
+    ///
+    /// # Errors
+    /// - Returns an [`Error::InvalidBase64`] if `text` is not valid standard base64.
+    /// - Returns an [`Error::InvalidBytesLength`] if the number of decoded bytes and `len` are inconsistent.
+    /// - Returns an [`Error::NonzeroPadding`] if the unused bits after the last character are set.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use dec_sixbit::{DecSixbit, Error};
+    ///
+    /// assert_eq!(DecSixbit::from_base64("olssvA==", 5).unwrap().to_string(), "HELLO");
+    /// assert_eq!(DecSixbit::from_base64("olssvA==", 6), Err(Error::InvalidBytesLength));
+    /// assert_eq!(DecSixbit::from_base64("olssvA", 5), Err(Error::InvalidBase64));
+    /// ```
+    pub fn from_base64(text: &str, len: usize) -> Result<Self, Error> {
+        Self::from_payload(payload_with(&STANDARD, text, len)?, len)
+    }
+
+    /// Creates a `DecSixbit` from the URL-safe base64 text written by [`DecSixbitStr::to_base64_url`].
+    ///
+    /// # Errors
+    /// - Returns an [`Error::InvalidBase64`] if `text` is not valid unpadded URL-safe base64.
+    /// - Returns an [`Error::InvalidBytesLength`] if the number of decoded bytes and `len` are inconsistent.
+    /// - Returns an [`Error::NonzeroPadding`] if the unused bits after the last character are set.
+    pub fn from_base64_url(text: &str, len: usize) -> Result<Self, Error> {
+        Self::from_payload(payload_with(&URL_SAFE_NO_PAD, text, len)?, len)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{decode_base64, decode_base64_url, encode_base64, encode_base64_url};
+    use crate::test_fixtures::{assert_round_trips, INPUTS};
+    use crate::{DecSixbit, Error};
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trips(|sixbit| DecSixbit::from_base64(&sixbit.to_base64(), sixbit.len()));
+        assert_round_trips(|sixbit| DecSixbit::from_base64_url(&sixbit.to_base64_url(), sixbit.len()));
+        for input in INPUTS {
+            let sixbit = DecSixbit::new(input).unwrap();
+            let (text, len) = encode_base64(input).unwrap();
+            assert_eq!((text.as_str(), len), (sixbit.to_base64().as_str(), input.len()));
+            assert_eq!(decode_base64(&text, len).unwrap(), input);
+            let (text, len) = encode_base64_url(input).unwrap();
+            assert_eq!(text, sixbit.to_base64_url());
+            assert_eq!(decode_base64_url(&text, len).unwrap(), input);
+        }
+        assert_eq!(DecSixbit::new("").unwrap().to_base64(), "");
+        assert_eq!(DecSixbit::from_base64("", 0), Ok(DecSixbit::new("").unwrap()));
+    }
+
+    #[test]
+    fn test_payload_excludes_marker() {
+        let sixbit = DecSixbit::new("TEST    ").unwrap();
+        assert_eq!(sixbit.as_bytes().last(), Some(&DecSixbit::TRAILING_SPACE_MARKER));
+        let (payload, _) = crate::encode("TEST    ").unwrap();
+        assert_eq!(payload.len(), 6);
+        assert_eq!(sixbit.to_base64(), "0lz0AAAA");
+        assert_eq!(sixbit.to_base64(), encode_base64("TEST    ").unwrap().0);
+    }
+
+    #[test]
+    fn test_invalid_text() {
+        let text = DecSixbit::new("HELLO").unwrap().to_base64();
+        assert_eq!(DecSixbit::from_base64(&text.replace('s', "!"), 5), Err(Error::InvalidBase64));
+        assert_eq!(DecSixbit::from_base64(&text[..4], 5), Err(Error::InvalidBytesLength));
+        assert_eq!(DecSixbit::from_base64_url(&text, 5), Err(Error::InvalidBase64));
+        // "HELLO" plus one set padding bit
+        assert_eq!(DecSixbit::from_base64("olssvQ==", 5), Err(Error::NonzeroPadding));
+        assert_eq!(decode_base64("olssvA==", 4), Err(Error::InvalidBytesLength));
+        // The bit count of this length wraps around to 2, which would otherwise fit in one byte
+        assert_eq!(decode_base64("AA==", usize::MAX / 6 + 1), Err(Error::InvalidBytesLength));
+        assert_eq!(encode_base64("lower"), Err(Error::InvalidCharacter));
+    }
+}
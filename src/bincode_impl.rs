@@ -0,0 +1,122 @@
+//! Native [bincode 2](https://docs.rs/bincode/2) `Encode` and `Decode` support for `DecSixbit`.
+//!
+//! The layout matches the serde binary format: the length in characters as a `u64`, followed by the
+//! packed bytes as a byte slice. Values encoded either way decode the same with a given configuration.
+
+use crate::{DecSixbit, Error};
+use bincode2::de::read::Reader;
+use bincode2::de::Decoder;
+use bincode2::enc::Encoder;
+use bincode2::error::{DecodeError, EncodeError};
+use bincode2::{Decode, Encode};
+
+/// Bytes read at a time, so a corrupt length can't reserve more memory than the input provides.
+const READ_CHUNK: usize = 4096;
+
+impl Encode for DecSixbit {
+    fn encode<E: Encoder>(&self, encoder: &mut E) -> Result<(), EncodeError> {
+        (self.len() as u64).encode(encoder)?;
+        self.as_bytes().encode(encoder)
+    }
+}
+
+impl<Context> Decode<Context> for DecSixbit {
+    fn decode<D: Decoder<Context = Context>>(decoder: &mut D) -> Result<Self, DecodeError> {
+        let len = decode_usize(decoder)?;
+        let byte_len = decode_usize(decoder)?;
+        // The byte count is the payload, plus one when the trailing-space marker is present. A length
+        // whose payload overflows can't match any byte count.
+        let consistent = match len.checked_mul(6) {
+            Some(bits) => byte_len == bits.div_ceil(8) || byte_len == bits.div_ceil(8) + 1,
+            None => false,
+        };
+        if !consistent {
+            return Err(DecodeError::OtherString(Error::InvalidBytesLength.to_string()));
+        }
+
+        decoder.claim_bytes_read(byte_len)?;
+        let mut bytes = Vec::with_capacity(byte_len.min(READ_CHUNK));
+        while bytes.len() < byte_len {
+            let start = bytes.len();
+            bytes.resize(start + (byte_len - start).min(READ_CHUNK), 0);
+            decoder.reader().read(&mut bytes[start..])?;
+        }
+        DecSixbit::from_parts(bytes, len).map_err(|error| DecodeError::OtherString(error.to_string()))
+    }
+}
+
+bincode2::impl_borrow_decode!(DecSixbit);
+
+/// Decodes a `u64` length, rejecting values that don't fit in `usize`.
+fn decode_usize<Context, D: Decoder<Context = Context>>(decoder: &mut D) -> Result<usize, DecodeError> {
+    let value = u64::decode(decoder)?;
+    usize::try_from(value).map_err(|_| DecodeError::OutsideUsizeRange(value))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_fixtures::{assert_round_trips, values};
+    use crate::DecSixbit;
+    use bincode2::config;
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trips(|sixbit| {
+            let encoded = bincode2::encode_to_vec(sixbit, config::standard()).unwrap();
+            let (decoded, read) = bincode2::decode_from_slice::<DecSixbit, _>(&encoded, config::standard())?;
+            assert_eq!(read, encoded.len());
+            Ok::<_, bincode2::error::DecodeError>(decoded)
+        });
+        assert_round_trips(|sixbit| {
+            let encoded = bincode2::encode_to_vec(sixbit, config::legacy()).unwrap();
+            assert_eq!(encoded.len(), 8 + 8 + sixbit.as_bytes().len());
+            let (decoded, read) = bincode2::decode_from_slice::<DecSixbit, _>(&encoded, config::legacy())?;
+            assert_eq!(read, encoded.len());
+            Ok::<_, bincode2::error::DecodeError>(decoded)
+        });
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_layout_matches_serde() {
+        for sixbit in values() {
+            let native = bincode2::encode_to_vec(&sixbit, config::legacy()).unwrap();
+            assert_eq!(native, bincode::serialize(&sixbit).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_rejects_malformed_input() {
+        let standard = config::standard();
+        let valid = DecSixbit::new("HELLO WORLD").unwrap();
+        let encoded = bincode2::encode_to_vec(&valid, standard).unwrap();
+        for end in 0..encoded.len() {
+            assert!(bincode2::decode_from_slice::<DecSixbit, _>(&encoded[..end], standard).is_err(), "end={end}");
+        }
+
+        let malformed: [(u64, &[u8]); 5] = [
+            (3, &[0x84, 0x20]),
+            (5, &[0xa2, 0x5b, 0x2c, 0xbd]),
+            (4, &[0x86, 0x28, 0xc0]),
+            (u64::MAX, &[]),
+            (2, &[0x86, 0x20, 0x00, 0x00]),
+        ];
+        for (len, bytes) in malformed {
+            let encoded = bincode2::encode_to_vec((len, bytes), standard).unwrap();
+            assert!(bincode2::decode_from_slice::<DecSixbit, _>(&encoded, standard).is_err(), "len={len}");
+        }
+    }
+
+    #[test]
+    fn test_huge_length_does_not_preallocate() {
+        let standard = config::standard();
+        // A consistent length and byte count for 1 TiB of packed bytes, with none following
+        let len = 1u64 << 42;
+        let encoded = bincode2::encode_to_vec((len, len / 4 * 3), standard).unwrap();
+        assert!(bincode2::decode_from_slice::<DecSixbit, _>(&encoded, standard).is_err());
+
+        let limited = standard.with_limit::<1024>();
+        let encoded = bincode2::encode_to_vec(DecSixbit::new(&"A".repeat(2000)).unwrap(), standard).unwrap();
+        assert!(bincode2::decode_from_slice::<DecSixbit, _>(&encoded, limited).is_err());
+    }
+}
@@ -0,0 +1,322 @@
+//! Helpers for addressing individual 6-bit fields inside packed SIXBIT bytes.
+//!
+//! Every 3 bytes hold 4 characters, so the field for character `index` lives in group `index / 4`
+//! and starts at one of four bit phases depending on `index % 4`. These helpers hide that
+//! arithmetic from the struct API.
+
+use crate::{Error, MASK_FOUR_BITS, MASK_SIX_BITS, MASK_TWO_BITS, SHIFT_FOUR_BITS, SHIFT_SIX_BITS, SHIFT_TWO_BITS};
+
+/// The marker byte for trailing spaces in the last block is added when the length is a multiple of 4, and the last 6 bits are all zero.
+pub(crate) const TRAILING_SPACE_MARKER: u8 = 0b11;
+
+/// Returns the number of packed bytes needed to store `len` characters, excluding any marker byte.
+#[inline(always)]
+pub(crate) const fn bytes_for_chars(len: usize) -> usize {
+    (len * 6).div_ceil(8)
+}
+
+/// Returns [`bytes_for_chars`], or `None` if `len` is too large for its bit count to fit in `usize`.
+///
+/// Lengths that come from the caller go through this before they are compared with a byte count, since
+/// a wrapped product could otherwise match a short buffer.
+#[inline(always)]
+pub(crate) const fn checked_bytes_for_chars(len: usize) -> Option<usize> {
+    match len.checked_mul(6) {
+        Some(bits) => Some(bits.div_ceil(8)),
+        None => None,
+    }
+}
+
+/// Returns the number of characters encoded in `bytes`, including any trailing-space marker byte.
+///
+/// A final group of 3 bytes holds 4 characters unless its last 6 bits are zero, in which case it is
+/// a 3-character tail: a fourth character of value 0 would have required the marker byte. A single
+/// extra byte is either the marker or a 1-character tail, whose low two bits are always zero.
+#[inline(always)]
+pub(crate) fn infer_len(bytes: &[u8]) -> usize {
+    let full_blocks = bytes.len() / 3;
+    match bytes.len() % 3 {
+        0 if bytes.is_empty() => 0,
+        0 if bytes[bytes.len() - 1] & MASK_SIX_BITS == 0 => full_blocks * 4 - 1,
+        0 => full_blocks * 4,
+        1 if bytes[bytes.len() - 1] == TRAILING_SPACE_MARKER => full_blocks * 4,
+        1 => full_blocks * 4 + 1,
+        _ => full_blocks * 4 + 2,
+    }
+}
+
+/// Returns `true` if `bytes` is exactly the encoding of `len` characters that `DecSixbit::new` produces:
+/// the length matches, the padding bits of a partial final group are zero, and the trailing-space marker
+/// is present exactly when it is needed.
+pub(crate) fn is_canonical(bytes: &[u8], len: usize) -> bool {
+    check_canonical(bytes, len).is_ok()
+}
+
+/// Checks that `bytes` is in canonical form like [`is_canonical`], reporting what is wrong if not.
+///
+/// # Errors
+/// - Returns an [`Error::InvalidBytesLength`] if `bytes` doesn't encode `len` characters.
+/// - Returns an [`Error::NonzeroPadding`] if the padding bits of a partial final group are set.
+/// - Returns an [`Error::UnexpectedMarker`] if the bytes end in a marker the content doesn't need.
+pub(crate) fn check_canonical(bytes: &[u8], len: usize) -> Result<(), Error> {
+    if infer_len(bytes) != len {
+        return Err(Error::InvalidBytesLength);
+    }
+    let payload = bytes_for_chars(len);
+    let used_bits = len * 6 % 8;
+    if used_bits != 0 && bytes[payload - 1] & (0xFF >> used_bits) != 0 {
+        return Err(Error::NonzeroPadding);
+    }
+    // With the length inferred from the bytes, a needed marker can't be missing
+    let needs_marker = len % 4 == 0 && len != 0 && bytes[payload - 1] & MASK_SIX_BITS == 0;
+    if bytes.len() != payload + needs_marker as usize {
+        return Err(Error::UnexpectedMarker);
+    }
+    Ok(())
+}
+
+/// Zeroes the unused padding bits after the last of `len` characters packed in `bytes`.
+///
+/// The caller must ensure that `bytes` holds at least `bytes_for_chars(len)` bytes.
+#[inline(always)]
+pub(crate) fn clear_padding(bytes: &mut [u8], len: usize) {
+    let used_bits = len * 6 % 8;
+    if used_bits != 0 {
+        bytes[bytes_for_chars(len) - 1] &= !(0xFF >> used_bits);
+    }
+}
+
+/// Converts a character to its 6-bit SIXBIT value.
+///
+/// Returns an [`Error::InvalidCharacter`] if `c` is outside the range `' '..='_'`.
+#[inline(always)]
+pub(crate) fn char_value(c: char) -> Result<u8, Error> {
+    crate::char_to_value(c).ok_or(Error::InvalidCharacter)
+}
+
+/// Reads the 6-bit SIXBIT value of the character at `index`.
+///
+/// The caller must ensure that `bytes` holds at least `bytes_for_chars(index + 1)` bytes.
+#[inline(always)]
+pub(crate) fn value_at(bytes: &[u8], index: usize) -> u8 {
+    let byte_idx = index / 4 * 3;
+    match index % 4 {
+        0 => bytes[byte_idx] >> SHIFT_TWO_BITS,
+        1 => ((bytes[byte_idx] & MASK_TWO_BITS) << SHIFT_FOUR_BITS) | (bytes[byte_idx + 1] >> SHIFT_FOUR_BITS),
+        2 => ((bytes[byte_idx + 1] & MASK_FOUR_BITS) << SHIFT_TWO_BITS) | (bytes[byte_idx + 2] >> SHIFT_SIX_BITS),
+        3 => bytes[byte_idx + 2] & MASK_SIX_BITS,
+        _ => unreachable!(),
+    }
+}
+
+/// Overwrites the 6-bit field of the character at `index` with `value`, leaving all other bits untouched.
+///
+/// The caller must ensure that `bytes` holds at least `bytes_for_chars(index + 1)` bytes and that
+/// `value` is below 64.
+#[inline(always)]
+pub(crate) fn set_value(bytes: &mut [u8], index: usize, value: u8) {
+    let byte_idx = index / 4 * 3;
+    match index % 4 {
+        0 => {
+            bytes[byte_idx] = (bytes[byte_idx] & MASK_TWO_BITS) | (value << SHIFT_TWO_BITS);
+        },
+        1 => {
+            bytes[byte_idx] = (bytes[byte_idx] & !MASK_TWO_BITS) | (value >> SHIFT_FOUR_BITS);
+            bytes[byte_idx + 1] = (bytes[byte_idx + 1] & MASK_FOUR_BITS) | ((value & MASK_FOUR_BITS) << SHIFT_FOUR_BITS);
+        },
+        2 => {
+            bytes[byte_idx + 1] = (bytes[byte_idx + 1] & !MASK_FOUR_BITS) | (value >> SHIFT_TWO_BITS);
+            bytes[byte_idx + 2] = (bytes[byte_idx + 2] & MASK_SIX_BITS) | ((value & MASK_TWO_BITS) << SHIFT_SIX_BITS);
+        },
+        3 => {
+            bytes[byte_idx + 2] = (bytes[byte_idx + 2] & !MASK_SIX_BITS) | value;
+        },
+        _ => unreachable!(),
+    }
+}
+
+/// Copies the characters packed in `src` into `dst`, starting at character `start`.
+///
+/// When `start` is a multiple of 4 the bytes line up and are copied directly. Otherwise every source
+/// byte is shifted right by the bit phase of `start` and split over two destination bytes. The caller
+/// must ensure that `src` is a payload with zero padding bits, that `dst` holds at least
+/// `bytes_for_chars(start + n)` bytes for the `n` characters of `src`, and that the destination bits
+/// from `start` on are zero.
+pub(crate) fn splice(dst: &mut [u8], start: usize, src: &[u8]) {
+    let byte_idx = start * 6 / 8;
+    let shift = start * 6 % 8;
+    if shift == 0 {
+        dst[byte_idx..byte_idx + src.len()].copy_from_slice(src);
+        return;
+    }
+    for (offset, &byte) in src.iter().enumerate() {
+        dst[byte_idx + offset] |= byte >> shift;
+        // The low bits of the last byte are padding, so any spill past `dst` is zero
+        if let Some(next) = dst.get_mut(byte_idx + offset + 1) {
+            *next |= byte << (8 - shift);
+        }
+    }
+}
+
+/// Returns `true` if the `len` characters packed in `needle` appear in `haystack` starting at character `start`.
+///
+/// When `start` is a multiple of 4 the whole bytes are compared directly. Otherwise each haystack byte
+/// pair is shifted left by the bit phase of `start` to line up with a needle byte. Either way the last
+/// needle byte is compared under a mask, so padding bits and any marker are ignored. The caller must
+/// ensure that `needle` holds at least `bytes_for_chars(len)` bytes and `haystack` holds at least
+/// `bytes_for_chars(start + len)` bytes.
+pub(crate) fn eq_at(haystack: &[u8], start: usize, needle: &[u8], len: usize) -> bool {
+    let needle = &needle[..bytes_for_chars(len)];
+    let byte_idx = start * 6 / 8;
+    let shift = start * 6 % 8;
+    let used_bits = len * 6 % 8;
+    let last_mask = if used_bits == 0 { 0xFF } else { !(0xFF >> used_bits) };
+    needle.iter().enumerate().all(|(offset, &byte)| {
+        let high = haystack[byte_idx + offset];
+        let aligned = if shift == 0 {
+            high
+        } else {
+            let low = haystack.get(byte_idx + offset + 1).copied().unwrap_or(0);
+            (high << shift) | (low >> (8 - shift))
+        };
+        let mask = if offset + 1 == needle.len() { last_mask } else { 0xFF };
+        (aligned ^ byte) & mask == 0
+    })
+}
+
+/// Returns the number of the first `len` characters in `bytes` that come before any trailing spaces.
+///
+/// Spaces are SIXBIT 0, so a whole group of 4 trailing spaces is 3 zero bytes and is skipped with one
+/// comparison; only the partial final group and the last non-blank group are examined per character.
+/// The caller must ensure that `bytes` holds at least `bytes_for_chars(len)` bytes.
+#[inline]
+pub(crate) fn trimmed_len(bytes: &[u8], len: usize) -> usize {
+    let mut end = len;
+    while end % 4 != 0 {
+        if value_at(bytes, end - 1) != 0 {
+            return end;
+        }
+        end -= 1;
+    }
+    while end >= 4 && bytes[end / 4 * 3 - 3..end / 4 * 3] == [0; 3] {
+        end -= 4;
+    }
+    while end > 0 && value_at(bytes, end - 1) == 0 {
+        end -= 1;
+    }
+    end
+}
+
+/// Calls `f` with the 6-bit value of each of the first `len` characters, in order.
+///
+/// Full groups are loaded as a single 24-bit word and split with shifts, which is considerably
+/// cheaper than addressing each field separately. Any marker byte past the payload is ignored.
+#[inline(always)]
+pub(crate) fn for_each_value<F: FnMut(u8)>(bytes: &[u8], len: usize, mut f: F) {
+    let full_chunks = len / 4;
+    for chunk in bytes[..full_chunks * 3].chunks_exact(3) {
+        let word = ((chunk[0] as u32) << 16) | ((chunk[1] as u32) << 8) | (chunk[2] as u32);
+        f((word >> 18) as u8 & MASK_SIX_BITS);
+        f((word >> 12) as u8 & MASK_SIX_BITS);
+        f((word >> 6) as u8 & MASK_SIX_BITS);
+        f(word as u8 & MASK_SIX_BITS);
+    }
+    for index in full_chunks * 4..len {
+        f(value_at(bytes, index));
+    }
+}
+
+/// Returns `true` if `f` holds for the 6-bit value of each of the first `len` characters.
+///
+/// Stops at the first value for which `f` returns `false`. Returns `true` when `len` is 0.
+#[inline(always)]
+pub(crate) fn all_values<F: FnMut(u8) -> bool>(bytes: &[u8], len: usize, mut f: F) -> bool {
+    let full_chunks = len / 4;
+    for chunk in bytes[..full_chunks * 3].chunks_exact(3) {
+        let word = ((chunk[0] as u32) << 16) | ((chunk[1] as u32) << 8) | (chunk[2] as u32);
+        if !(f((word >> 18) as u8 & MASK_SIX_BITS)
+            && f((word >> 12) as u8 & MASK_SIX_BITS)
+            && f((word >> 6) as u8 & MASK_SIX_BITS)
+            && f(word as u8 & MASK_SIX_BITS))
+        {
+            return false;
+        }
+    }
+    (full_chunks * 4..len).all(|index| f(value_at(bytes, index)))
+}
+
+#[cfg(all(test, feature = "with-struct"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bytes_for_chars() {
+        assert_eq!(bytes_for_chars(0), 0);
+        assert_eq!(bytes_for_chars(1), 1);
+        assert_eq!(bytes_for_chars(2), 2);
+        assert_eq!(bytes_for_chars(3), 3);
+        assert_eq!(bytes_for_chars(4), 3);
+        assert_eq!(bytes_for_chars(5), 4);
+    }
+
+    #[test]
+    fn test_infer_len_matches_struct() {
+        for len in 0usize..=12 {
+            for tail in ["A", " "] {
+                let input = "X".repeat(len.saturating_sub(1)) + if len == 0 { "" } else { tail };
+                let sixbit = crate::DecSixbit::new(&input).unwrap();
+                assert_eq!(infer_len(sixbit.as_bytes()), len, "Mismatch for {:?}", input);
+            }
+        }
+    }
+
+    #[test]
+    fn test_is_canonical() {
+        for input in ["", "A", "AB", "ABC", "ABCD", "ABC ", "HELLO WORLD", "    "] {
+            let sixbit = crate::DecSixbit::new(input).unwrap();
+            assert!(is_canonical(sixbit.as_bytes(), input.len()), "{:?} should be canonical", input);
+        }
+        // Nonzero padding bits
+        assert!(!is_canonical(&[0b10000101], 1));
+        assert!(!is_canonical(&[0b10000110, 0b00101000], 2));
+        // Marker on a value that does not need it
+        assert!(!is_canonical(&[0x86, 0x28, 0xe4, TRAILING_SPACE_MARKER], 4));
+        // Length disagreeing with the bytes
+        assert!(!is_canonical(&[0x86, 0x28, 0xe4], 5));
+        assert!(!is_canonical(&[0x86, 0x28, 0xe4], usize::MAX));
+    }
+
+    #[test]
+    fn test_value_at_matches_encode() {
+        let input = "HELLO WORLD_";
+        let (bytes, len) = crate::encode(input).unwrap();
+        for (i, b) in input.bytes().enumerate().take(len) {
+            assert_eq!(value_at(&bytes, i), b - 32, "Mismatch at index {}", i);
+        }
+    }
+
+    #[test]
+    fn test_for_each_value() {
+        let input = "THE QUICK BROWN FOX";
+        let (bytes, len) = crate::encode(input).unwrap();
+        let mut values = Vec::new();
+        for_each_value(&bytes, len, |v| values.push(v));
+        let expected: Vec<u8> = input.bytes().map(|b| b - 32).collect();
+        assert_eq!(values, expected);
+    }
+
+    #[test]
+    fn test_set_value_every_phase() {
+        let input = "ABCDEFGH";
+        let (mut bytes, len) = crate::encode(input).unwrap();
+        for i in 0..len {
+            let original = bytes.clone();
+            set_value(&mut bytes, i, 0b111111);
+            for j in 0..len {
+                let expected = if i == j { 0b111111 } else { input.as_bytes()[j] - 32 };
+                assert_eq!(value_at(&bytes, j), expected, "Mismatch at index {} after setting {}", j, i);
+            }
+            bytes = original;
+        }
+    }
+}
@@ -0,0 +1,98 @@
+//! [Borsh](https://borsh.io) support for `DecSixbit`.
+//!
+//! A `DecSixbit` is written as its length in characters as a little-endian `u32`, followed by the
+//! packed bytes returned by [`encode`](crate::encode). The number of packed bytes follows from the
+//! length, so no byte count is written, and the trailing-space marker kept by `DecSixbit` is never
+//! included. Each value therefore has exactly one serialized form.
+//!
+//! | Value     | Bytes                                      |
+//! |-----------|--------------------------------------------|
+//! | `""`      | `00 00 00 00`                              |
+//! | `"HELLO"` | `05 00 00 00` `a2 5b 2c bc`                |
+//! | `"ABC "`  | `04 00 00 00` `86 28 c0`                   |
+//!
+//! Deserialization rejects nonzero padding bits and input that ends before the packed bytes do.
+
+use crate::DecSixbit;
+use borsh::io::{Error as IoError, ErrorKind, Read, Write};
+use borsh::{BorshDeserialize, BorshSerialize};
+
+/// Bytes read at a time, so a corrupt length can't reserve more memory than the input provides.
+const READ_CHUNK: usize = 4096;
+
+impl BorshSerialize for DecSixbit {
+    fn serialize<W: Write>(&self, writer: &mut W) -> borsh::io::Result<()> {
+        let len = u32::try_from(self.len())
+            .map_err(|_| IoError::new(ErrorKind::InvalidInput, "DecSixbit is too long for a u32 length"))?;
+        len.serialize(writer)?;
+        writer.write_all(self.payload())
+    }
+}
+
+impl BorshDeserialize for DecSixbit {
+    fn deserialize_reader<R: Read>(reader: &mut R) -> borsh::io::Result<Self> {
+        let len = u32::deserialize_reader(reader)? as usize;
+        // Only overflows where usize is 32 bits, and no such value fits in memory anyway
+        let payload_len = len
+            .checked_mul(6)
+            .map(|bits| bits.div_ceil(8))
+            .ok_or_else(|| IoError::new(ErrorKind::InvalidData, "DecSixbit length is too large"))?;
+        let mut bytes = Vec::with_capacity(payload_len.min(READ_CHUNK));
+        while bytes.len() < payload_len {
+            let start = bytes.len();
+            bytes.resize(start + (payload_len - start).min(READ_CHUNK), 0);
+            reader.read_exact(&mut bytes[start..])?;
+        }
+        DecSixbit::from_payload(bytes, len).map_err(IoError::from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_fixtures::{assert_round_trips, values};
+    use crate::DecSixbit;
+
+    #[test]
+    fn test_exact_bytes() {
+        let cases: [(&str, &[u8]); 3] = [
+            ("", &[0, 0, 0, 0]),
+            ("HELLO", &[5, 0, 0, 0, 0xa2, 0x5b, 0x2c, 0xbc]),
+            ("ABC ", &[4, 0, 0, 0, 0x86, 0x28, 0xc0]),
+        ];
+        for (input, expected) in cases {
+            let sixbit = DecSixbit::new(input).unwrap();
+            assert_eq!(borsh::to_vec(&sixbit).unwrap(), expected, "{input:?}");
+        }
+    }
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trips(|sixbit| {
+            let encoded = borsh::to_vec(sixbit).unwrap();
+            assert_eq!(encoded.len(), 4 + sixbit.payload().len());
+            borsh::from_slice::<DecSixbit>(&encoded)
+        });
+
+        let values = values();
+        let encoded = borsh::to_vec(&values).unwrap();
+        assert_eq!(borsh::from_slice::<Vec<DecSixbit>>(&encoded).unwrap(), values);
+    }
+
+    #[test]
+    fn test_rejects_truncated_buffer() {
+        let encoded = borsh::to_vec(&DecSixbit::new("HELLO WORLD").unwrap()).unwrap();
+        for end in 0..encoded.len() {
+            assert!(borsh::from_slice::<DecSixbit>(&encoded[..end]).is_err(), "end={end}");
+        }
+
+        // A huge length with no bytes behind it fails without reserving memory for it
+        assert!(borsh::from_slice::<DecSixbit>(&u32::MAX.to_le_bytes()).is_err());
+    }
+
+    #[test]
+    fn test_rejects_nonzero_padding() {
+        let mut encoded = borsh::to_vec(&DecSixbit::new("HELLO").unwrap()).unwrap();
+        *encoded.last_mut().unwrap() |= 1;
+        assert!(borsh::from_slice::<DecSixbit>(&encoded).is_err());
+    }
+}
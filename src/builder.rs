@@ -0,0 +1,142 @@
+//! Provides `DecSixbitBuilder`, which builds a value from untrusted text while collecting every invalid
+//! character instead of stopping at the first.
+//!
+//! Each invalid character is replaced by a placeholder, so the output always has one character per
+//! input character and the recorded positions index straight into it.
+
+use crate::{DecSixbit, InvalidCharacters, SixbitChar};
+use alloc::vec::Vec;
+use core::convert::TryFrom;
+
+/// Builds a `DecSixbit` from fragments, substituting a placeholder for invalid characters and
+/// recording where each one was.
+///
+/// # Examples
+///
+/// ```rust
+/// use dec_sixbit::DecSixbitBuilder;
+///
+/// let mut builder = DecSixbitBuilder::new();
+/// builder.append_str("Acme").append_char(' ').append_str("CO.");
+/// assert_eq!(builder.errors(), [(1, 'c'), (2, 'm'), (3, 'e')]);
+/// let (sixbit, errors) = builder.finish();
+/// assert_eq!(sixbit.to_string(), "A??? CO.");
+/// assert_eq!(errors.len(), 3);
+/// ```
+#[derive(Debug, Clone)]
+pub struct DecSixbitBuilder {
+    sixbit: DecSixbit,
+    placeholder: SixbitChar,
+    errors: Vec<(usize, char)>,
+}
+
+impl DecSixbitBuilder {
+    /// The placeholder used by [`new`](Self::new), `'?'`.
+    pub const DEFAULT_PLACEHOLDER: char = '?';
+
+    /// Creates an empty builder that substitutes `'?'` for invalid characters.
+    pub fn new() -> Self {
+        Self::with_placeholder(SixbitChar::try_from(Self::DEFAULT_PLACEHOLDER).expect("'?' is a valid SIXBIT character"))
+    }
+
+    /// Creates an empty builder that substitutes `placeholder` for invalid characters.
+    ///
+    /// # Parameters
+    /// - `placeholder`: The character written in place of each invalid character.
+    pub fn with_placeholder(placeholder: SixbitChar) -> Self {
+        Self {
+            sixbit: DecSixbit::default(),
+            placeholder,
+            errors: Vec::new(),
+        }
+    }
+
+    /// Appends the characters of `str`, substituting the placeholder for any invalid ones.
+    ///
+    /// # Parameters
+    /// - `str`: The fragment to append.
+    pub fn append_str(&mut self, str: &str) -> &mut Self {
+        self.sixbit.reserve(str.len());
+        for c in str.chars() {
+            self.append_char(c);
+        }
+        self
+    }
+
+    /// Appends `c`, substituting the placeholder if it is invalid.
+    ///
+    /// # Parameters
+    /// - `c`: The character to append.
+    pub fn append_char(&mut self, c: char) -> &mut Self {
+        let sixbit_char = SixbitChar::try_from(c).unwrap_or_else(|_| {
+            self.errors.push((self.sixbit.len(), c));
+            self.placeholder
+        });
+        self.sixbit.push_sixbit_char(sixbit_char);
+        self
+    }
+
+    /// Returns the invalid characters seen so far, with their positions in the output.
+    pub fn errors(&self) -> &[(usize, char)] {
+        &self.errors
+    }
+
+    /// Returns the built value, with placeholders for invalid characters, and the list of them.
+    pub fn finish(self) -> (DecSixbit, Vec<(usize, char)>) {
+        (self.sixbit, self.errors)
+    }
+
+    /// Returns the built value if every character was valid.
+    ///
+    /// # Errors
+    /// Returns an [`InvalidCharacters`] listing every invalid character if there were any.
+    pub fn finish_strict(self) -> Result<DecSixbit, InvalidCharacters> {
+        if self.errors.is_empty() {
+            Ok(self.sixbit)
+        } else {
+            Err(InvalidCharacters::new(self.errors))
+        }
+    }
+}
+
+impl Default for DecSixbitBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DecSixbitBuilder;
+    use crate::{DecSixbit, SixbitChar};
+
+    #[test]
+    fn test_collects_all_errors() {
+        let mut builder = DecSixbitBuilder::new();
+        builder.append_str("Name: ").append_str("Jörg").append_char('\t').append_str("OK~");
+        let expected = [(1, 'a'), (2, 'm'), (3, 'e'), (7, 'ö'), (8, 'r'), (9, 'g'), (10, '\t'), (13, '~')];
+        assert_eq!(builder.errors(), expected);
+
+        let (sixbit, errors) = builder.clone().finish();
+        assert_eq!(sixbit.to_string(), "N???: J????OK?");
+        assert_eq!(errors, expected);
+
+        let error = builder.finish_strict().unwrap_err();
+        assert_eq!(error.errors(), expected);
+        assert_eq!(error.clone().into_errors(), expected);
+        assert_eq!(error.to_string(), "8 invalid characters, the first 'a' at position 1");
+    }
+
+    #[test]
+    fn test_custom_placeholder_and_clean_input() {
+        let mut builder = DecSixbitBuilder::with_placeholder(SixbitChar::SPACE);
+        builder.append_str("AB").append_char('c').append_str("");
+        assert_eq!(builder.finish(), (DecSixbit::new("AB ").unwrap(), vec![(2, 'c')]));
+
+        let mut builder = DecSixbitBuilder::default();
+        builder.append_str("DSK:").append_str("FOO.BAR");
+        assert!(builder.errors().is_empty());
+        assert_eq!(builder.finish_strict().unwrap(), DecSixbit::new("DSK:FOO.BAR").unwrap());
+        assert_eq!(DecSixbitBuilder::new().finish_strict().unwrap(), DecSixbit::default());
+    }
+}
@@ -0,0 +1,304 @@
+//! Integration with the [bytes](https://docs.rs/bytes) crate's `Bytes` and `BytesMut` buffers.
+//!
+//! [`SixbitBytes`] holds packed bytes in a reference-counted `Bytes`, so a field sliced out of a larger
+//! network buffer is validated once and then shared without copying. [`encode_to_bytes_mut`] appends
+//! packed bytes to a `BytesMut` in the same form, so fields written by it can be sliced out again with
+//! [`BytesMut::freeze`](bytes::BytesMut::freeze) and [`Bytes::slice`].
+//!
+//! All three take bytes in the form [`DecSixbit::as_bytes`] returns them, including the trailing-space
+//! marker when the content needs it.
+
+use crate::{bits, decode::decode_into, display, encode::encode_into, DecSixbit, DecSixbitStr, Error};
+use alloc::string::String;
+use alloc::vec;
+use bytes::{BufMut, Bytes, BytesMut};
+use core::borrow::Borrow;
+use core::cmp::Ordering;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::ops::Deref;
+
+/// An immutable SIXBIT value whose packed bytes are held in a `Bytes` buffer.
+///
+/// It dereferences to [`DecSixbitStr`] and compares, hashes, and orders like the equivalent [`DecSixbit`].
+/// Cloning only increments the reference count of the buffer.
+///
+/// # Examples
+///
+/// ```rust
+/// use bytes::Bytes;
+/// use dec_sixbit::{DecSixbit, SixbitBytes};
+///
+/// let packet = Bytes::from(DecSixbit::new("DSKB").unwrap().as_bytes().to_vec());
+/// let name = SixbitBytes::from_shared(packet.clone(), 4).unwrap();
+/// assert_eq!(name.as_bytes().as_ptr(), packet.as_ptr());
+/// assert_eq!(name.to_string(), "DSKB");
+/// ```
+#[derive(Clone)]
+pub struct SixbitBytes {
+    bytes: Bytes,
+}
+
+impl SixbitBytes {
+    /// Wraps packed bytes holding `len` characters without copying them.
+    ///
+    /// The bytes are checked up front exactly like [`DecSixbitStr::new`], so a value that is constructed
+    /// is always consistent with its length.
+    ///
+    /// # Parameters
+    /// - `bytes`: The packed bytes, as returned by [`DecSixbit::as_bytes`].
+    /// - `len`: The number of characters encoded in `bytes`.
+    ///
+    /// # Errors
+    /// - Returns an [`Error::InvalidBytesLength`] if `bytes` doesn't encode `len` characters.
+    /// - Returns an [`Error::NonzeroPadding`] if the unused bits after the last character are set.
+    /// - Returns an [`Error::UnexpectedMarker`] if the bytes end in a marker the content doesn't need.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bytes::Bytes;
+    /// use dec_sixbit::{Error, SixbitBytes};
+    ///
+    /// let (bytes, len) = dec_sixbit::encode("HELLO").unwrap();
+    /// let bytes = Bytes::from(bytes);
+    /// assert_eq!(SixbitBytes::from_shared(bytes.clone(), len).unwrap().len(), 5);
+    /// assert_eq!(SixbitBytes::from_shared(bytes, 6), Err(Error::InvalidBytesLength));
+    /// ```
+    pub fn from_shared(bytes: Bytes, len: usize) -> Result<Self, Error> {
+        bits::check_canonical(&bytes, len)?;
+        Ok(Self { bytes })
+    }
+
+    /// Borrows the value as a [`DecSixbitStr`] view.
+    #[inline(always)]
+    pub fn as_sixbit_str(&self) -> &DecSixbitStr {
+        DecSixbitStr::from_bytes_unchecked(&self.bytes)
+    }
+
+    /// Returns the buffer holding the packed bytes.
+    pub fn into_bytes(self) -> Bytes {
+        self.bytes
+    }
+}
+
+/// Encodes a string and appends the packed bytes to a `BytesMut`.
+///
+/// The bytes are appended as [`DecSixbit::as_bytes`] returns them, so they can be read back with
+/// [`SixbitBytes::from_shared`] or [`decode_from_bytes`].
+///
+/// # Parameters
+/// - `str`: The input string to encode. Must contain only ASCII characters in the range 32-95.
+/// - `buf`: The buffer to append to.
+///
+/// # Returns
+/// The number of bytes appended.
+///
+/// # Errors
+/// Returns an [`Error::InvalidCharacter`] if the input contains characters outside the valid range, in
+/// which case `buf` is left unchanged.
+///
+/// # Examples
+///
+/// ```rust
+/// use bytes::BytesMut;
+/// use dec_sixbit::encode_to_bytes_mut;
+///
+/// let mut buf = BytesMut::new();
+/// assert_eq!(encode_to_bytes_mut("SYS", &mut buf), Ok(3));
+/// assert_eq!(encode_to_bytes_mut("LOGIN ", &mut buf), Ok(5));
+/// assert_eq!(buf.len(), 8);
+/// ```
+pub fn encode_to_bytes_mut(str: &str, buf: &mut BytesMut) -> Result<usize, Error> {
+    let start = buf.len();
+    let len = str.len();
+    buf.put_bytes(0, bits::bytes_for_chars(len));
+    if let Err(error) = encode_into(str, &mut buf[start..]) {
+        buf.truncate(start);
+        return Err(error);
+    }
+    if len % 4 == 0 && len != 0 && buf[buf.len() - 1] & 0b111111 == 0 {
+        buf.put_u8(DecSixbit::TRAILING_SPACE_MARKER);
+    }
+    Ok(buf.len() - start)
+}
+
+/// Decodes packed bytes held in a `Bytes` buffer into a string.
+///
+/// # Parameters
+/// - `bytes`: The packed bytes, as returned by [`DecSixbit::as_bytes`].
+/// - `len`: The number of characters encoded in `bytes`.
+///
+/// # Errors
+/// Returns the same errors as [`SixbitBytes::from_shared`].
+///
+/// # Examples
+///
+/// ```rust
+/// use bytes::BytesMut;
+/// use dec_sixbit::{decode_from_bytes, encode_to_bytes_mut};
+///
+/// let mut buf = BytesMut::new();
+/// encode_to_bytes_mut("HELLO", &mut buf).unwrap();
+/// assert_eq!(decode_from_bytes(&buf.freeze(), 5).unwrap(), "HELLO");
+/// ```
+pub fn decode_from_bytes(bytes: &Bytes, len: usize) -> Result<String, Error> {
+    bits::check_canonical(bytes, len)?;
+    let mut decoded = vec![0; len];
+    decode_into(bytes, len, &mut decoded);
+    // SAFETY: decoded SIXBIT characters are always ASCII
+    Ok(unsafe { String::from_utf8_unchecked(decoded) })
+}
+
+impl From<DecSixbit> for SixbitBytes {
+    fn from(sixbit: DecSixbit) -> Self {
+        Self {
+            bytes: Bytes::from(sixbit.into_boxed_bytes()),
+        }
+    }
+}
+
+impl From<SixbitBytes> for DecSixbit {
+    fn from(sixbit: SixbitBytes) -> Self {
+        DecSixbit::from_buffer(sixbit.as_bytes(), sixbit.len())
+    }
+}
+
+impl Deref for SixbitBytes {
+    type Target = DecSixbitStr;
+
+    #[inline(always)]
+    fn deref(&self) -> &DecSixbitStr {
+        self.as_sixbit_str()
+    }
+}
+
+impl Borrow<DecSixbitStr> for SixbitBytes {
+    fn borrow(&self) -> &DecSixbitStr {
+        self.as_sixbit_str()
+    }
+}
+
+impl AsRef<DecSixbitStr> for SixbitBytes {
+    fn as_ref(&self) -> &DecSixbitStr {
+        self.as_sixbit_str()
+    }
+}
+
+impl AsRef<[u8]> for SixbitBytes {
+    fn as_ref(&self) -> &[u8] {
+        &self.bytes
+    }
+}
+
+impl PartialEq for SixbitBytes {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.as_sixbit_str() == other.as_sixbit_str()
+    }
+}
+
+impl Eq for SixbitBytes {}
+
+impl PartialEq<DecSixbit> for SixbitBytes {
+    fn eq(&self, other: &DecSixbit) -> bool {
+        self.as_sixbit_str() == other.as_sixbit_str()
+    }
+}
+
+impl PartialEq<SixbitBytes> for DecSixbit {
+    fn eq(&self, other: &SixbitBytes) -> bool {
+        self.as_sixbit_str() == other.as_sixbit_str()
+    }
+}
+
+impl PartialOrd for SixbitBytes {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for SixbitBytes {
+    /// Orders values lexicographically by their decoded characters, like `str`.
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.as_sixbit_str().cmp(other.as_sixbit_str())
+    }
+}
+
+impl Hash for SixbitBytes {
+    /// Hashes the same way as [`DecSixbit`] and [`DecSixbitStr`].
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_sixbit_str().hash(state);
+    }
+}
+
+impl fmt::Debug for SixbitBytes {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        display::debug_packed(f, "SixbitBytes", &self.bytes, self.len())
+    }
+}
+
+impl fmt::Display for SixbitBytes {
+    #[inline(always)]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self.as_sixbit_str(), f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{decode_from_bytes, encode_to_bytes_mut, SixbitBytes};
+    use crate::{DecSixbit, Error};
+    use bytes::BytesMut;
+
+    const FIELDS: [&str; 4] = ["SYS", "LOGIN ", "ABC ", "SPHINX OF BLACK QUARTZ, JUDGE MY VOW!"];
+
+    #[test]
+    fn test_slice_fields_without_copying() {
+        let mut buf = BytesMut::new();
+        let mut ranges = Vec::new();
+        for field in FIELDS {
+            let start = buf.len();
+            let appended = encode_to_bytes_mut(field, &mut buf).unwrap();
+            assert_eq!(&buf[start..], DecSixbit::new(field).unwrap().as_bytes(), "{field:?}");
+            ranges.push(start..start + appended);
+        }
+        let packet = buf.freeze();
+
+        for (field, range) in FIELDS.iter().zip(ranges) {
+            let slice = packet.slice(range.clone());
+            let sixbit = SixbitBytes::from_shared(slice, field.len()).unwrap();
+            assert_eq!(sixbit.as_bytes().as_ptr(), packet[range.start..].as_ptr(), "{field:?}");
+            assert_eq!(sixbit.to_string(), *field);
+            assert_eq!(sixbit, DecSixbit::new(field).unwrap());
+            assert_eq!(decode_from_bytes(&packet.slice(range), field.len()).unwrap(), *field);
+        }
+    }
+
+    #[test]
+    fn test_invalid_input() {
+        let mut buf = BytesMut::from(&b"\xff"[..]);
+        assert_eq!(encode_to_bytes_mut("lower", &mut buf), Err(Error::InvalidCharacter));
+        assert_eq!(&buf[..], b"\xff");
+
+        let packet = buf.freeze();
+        assert_eq!(SixbitBytes::from_shared(packet.clone(), 2), Err(Error::InvalidBytesLength));
+        assert_eq!(SixbitBytes::from_shared(packet.clone(), 1), Err(Error::NonzeroPadding));
+        assert_eq!(decode_from_bytes(&packet, 1), Err(Error::NonzeroPadding));
+    }
+
+    #[test]
+    fn test_round_trip() {
+        for field in FIELDS {
+            let sixbit = DecSixbit::new(field).unwrap();
+            let shared = SixbitBytes::from(sixbit.clone());
+            assert_eq!(shared.len(), field.len());
+            assert_eq!(DecSixbit::from(shared.clone()), sixbit);
+            assert_eq!(format!("{shared:?}"), format!("{sixbit:?}").replacen("DecSixbit", "SixbitBytes", 1));
+            assert_eq!(SixbitBytes::from_shared(shared.clone().into_bytes(), field.len()), Ok(shared));
+        }
+    }
+}
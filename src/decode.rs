@@ -4,10 +4,16 @@
 //! to ensure all SIXBIT values are within the valid range, while the unchecked functions assume the input
 //! is already valid for increased performance.
 
-use crate::{Error, ASCII_OFFSET, MASK_SIX_BITS};
+use crate::{ASCII_OFFSET, MASK_SIX_BITS, U64_CHARS};
+#[cfg(feature = "alloc")]
+use crate::{Error, U128_CHARS};
+#[cfg(feature = "alloc")]
+use alloc::{string::String, vec, vec::Vec};
 
 /// This function converts a slice of SIXBIT-encoded bytes into the original string based on the provided length.
 ///
+/// This function requires the `alloc` feature, which is enabled by default.
+///
 /// # Parameters
 /// - `bytes`: A slice of bytes containing SIXBIT-encoded data.
 /// - `len`: The length of the original string.
@@ -25,9 +31,10 @@ use crate::{Error, ASCII_OFFSET, MASK_SIX_BITS};
 /// let decoded_string = decode(&encoded_bytes, length).unwrap();
 /// assert_eq!(decoded_string, input);
 /// ```
+#[cfg(feature = "alloc")]
 #[inline(always)]
 pub fn decode(bytes: &[u8], len: usize) -> Result<String, Error> {
-    if bytes.len() != (len * 6 + 7) / 8 {
+    if crate::bits::checked_bytes_for_chars(len) != Some(bytes.len()) {
         return Err(Error::InvalidBytesLength);
     }
     Ok(decode_core(bytes, len))
@@ -37,6 +44,8 @@ pub fn decode(bytes: &[u8], len: usize) -> Result<String, Error> {
 /// valid range or whether the resulting bytes form a valid UTF-8 string. Use this function only
 /// when you are certain the input is valid to avoid undefined behavior.
 ///
+/// This function requires the `alloc` feature, which is enabled by default.
+///
 /// # Safety
 /// The `bytes` slice must contain valid SIXBIT-encoded data:
 /// - The `len` must accurately reflect the number of original characters.
@@ -58,11 +67,13 @@ pub fn decode(bytes: &[u8], len: usize) -> Result<String, Error> {
 /// let decoded_string = decode_unchecked(&encoded_bytes, length);
 /// assert_eq!(decoded_string, input);
 /// ```
+#[cfg(feature = "alloc")]
 #[inline(always)]
 pub fn decode_unchecked(bytes: &[u8], len: usize) -> String {
     decode_core(bytes, len)
 }
 
+#[cfg(feature = "alloc")]
 #[inline(always)]
 fn decode_core(bytes: &[u8], len: usize) -> String {
     if len == 0 {
@@ -70,6 +81,91 @@ fn decode_core(bytes: &[u8], len: usize) -> String {
     }
 
     let mut result = vec![0u8; len];
+    decode_into(bytes, len, &mut result);
+
+    // SAFETY: Each byte of result is guaranteed to fit to any ASCII printable character
+    unsafe { String::from_utf8_unchecked(result) }
+}
+
+/// Decodes SIXBIT bytes like [`decode`], without the trailing spaces.
+///
+/// The end of the content is found by scanning the packed bytes backward, skipping whole groups of
+/// spaces bytewise, and only the characters before it are decoded. Leading and interior spaces are kept.
+///
+/// This function requires the `alloc` feature, which is enabled by default.
+///
+/// # Parameters
+/// - `bytes`: A slice of bytes containing SIXBIT-encoded data.
+/// - `len`: The length of the original string.
+///
+/// # Errors
+/// Returns an [`Error::InvalidBytesLength`] if `bytes.len()` and `len` are inconsistent.
+///
+/// # Examples
+///
+/// ```rust
+/// use dec_sixbit::{decode_trimmed, encode};
+///
+/// let (bytes, len) = encode("LOGIN   SAV         ").unwrap();
+/// assert_eq!(decode_trimmed(&bytes, len).unwrap(), "LOGIN   SAV");
+/// ```
+#[cfg(feature = "alloc")]
+pub fn decode_trimmed(bytes: &[u8], len: usize) -> Result<String, Error> {
+    if crate::bits::checked_bytes_for_chars(len) != Some(bytes.len()) {
+        return Err(Error::InvalidBytesLength);
+    }
+    Ok(decode_core(bytes, crate::bits::trimmed_len(bytes, len)))
+}
+
+/// Decodes SIXBIT bytes like [`decode`], with the letters `A`-`Z` mapped to lowercase.
+///
+/// Digits, punctuation, and spaces are unchanged. This is meant for display; the result is no longer
+/// valid SIXBIT input unless it is uppercased again.
+///
+/// This function requires the `alloc` feature, which is enabled by default.
+///
+/// # Parameters
+/// - `bytes`: A slice of bytes containing SIXBIT-encoded data.
+/// - `len`: The length of the original string.
+///
+/// # Errors
+/// Returns an [`Error::InvalidBytesLength`] if `bytes.len()` and `len` are inconsistent.
+///
+/// # Examples
+///
+/// ```rust
+/// use dec_sixbit::{decode_lowercase, encode};
+///
+/// let (bytes, len) = encode("SYS:LOGIN.SAV[1,2]").unwrap();
+/// assert_eq!(decode_lowercase(&bytes, len).unwrap(), "sys:login.sav[1,2]");
+/// ```
+#[cfg(feature = "alloc")]
+pub fn decode_lowercase(bytes: &[u8], len: usize) -> Result<String, Error> {
+    if crate::bits::checked_bytes_for_chars(len) != Some(bytes.len()) {
+        return Err(Error::InvalidBytesLength);
+    }
+    Ok(decode_lowercase_core(bytes, len))
+}
+
+/// Decodes the first `len` characters of `bytes` with letters lowercased, in a single pass into a
+/// `String` of exactly `len` bytes.
+#[cfg(feature = "alloc")]
+pub(crate) fn decode_lowercase_core(bytes: &[u8], len: usize) -> String {
+    let mut result = Vec::with_capacity(len);
+    crate::bits::for_each_value(bytes, len, |value| result.push((value + ASCII_OFFSET).to_ascii_lowercase()));
+    // SAFETY: Each byte of result is a printable ASCII character
+    unsafe { String::from_utf8_unchecked(result) }
+}
+
+/// Decodes the first `len` characters of `bytes` as ASCII into the start of `result`.
+///
+/// This lets callers decode into a buffer they already own instead of allocating. The caller must
+/// ensure that `bytes` holds at least `(len * 6).div_ceil(8)` bytes and `result` at least `len` bytes.
+#[inline(always)]
+pub(crate) fn decode_into(bytes: &[u8], len: usize, result: &mut [u8]) {
+    // The checked byte count keeps a huge `len` from wrapping around to a size that passes the check
+    let payload = crate::bits::checked_bytes_for_chars(len);
+    assert!(payload.is_some_and(|payload| bytes.len() >= payload) && result.len() >= len);
     let full_chunks = len / 4;
     let remaining_chars = len % 4;
 
@@ -130,12 +226,121 @@ fn decode_core(bytes: &[u8], len: usize) -> String {
             _ => unreachable!(),
         }
     }
+}
 
-    // SAFETY: Each byte of result is guaranteed to fit to any ASCII printable character
-    unsafe { String::from_utf8_unchecked(result) }
+/// Unpacks the raw 6-bit values of `len` characters, `0..=63`, without the ASCII offset.
+///
+/// This function requires the `alloc` feature, which is enabled by default.
+///
+/// # Parameters
+/// - `bytes`: A slice of bytes containing SIXBIT-encoded data.
+/// - `len`: The number of characters encoded in `bytes`.
+///
+/// # Errors
+/// Returns an [`Error::InvalidBytesLength`] if `bytes.len()` and `len` are inconsistent.
+///
+/// # Examples
+///
+/// ```rust
+/// use dec_sixbit::{decode_values, encode};
+///
+/// let (bytes, len) = encode("A 0").unwrap();
+/// assert_eq!(decode_values(&bytes, len).unwrap(), [33, 0, 16]);
+/// ```
+#[cfg(feature = "alloc")]
+pub fn decode_values(bytes: &[u8], len: usize) -> Result<Vec<u8>, Error> {
+    if crate::bits::checked_bytes_for_chars(len) != Some(bytes.len()) {
+        return Err(Error::InvalidBytesLength);
+    }
+    let mut values = vec![0; len];
+    decode_into(bytes, len, &mut values);
+    values.iter_mut().for_each(|value| *value -= ASCII_OFFSET);
+    Ok(values)
+}
+
+/// Unpacks `len` characters from a `u64` written by [`encode_u64`](crate::encode_u64).
+///
+/// This function requires the `alloc` feature, which is enabled by default.
+///
+/// # Parameters
+/// - `value`: The packed value, with the first character in the 6 most significant bits.
+/// - `len`: The number of characters packed in `value`, at most 10.
+///
+/// # Errors
+/// - Returns an [`Error::FieldOverflow`] if `len` is greater than 10.
+/// - Returns an [`Error::InvalidBytesLength`] if any bit below the last character is set.
+///
+/// # Examples
+///
+/// ```rust
+/// use dec_sixbit::{decode_u64, encode_u64};
+///
+/// let packed = encode_u64("DSK").unwrap();
+/// assert_eq!(decode_u64(packed, 3).unwrap(), "DSK");
+/// assert!(decode_u64(packed, 2).is_err());
+/// ```
+#[cfg(feature = "alloc")]
+pub fn decode_u64(value: u64, len: usize) -> Result<String, Error> {
+    if len > U64_CHARS {
+        return Err(Error::FieldOverflow);
+    }
+    if value.checked_shl((6 * len) as u32).unwrap_or(0) != 0 {
+        return Err(Error::InvalidBytesLength);
+    }
+    Ok((0..len).map(|i| (((value >> (58 - 6 * i)) as u8 & MASK_SIX_BITS) + ASCII_OFFSET) as char).collect())
+}
+
+/// Unpacks `len` characters from a `u128` written by [`encode_u128`](crate::encode_u128).
+///
+/// This function requires the `alloc` feature, which is enabled by default.
+///
+/// # Parameters
+/// - `value`: The packed value, with the first character in the 6 most significant bits.
+/// - `len`: The number of characters packed in `value`, at most 21.
+///
+/// # Errors
+/// - Returns an [`Error::FieldOverflow`] if `len` is greater than 21.
+/// - Returns an [`Error::InvalidBytesLength`] if any bit below the last character is set.
+///
+/// # Examples
+///
+/// ```rust
+/// use dec_sixbit::{decode_u128, encode_u128};
+///
+/// let packed = encode_u128("DSKB:README.TXT").unwrap();
+/// assert_eq!(decode_u128(packed, 15).unwrap(), "DSKB:README.TXT");
+/// ```
+#[cfg(feature = "alloc")]
+pub fn decode_u128(value: u128, len: usize) -> Result<String, Error> {
+    if len > U128_CHARS {
+        return Err(Error::FieldOverflow);
+    }
+    if value.checked_shl((6 * len) as u32).unwrap_or(0) != 0 {
+        return Err(Error::InvalidBytesLength);
+    }
+    Ok((0..len).map(|i| (((value >> (122 - 6 * i)) as u8 & MASK_SIX_BITS) + ASCII_OFFSET) as char).collect())
+}
+
+/// Narrows a value of `len` characters packed by [`encode_u128`](crate::encode_u128) to the layout of
+/// [`encode_u64`](crate::encode_u64).
+///
+/// # Returns
+/// `None` if `len` is greater than 10, or if any of the low 64 bits of `value` are set.
+///
+/// # Examples
+///
+/// ```rust
+/// use dec_sixbit::{encode_u128, encode_u64, u128_to_u64};
+///
+/// assert_eq!(u128_to_u64(encode_u128("DSK").unwrap(), 3), Some(encode_u64("DSK").unwrap()));
+/// assert_eq!(u128_to_u64(encode_u128("DSKB:README").unwrap(), 11), None);
+/// ```
+#[inline]
+pub fn u128_to_u64(value: u128, len: usize) -> Option<u64> {
+    (len <= U64_CHARS && value as u64 == 0).then_some((value >> 64) as u64)
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "alloc"))]
 mod tests {
     use super::*;
 
@@ -166,6 +371,47 @@ mod tests {
     fn test_invalid_length() {
         let bytes = [0u8; 2];
         assert!(decode(&bytes, 3).is_err());
+
+        // The bit count of this length wraps around to 2, which would otherwise fit in one byte
+        let huge = usize::MAX / 6 + 1;
+        assert_eq!(decode(&bytes[..1], huge), Err(Error::InvalidBytesLength));
+        assert_eq!(decode_trimmed(&bytes[..1], huge), Err(Error::InvalidBytesLength));
+        assert_eq!(decode_lowercase(&bytes[..1], huge), Err(Error::InvalidBytesLength));
+        assert_eq!(decode_values(&bytes[..1], huge), Err(Error::InvalidBytesLength));
+    }
+
+    #[test]
+    fn test_decode_trimmed() {
+        for input in ["", " ", "        ", "HELLO", "HELLO   ", "  A  B", "ABCD            ", "ABC                    X  "] {
+            let (bytes, len) = crate::encode(input).unwrap();
+            assert_eq!(decode_trimmed(&bytes, len).unwrap(), input.trim_end(), "{input:?}");
+        }
+        let (bytes, len) = crate::encode("NO PADDING").unwrap();
+        assert_eq!(decode_trimmed(&bytes, len), decode(&bytes, len));
+        assert_eq!(decode_trimmed(&bytes, len + 2), Err(Error::InvalidBytesLength));
+    }
+
+    proptest::proptest! {
+        #[test]
+        fn prop_decode_trimmed_matches_trim_end(content in crate::proptest::sixbit_string(0..=24), padding in 0usize..=24) {
+            let input = format!("{content}{}", " ".repeat(padding));
+            let (bytes, len) = crate::encode(&input).unwrap();
+            let decoded = decode(&bytes, len).unwrap();
+            proptest::prop_assert_eq!(decode_trimmed(&bytes, len).unwrap(), decoded.trim_end());
+        }
+    }
+
+    #[test]
+    fn test_decode_lowercase() {
+        let all: String = crate::SIXBIT_CHARS.iter().collect();
+        let (bytes, len) = crate::encode(&all).unwrap();
+        let lower = decode_lowercase(&bytes, len).unwrap();
+        assert_eq!(lower, all.to_ascii_lowercase());
+        assert_eq!(lower.capacity(), len);
+        let changed: String = all.chars().zip(lower.chars()).filter(|(a, b)| a != b).map(|(a, _)| a).collect();
+        assert_eq!(changed, "ABCDEFGHIJKLMNOPQRSTUVWXYZ");
+        assert_eq!(decode_lowercase(&[], 0).unwrap(), "");
+        assert!(decode_lowercase(&bytes, len + 1).is_err());
     }
 
     #[test]
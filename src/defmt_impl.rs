@@ -0,0 +1,51 @@
+//! [defmt](https://docs.rs/defmt) support for logging SIXBIT values from embedded targets.
+//!
+//! The characters are decoded a chunk at a time into a stack buffer and written as `str` fragments, so
+//! formatting never allocates. A value whose bytes are too short for its length is written as a hex
+//! dump of the bytes instead.
+
+use crate::decode::decode_into;
+use crate::{bits, DecSixbit, DecSixbitArray, DecSixbitStr, SharedDecSixbit};
+use defmt::{Format, Formatter};
+
+/// The number of characters decoded per fragment, a multiple of 4 so each chunk starts on a group.
+const CHUNK_CHARS: usize = 32;
+
+/// Writes the first `len` characters packed in `bytes`, or a hex dump if `bytes` is too short.
+fn format_packed(f: Formatter<'_>, bytes: &[u8], len: usize) {
+    if bytes.len() < bits::bytes_for_chars(len) {
+        defmt::write!(f, "<invalid SIXBIT {=[u8]:02x}>", bytes);
+        return;
+    }
+    let mut buf = [0u8; CHUNK_CHARS];
+    for start in (0..len).step_by(CHUNK_CHARS) {
+        let count = CHUNK_CHARS.min(len - start);
+        decode_into(&bytes[start / 4 * 3..], count, &mut buf);
+        // SAFETY: decoded SIXBIT characters are always ASCII
+        defmt::write!(f, "{=str}", unsafe { core::str::from_utf8_unchecked(&buf[..count]) });
+    }
+}
+
+impl Format for DecSixbitStr {
+    fn format(&self, f: Formatter<'_>) {
+        format_packed(f, self.as_bytes(), self.len());
+    }
+}
+
+impl Format for DecSixbit {
+    fn format(&self, f: Formatter<'_>) {
+        format_packed(f, self.as_bytes(), self.len());
+    }
+}
+
+impl Format for SharedDecSixbit {
+    fn format(&self, f: Formatter<'_>) {
+        format_packed(f, self.as_bytes(), self.len());
+    }
+}
+
+impl<const N: usize, const M: usize> Format for DecSixbitArray<N, M> {
+    fn format(&self, f: Formatter<'_>) {
+        format_packed(f, self.as_bytes(), N);
+    }
+}
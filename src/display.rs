@@ -0,0 +1,177 @@
+//! Formatting helpers that render packed SIXBIT bytes without allocating a `String`.
+//!
+//! Characters are decoded into a small stack buffer one block at a time and written straight to the
+//! formatter, while width, fill, alignment, and precision are applied the same way `str` applies them.
+
+use crate::{decode::decode_into, DecSixbitStr};
+use core::fmt::{self, Alignment, Write};
+
+/// Number of characters decoded per block; a multiple of 4 so that blocks start on a group boundary.
+const BLOCK_CHARS: usize = 64;
+
+/// Writes the first `len` characters of `bytes`, honoring the formatter's width, fill, alignment,
+/// and precision exactly like [`fmt::Formatter::pad`] does for a `&str`.
+pub(crate) fn pad_packed(f: &mut fmt::Formatter<'_>, bytes: &[u8], len: usize) -> fmt::Result {
+    // Every character is a single ASCII byte, so precision truncates by characters and bytes alike
+    let len = f.precision().map_or(len, |precision| precision.min(len));
+    let Some(padding) = f.width().and_then(|width| width.checked_sub(len)).filter(|&padding| padding > 0) else {
+        return write_packed(f, bytes, len);
+    };
+    let (pre, post) = match f.align() {
+        None | Some(Alignment::Left) => (0, padding),
+        Some(Alignment::Right) => (padding, 0),
+        Some(Alignment::Center) => (padding / 2, padding.div_ceil(2)),
+    };
+    let fill = f.fill();
+    for _ in 0..pre {
+        f.write_char(fill)?;
+    }
+    write_packed(f, bytes, len)?;
+    for _ in 0..post {
+        f.write_char(fill)?;
+    }
+    Ok(())
+}
+
+/// Displays a value with its trailing spaces removed.
+///
+/// This struct is created by [`DecSixbitStr::display_trimmed`].
+pub(crate) struct Trimmed<'a>(pub(crate) &'a DecSixbitStr);
+
+impl fmt::Display for Trimmed<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        pad_packed(f, self.0.as_bytes(), self.0.trimmed_len())
+    }
+}
+
+/// Writes the `Debug` form shared by the SIXBIT value types, showing both the text and the packed
+/// bytes including any trailing-space marker.
+///
+/// The compact form is `Name("TEXT", len=4, bytes=[0x.., ..])` on a single line; the alternate form
+/// (`{:#?}`) puts each part on its own line.
+pub(crate) fn debug_packed(f: &mut fmt::Formatter<'_>, name: &str, bytes: &[u8], len: usize) -> fmt::Result {
+    let (open, separator, close) = if f.alternate() { ("(\n    ", ",\n    ", ",\n)") } else { ("(", ", ", ")") };
+    f.write_str(name)?;
+    f.write_str(open)?;
+    f.write_char('"')?;
+    write_packed(&mut EscapeQuoted(f), bytes, len)?;
+    f.write_char('"')?;
+    write!(f, "{}len={}{}bytes=[", separator, len, separator)?;
+    for (i, byte) in bytes.iter().enumerate() {
+        if i > 0 {
+            f.write_str(", ")?;
+        }
+        write!(f, "{:#04x}", byte)?;
+    }
+    f.write_char(']')?;
+    f.write_str(close)
+}
+
+/// Writes `bytes` as a continuous string of digits, each byte zero-padded to a fixed width.
+///
+/// `prefix` is written first when the alternate flag (`#`) is set, e.g. `0x` or `0o`.
+pub(crate) fn radix_packed(f: &mut fmt::Formatter<'_>, bytes: &[u8], prefix: &str, digit: fn(&mut fmt::Formatter<'_>, u8) -> fmt::Result) -> fmt::Result {
+    if f.alternate() {
+        f.write_str(prefix)?;
+    }
+    bytes.iter().try_for_each(|&byte| digit(f, byte))
+}
+
+/// Escapes the characters that `str`'s `Debug` escapes within the SIXBIT range: `"` and `\`.
+struct EscapeQuoted<'a, 'b>(&'a mut fmt::Formatter<'b>);
+
+impl Write for EscapeQuoted<'_, '_> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        for part in s.split_inclusive(['"', '\\']) {
+            match part.as_bytes().last() {
+                Some(b'"' | b'\\') => {
+                    self.0.write_str(&part[..part.len() - 1])?;
+                    self.0.write_char('\\')?;
+                    self.0.write_str(&part[part.len() - 1..])?;
+                },
+                _ => self.0.write_str(part)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Writes the first `len` characters of `bytes` without any padding.
+fn write_packed<W: Write>(f: &mut W, bytes: &[u8], len: usize) -> fmt::Result {
+    let mut buf = [0u8; BLOCK_CHARS];
+    for start in (0..len).step_by(BLOCK_CHARS) {
+        let count = BLOCK_CHARS.min(len - start);
+        decode_into(&bytes[start / 4 * 3..], count, &mut buf);
+        // SAFETY: Each decoded byte is a printable ASCII character
+        f.write_str(unsafe { core::str::from_utf8_unchecked(&buf[..count]) })?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::DecSixbit;
+
+    #[test]
+    fn test_hex_and_octal() {
+        let sixbit = DecSixbit::new("ABCD").unwrap();
+        assert_eq!(format!("{:x}", sixbit), "8628e4");
+        assert_eq!(format!("{:X}", sixbit), "8628E4");
+        assert_eq!(format!("{:#x}", sixbit), "0x8628e4");
+        assert_eq!(format!("{:#X}", sixbit), "0x8628E4");
+        assert_eq!(format!("{:o}", sixbit), "206050344");
+        assert_eq!(format!("{:#o}", sixbit), "0o206050344");
+        assert_eq!(format!("{:x}", sixbit.as_sixbit_str()), "8628e4");
+
+        let empty = DecSixbit::new("").unwrap();
+        assert_eq!(format!("{:x}{:X}{:o}", empty, empty, empty), "");
+        assert_eq!(format!("{:#x}", empty), "0x");
+
+        // The trailing-space marker is not part of the payload
+        let marked = DecSixbit::new("ABC ").unwrap();
+        assert_eq!(marked.as_bytes().len(), 4);
+        assert_eq!(format!("{:x}", marked), "8628c0");
+        assert_eq!(format!("{:o}", marked), "206050300");
+    }
+
+    #[test]
+    fn test_debug_format() {
+        let sixbit = DecSixbit::new("HELLO").unwrap();
+        assert_eq!(format!("{:?}", sixbit), r#"DecSixbit("HELLO", len=5, bytes=[0xa2, 0x5b, 0x2c, 0xbc])"#);
+        assert_eq!(
+            format!("{:#?}", sixbit),
+            "DecSixbit(\n    \"HELLO\",\n    len=5,\n    bytes=[0xa2, 0x5b, 0x2c, 0xbc],\n)"
+        );
+        assert_eq!(format!("{:?}", sixbit.as_sixbit_str()), r#"DecSixbitStr("HELLO", len=5, bytes=[0xa2, 0x5b, 0x2c, 0xbc])"#);
+    }
+
+    #[test]
+    fn test_debug_format_marker_and_escapes() {
+        let sixbit = DecSixbit::new("ABC ").unwrap();
+        assert_eq!(format!("{:?}", sixbit), r#"DecSixbit("ABC ", len=4, bytes=[0x86, 0x28, 0xc0, 0x03])"#);
+        assert_eq!(format!("{:?}", DecSixbit::new("").unwrap()), r#"DecSixbit("", len=0, bytes=[])"#);
+        let quoted = DecSixbit::new(r#"A"\B"#).unwrap();
+        assert!(format!("{:?}", quoted).starts_with(r#"DecSixbit("A\"\\B", len=4,"#));
+    }
+
+    #[test]
+    fn test_display_trimmed() {
+        let cases = [("DSKB  ", "DSKB"), ("      ", ""), ("", ""), ("RSX11M", "RSX11M"), ("  A B   ", "  A B"), ("ABC ", "ABC")];
+        for (input, expected) in cases {
+            let sixbit = DecSixbit::new(input).unwrap();
+            assert_eq!(sixbit.display_trimmed().to_string(), expected);
+            assert_eq!(sixbit.as_sixbit_str().display_trimmed().to_string(), expected);
+            assert_eq!(format!("{:>8}", sixbit.display_trimmed()), format!("{:>8}", expected));
+            assert_eq!(format!("{:.2}|", sixbit.display_trimmed()), format!("{:.2}|", expected));
+        }
+    }
+
+    #[test]
+    fn test_long_values_span_blocks() {
+        let input = "THE QUICK BROWN FOX JUMPS OVER THE LAZY DOG ".repeat(5);
+        let sixbit = DecSixbit::new(&input).unwrap();
+        assert_eq!(sixbit.to_string(), input);
+        assert_eq!(format!("{:.100}", sixbit), input[..100]);
+        assert_eq!(format!("{:>300}", sixbit), format!("{:>300}", input));
+    }
+}
@@ -0,0 +1,403 @@
+//! [embedded-io](https://docs.rs/embedded-io) adapters that encode or decode SIXBIT as it is written or
+//! read.
+//!
+//! Both adapters drive the [`SixbitEncoder`] and [`SixbitDecoder`] state machines, so they accept reads
+//! and writes of any size, down to a single byte, and never allocate.
+
+use crate::{Error, SixbitDecoder, SixbitEncoder};
+use core::fmt;
+use embedded_io::{ErrorKind, ErrorType, Read, Write};
+
+/// The number of packed bytes an adapter moves to or from the inner stream at once, a multiple of 3.
+const CHUNK_BYTES: usize = 48;
+/// The number of characters packed in [`CHUNK_BYTES`].
+const CHUNK_CHARS: usize = CHUNK_BYTES / 3 * 4;
+
+/// An error from a SIXBIT embedded-io adapter.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SixbitIoError<E> {
+    /// The data could not be encoded or decoded.
+    ///
+    /// This is an [`Error::InvalidCharacter`] for a character outside the range 32-95 written to a
+    /// [`SixbitEncodeWriter`], or an [`Error::InvalidBytesLength`] when the stream read by a
+    /// [`SixbitDecodeReader`] ends before the expected number of characters.
+    Sixbit(Error),
+    /// The inner stream failed.
+    Io(E),
+}
+
+impl<E: fmt::Debug> fmt::Display for SixbitIoError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Sixbit(error) => fmt::Display::fmt(error, f),
+            Self::Io(error) => write!(f, "I/O error: {error:?}"),
+        }
+    }
+}
+
+impl<E: embedded_io::Error> embedded_io::Error for SixbitIoError<E> {
+    fn kind(&self) -> ErrorKind {
+        match self {
+            Self::Sixbit(Error::InvalidCharacter) => ErrorKind::InvalidInput,
+            Self::Sixbit(_) => ErrorKind::InvalidData,
+            Self::Io(error) => error.kind(),
+        }
+    }
+}
+
+/// An [`embedded_io::Write`] adapter that encodes the ASCII characters written to it and writes the
+/// packed bytes to an inner writer.
+///
+/// An incomplete group of up to 3 characters is held back until more characters arrive, so
+/// [`finish`](Self::finish) must be called after the last write to emit it.
+///
+/// # Examples
+///
+/// ```rust
+/// use dec_sixbit::{encode_to_slice, SixbitEncodeWriter};
+/// use embedded_io::Write;
+///
+/// let mut buf = [0u8; 16];
+/// let mut writer = SixbitEncodeWriter::new(&mut buf[..]);
+/// writer.write_all(b"HEL").unwrap();
+/// writer.write_all(b"LO").unwrap();
+/// let (rest, len) = writer.finish().unwrap();
+/// let written = 16 - rest.len();
+/// let mut expected = [0u8; 4];
+/// encode_to_slice("HELLO", &mut expected).unwrap();
+/// assert_eq!((&buf[..written], len), (&expected[..], 5));
+/// ```
+#[derive(Debug)]
+pub struct SixbitEncodeWriter<W> {
+    inner: W,
+    encoder: SixbitEncoder,
+}
+
+impl<W: Write> SixbitEncodeWriter<W> {
+    /// Creates an adapter writing packed bytes to `inner`.
+    #[inline]
+    pub fn new(inner: W) -> Self {
+        Self {
+            inner,
+            encoder: SixbitEncoder::new(),
+        }
+    }
+
+    /// Returns a reference to the inner writer.
+    #[inline]
+    pub fn get_ref(&self) -> &W {
+        &self.inner
+    }
+
+    /// Returns the number of characters written so far.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.encoder.len()
+    }
+
+    /// Returns `true` if no characters have been written.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.encoder.is_empty()
+    }
+
+    /// Writes the incomplete last group, if any, and flushes the inner writer.
+    ///
+    /// # Returns
+    /// The inner writer and the number of characters written, the `len` to decode the output with.
+    ///
+    /// # Errors
+    /// Returns a [`SixbitIoError::Io`] if the inner writer fails.
+    pub fn finish(mut self) -> Result<(W, usize), SixbitIoError<W::Error>> {
+        let (tail, tail_len) = self.encoder.finish();
+        self.inner.write_all(&tail[..tail_len]).map_err(SixbitIoError::Io)?;
+        self.inner.flush().map_err(SixbitIoError::Io)?;
+        Ok((self.inner, self.encoder.len()))
+    }
+}
+
+impl<W: Write> ErrorType for SixbitEncodeWriter<W> {
+    type Error = SixbitIoError<W::Error>;
+}
+
+impl<W: Write> Write for SixbitEncodeWriter<W> {
+    /// Encodes the characters in `buf`, writing each completed group to the inner writer.
+    ///
+    /// A character outside the range 32-95 ends the write. The characters before it are accepted and
+    /// their count returned; if it is the first character, a [`SixbitIoError::Sixbit`] is returned.
+    fn write(&mut self, buf: &[u8]) -> Result<usize, Self::Error> {
+        let mut packed = [0u8; CHUNK_BYTES];
+        let mut packed_len = 0;
+        let mut accepted = 0;
+        for &c in buf {
+            match self.encoder.push(c) {
+                Ok(Some(group)) => {
+                    packed[packed_len..packed_len + 3].copy_from_slice(&group);
+                    packed_len += 3;
+                    if packed_len == CHUNK_BYTES {
+                        self.inner.write_all(&packed).map_err(SixbitIoError::Io)?;
+                        packed_len = 0;
+                    }
+                }
+                Ok(None) => {}
+                Err(error) if accepted == 0 => return Err(SixbitIoError::Sixbit(error)),
+                Err(_) => break,
+            }
+            accepted += 1;
+        }
+        self.inner.write_all(&packed[..packed_len]).map_err(SixbitIoError::Io)?;
+        Ok(accepted)
+    }
+
+    /// Flushes the inner writer; the incomplete last group is only written by [`finish`](Self::finish).
+    fn flush(&mut self) -> Result<(), Self::Error> {
+        self.inner.flush().map_err(SixbitIoError::Io)
+    }
+}
+
+/// An [`embedded_io::Read`] adapter that reads packed bytes from an inner reader and yields the decoded
+/// ASCII characters.
+///
+/// The number of characters is given up front, since it can't be recovered from the packed bytes alone.
+/// Reading stops after that many characters, without consuming any further bytes from the inner reader.
+///
+/// # Examples
+///
+/// ```rust
+/// use dec_sixbit::{encode_to_slice, SixbitDecodeReader};
+/// use embedded_io::Read;
+///
+/// let mut bytes = [0u8; 4];
+/// encode_to_slice("HELLO", &mut bytes).unwrap();
+/// let mut reader = SixbitDecodeReader::new(&bytes[..], 5).unwrap();
+/// let mut decoded = [0u8; 5];
+/// reader.read_exact(&mut decoded).unwrap();
+/// assert_eq!(&decoded, b"HELLO");
+/// assert_eq!(reader.read(&mut decoded).unwrap(), 0);
+/// ```
+#[derive(Debug)]
+pub struct SixbitDecodeReader<R> {
+    inner: R,
+    decoder: SixbitDecoder,
+    /// Decoded characters not yet read
+    chars: [u8; CHUNK_CHARS],
+    /// The range of `chars` not yet read
+    start: usize,
+    end: usize,
+}
+
+impl<R: Read> SixbitDecodeReader<R> {
+    /// Creates an adapter decoding `len` characters from the packed bytes read from `inner`.
+    ///
+    /// # Errors
+    /// Returns an [`Error::InvalidBytesLength`] if the number of bytes for `len` characters overflows `usize`.
+    #[inline]
+    pub fn new(inner: R, len: usize) -> Result<Self, Error> {
+        Ok(Self {
+            inner,
+            decoder: SixbitDecoder::new(len)?,
+            chars: [0; CHUNK_CHARS],
+            start: 0,
+            end: 0,
+        })
+    }
+
+    /// Returns a reference to the inner reader.
+    #[inline]
+    pub fn get_ref(&self) -> &R {
+        &self.inner
+    }
+
+    /// Unwraps the adapter, returning the inner reader.
+    #[inline]
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+}
+
+impl<R: Read> ErrorType for SixbitDecodeReader<R> {
+    type Error = SixbitIoError<R::Error>;
+}
+
+impl<R: Read> Read for SixbitDecodeReader<R> {
+    /// Reads decoded characters into `buf`, returning 0 once every character has been read.
+    ///
+    /// Returns a [`SixbitIoError::Sixbit`] holding an [`Error::InvalidBytesLength`] if the inner reader
+    /// ends early.
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize, Self::Error> {
+        if buf.is_empty() {
+            return Ok(0);
+        }
+        while self.start == self.end {
+            if self.decoder.is_finished() {
+                return Ok(0);
+            }
+            let mut packed = [0u8; CHUNK_BYTES];
+            let want = self.decoder.bytes_remaining().min(CHUNK_BYTES);
+            let read = self.inner.read(&mut packed[..want]).map_err(SixbitIoError::Io)?;
+            if read == 0 {
+                return Err(SixbitIoError::Sixbit(Error::InvalidBytesLength));
+            }
+            // At most 2 carried-over bytes plus CHUNK_BYTES complete CHUNK_BYTES / 3 groups, so `chars`
+            // never overflows
+            self.start = 0;
+            self.end = 0;
+            for &byte in &packed[..read] {
+                if let Some((group, count)) = self.decoder.push(byte) {
+                    self.chars[self.end..self.end + count].copy_from_slice(&group[..count]);
+                    self.end += count;
+                }
+            }
+        }
+        let count = buf.len().min(self.end - self.start);
+        buf[..count].copy_from_slice(&self.chars[self.start..self.start + count]);
+        self.start += count;
+        Ok(count)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{SixbitDecodeReader, SixbitEncodeWriter, SixbitIoError};
+    use crate::{decode_to_slice, encode_to_slice, Error};
+    use core::convert::Infallible;
+    use embedded_io::{ErrorType, Read, ReadExactError, Write};
+
+    /// An in-memory writer that accepts at most one byte per call.
+    #[derive(Default)]
+    struct ByteWriter(Vec<u8>);
+
+    impl ErrorType for ByteWriter {
+        type Error = Infallible;
+    }
+
+    impl Write for ByteWriter {
+        fn write(&mut self, buf: &[u8]) -> Result<usize, Infallible> {
+            self.0.extend(buf.first());
+            Ok(buf.len().min(1))
+        }
+
+        fn flush(&mut self) -> Result<(), Infallible> {
+            Ok(())
+        }
+    }
+
+    /// An in-memory reader that returns at most one byte per call.
+    struct ByteReader<'a>(&'a [u8]);
+
+    impl ErrorType for ByteReader<'_> {
+        type Error = Infallible;
+    }
+
+    impl Read for ByteReader<'_> {
+        fn read(&mut self, buf: &mut [u8]) -> Result<usize, Infallible> {
+            let Some((&byte, rest)) = self.0.split_first() else {
+                return Ok(0);
+            };
+            if buf.is_empty() {
+                return Ok(0);
+            }
+            buf[0] = byte;
+            self.0 = rest;
+            Ok(1)
+        }
+    }
+
+    /// Encodes with the slice-based function, to compare against.
+    fn encode(input: &str) -> (Vec<u8>, usize) {
+        let mut bytes = vec![0u8; input.len()];
+        let written = encode_to_slice(input, &mut bytes).unwrap();
+        bytes.truncate(written);
+        (bytes, input.len())
+    }
+
+    const INPUT: &str = "THE QUICK BROWN FOX JUMPS OVER THE LAZY DOG 0123456789 [SYS:LOGIN.SAV] !#$%&'()*+,-./";
+
+    #[test]
+    fn test_write_one_byte_at_a_time() {
+        for end in 0..=INPUT.len() {
+            let input = &INPUT[..end];
+            let mut writer = SixbitEncodeWriter::new(ByteWriter::default());
+            for c in input.as_bytes().chunks(1) {
+                assert_eq!(writer.write(c), Ok(1));
+            }
+            let (inner, len) = writer.finish().unwrap();
+            assert_eq!((inner.0, len), encode(input), "{input:?}");
+        }
+    }
+
+    #[test]
+    fn test_write_all_at_once() {
+        let mut writer = SixbitEncodeWriter::new(ByteWriter::default());
+        writer.write_all(INPUT.as_bytes()).unwrap();
+        writer.write_all(INPUT.as_bytes()).unwrap();
+        assert_eq!(writer.len(), INPUT.len() * 2);
+        let (inner, len) = writer.finish().unwrap();
+        assert_eq!((inner.0, len), encode(&INPUT.repeat(2)));
+    }
+
+    #[test]
+    fn test_write_invalid_character() {
+        let mut writer = SixbitEncodeWriter::new(ByteWriter::default());
+        assert_eq!(writer.write(b"ABCDEFab"), Ok(6));
+        assert_eq!(writer.write(b"ab"), Err(SixbitIoError::Sixbit(Error::InvalidCharacter)));
+        assert_eq!(embedded_io::Error::kind(&writer.write(b"\xC3").unwrap_err()), embedded_io::ErrorKind::InvalidInput);
+        let (inner, len) = writer.finish().unwrap();
+        assert_eq!((inner.0, len), encode("ABCDEF"));
+    }
+
+    #[test]
+    fn test_read_one_byte_at_a_time() {
+        for end in 0..=INPUT.len() {
+            let (bytes, len) = encode(&INPUT[..end]);
+            let mut reader = SixbitDecodeReader::new(ByteReader(&bytes), len).unwrap();
+            let mut decoded = Vec::new();
+            let mut buf = [0u8; 1];
+            while reader.read(&mut buf).unwrap() == 1 {
+                decoded.push(buf[0]);
+            }
+            assert_eq!(std::str::from_utf8(&decoded), Ok(decode_to_slice(&bytes, len, &mut [0; 128]).unwrap()));
+        }
+    }
+
+    #[test]
+    fn test_read_large_buffers() {
+        let input = INPUT.repeat(4);
+        let (bytes, len) = encode(&input);
+        let mut reader = SixbitDecodeReader::new(&bytes[..], len).unwrap();
+        let mut decoded = vec![0u8; len + 10];
+        let mut total = 0;
+        loop {
+            let read = reader.read(&mut decoded[total..]).unwrap();
+            if read == 0 {
+                break;
+            }
+            total += read;
+        }
+        assert_eq!(&decoded[..total], input.as_bytes());
+    }
+
+    #[test]
+    fn test_read_stops_at_len() {
+        let (mut bytes, len) = encode("ABCDE");
+        bytes.extend_from_slice(b"REST");
+        let mut reader = SixbitDecodeReader::new(&bytes[..], len).unwrap();
+        let mut decoded = [0u8; 5];
+        reader.read_exact(&mut decoded).unwrap();
+        assert_eq!(&decoded, b"ABCDE");
+        assert_eq!(reader.read(&mut decoded), Ok(0));
+        assert_eq!(reader.into_inner(), b"REST");
+    }
+
+    #[test]
+    fn test_read_truncated() {
+        let (bytes, len) = encode("ABCDE");
+        let mut reader = SixbitDecodeReader::new(ByteReader(&bytes[..3]), len).unwrap();
+        let mut decoded = [0u8; 5];
+        assert_eq!(
+            reader.read_exact(&mut decoded),
+            Err(ReadExactError::Other(SixbitIoError::Sixbit(Error::InvalidBytesLength)))
+        );
+        assert_eq!(SixbitDecodeReader::new(&bytes[..], usize::MAX / 6 + 1).err(), Some(Error::InvalidBytesLength));
+    }
+}
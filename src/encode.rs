@@ -4,11 +4,15 @@
 //! to ensure all characters are within the valid SIXBIT range, while the unchecked functions assume the input
 //! is already valid for increased performance.
 
-use crate::{Error, MASK_FOUR_BITS, MASK_TWO_BITS, ASCII_OFFSET, SHIFT_TWO_BITS, SHIFT_FOUR_BITS, SHIFT_SIX_BITS};
+use crate::{is_sixbit_char, Error, MASK_FOUR_BITS, MASK_TWO_BITS, ASCII_OFFSET, SHIFT_TWO_BITS, SHIFT_FOUR_BITS, SHIFT_SIX_BITS, U128_CHARS, U64_CHARS};
+#[cfg(feature = "alloc")]
+use alloc::{vec, vec::Vec};
 
 /// This function converts the input string into a compact SIXBIT-encoded byte vector and returns the
 /// encoded bytes along with the original string length.
 ///
+/// This function requires the `alloc` feature, which is enabled by default.
+///
 /// # Constraints
 /// - Only ASCII characters in the range 32-95 (space through underscore) are allowed.
 ///
@@ -23,27 +27,55 @@ use crate::{Error, MASK_FOUR_BITS, MASK_TWO_BITS, ASCII_OFFSET, SHIFT_TWO_BITS,
 /// let input = "HELLO";
 /// let (encoded_bytes, length) = encode(input).unwrap();
 /// ```
+#[cfg(feature = "alloc")]
 #[inline(always)]
 pub fn encode(str: &str) -> Result<(Vec<u8>, usize), Error> {
+    let len = str.len();
+    // Every 4 characters need 3 bytes, round up
+    let bytes_needed = (len * 3).div_ceil(4);
+    let mut bytes = vec![0u8; bytes_needed];
+    encode_into(str, &mut bytes)?;
+    Ok((bytes, len))
+}
+
+/// Encodes `str` into the start of `bytes`, which must be zeroed and hold at least
+/// `(str.len() * 3).div_ceil(4)` bytes.
+///
+/// This lets callers pack into a buffer they already own instead of allocating.
+///
+/// # Errors
+/// Returns an [`Error::InvalidCharacter`] if the input contains characters outside the valid range,
+/// in which case `bytes` may be partially written.
+#[inline(always)]
+pub(crate) fn encode_into(str: &str, bytes: &mut [u8]) -> Result<(), Error> {
     // Check if input string contains only ASCII characters
     if !str.is_ascii() {
         return Err(Error::InvalidCharacter);
     }
-    let len = str.len();
-    // Every 4 characters need 3 bytes, round up
-    let bytes_needed = (len * 3 + 3) / 4;
-    let mut bytes = vec![0u8; bytes_needed];
+    encode_ascii_into(str.as_bytes(), bytes)
+}
 
+/// Encodes the ASCII bytes `ascii` into the start of `bytes` like [`encode_into`], without first
+/// checking that the input is ASCII.
+///
+/// Bytes outside the range 32-95, including any non-ASCII byte, are still rejected.
+///
+/// # Errors
+/// Returns an [`Error::InvalidCharacter`] if the input contains characters outside the valid range,
+/// in which case `bytes` may be partially written.
+#[inline(always)]
+pub(crate) fn encode_ascii_into(ascii: &[u8], bytes: &mut [u8]) -> Result<(), Error> {
+    let len = ascii.len();
     let full_chunks = len / 4;
     let remaining = len % 4;
 
     for chunk_idx in 0..full_chunks {
         let start = chunk_idx * 4;
-        let chunk = &str.as_bytes()[start..start + 4];
+        let chunk = &ascii[start..start + 4];
 
         // Validate characters
         for &code in chunk {
-            if !(ASCII_OFFSET..=95).contains(&code) {
+            if !is_sixbit_char(code as char) {
                 return Err(Error::InvalidCharacter);
             }
         }
@@ -65,14 +97,14 @@ pub fn encode(str: &str) -> Result<(Vec<u8>, usize), Error> {
     // Handle the remaining 1-3 characters, if any
     if remaining > 0 {
         let start = full_chunks * 4;
-        let chunk = &str.as_bytes()[start..];
+        let chunk = &ascii[start..];
         let byte_idx = full_chunks * 3;
 
         match chunk.len() {
             3 => {
                 // Validate characters
                 for &code in chunk {
-                    if !(ASCII_OFFSET..=95).contains(&code) {
+                    if !is_sixbit_char(code as char) {
                         return Err(Error::InvalidCharacter);
                     }
                 }
@@ -90,7 +122,7 @@ pub fn encode(str: &str) -> Result<(Vec<u8>, usize), Error> {
             2 => {
                 // Validate characters
                 for &code in chunk {
-                    if !(ASCII_OFFSET..=95).contains(&code) {
+                    if !is_sixbit_char(code as char) {
                         return Err(Error::InvalidCharacter);
                     }
                 }
@@ -106,7 +138,7 @@ pub fn encode(str: &str) -> Result<(Vec<u8>, usize), Error> {
             1 => {
                 // Validate character
                 let code = chunk[0];
-                if !(ASCII_OFFSET..=95).contains(&code) {
+                if !is_sixbit_char(code as char) {
                     return Err(Error::InvalidCharacter);
                 }
 
@@ -120,13 +152,15 @@ pub fn encode(str: &str) -> Result<(Vec<u8>, usize), Error> {
         }
     }
 
-    Ok((bytes, len))
+    Ok(())
 }
 
 /// This function performs encoding without validating whether the input string contains only
 /// valid SIXBIT characters (ASCII 32-95). Use this function only when you are certain the input
 /// meets the required constraints to avoid undefined behavior.
 ///
+/// This function requires the `alloc` feature, which is enabled by default.
+///
 /// # Safety
 /// The caller must ensure that all characters in `str` are within the valid SIXBIT range (32-95).
 ///
@@ -138,11 +172,12 @@ pub fn encode(str: &str) -> Result<(Vec<u8>, usize), Error> {
 /// let input = "HELLO";
 /// let (encoded_bytes, length) = encode_unchecked(input);
 /// ```
+#[cfg(feature = "alloc")]
 #[inline(always)]
 pub fn encode_unchecked(str: &str) -> (Vec<u8>, usize) {
     let len = str.len();
     // Every 4 characters need 3 bytes, round up
-    let bytes_needed = (len * 3 + 3) / 4;
+    let bytes_needed = (len * 3).div_ceil(4);
     let mut bytes = vec![0u8; bytes_needed];
 
     let full_chunks = len / 4;
@@ -207,7 +242,78 @@ pub fn encode_unchecked(str: &str) -> (Vec<u8>, usize) {
     (bytes, len)
 }
 
-#[cfg(test)]
+/// Packs a string of up to 10 characters into a `u64`.
+///
+/// The first character occupies the 6 most significant bits, each following character the next 6 bits,
+/// and all remaining low bits are zero. Values of the same length therefore compare numerically in the
+/// same order as the strings. The length isn't stored, so it must be kept alongside the value and passed
+/// to [`decode_u64`](crate::decode_u64).
+///
+/// # Errors
+/// - Returns an [`Error::InvalidCharacter`] if the input contains characters outside the valid range.
+/// - Returns an [`Error::FieldOverflow`] if the input is longer than 10 characters.
+///
+/// # Examples
+///
+/// ```rust
+/// use dec_sixbit::encode_u64;
+///
+/// assert_eq!(encode_u64("A").unwrap(), 33 << 58);
+/// assert!(encode_u64("ABC").unwrap() < encode_u64("ABD").unwrap());
+/// assert!(encode_u64("ELEVEN CHAR").is_err());
+/// ```
+pub fn encode_u64(str: &str) -> Result<u64, Error> {
+    if str.len() > U64_CHARS {
+        return Err(Error::FieldOverflow);
+    }
+    str.bytes().enumerate().try_fold(0u64, |packed, (i, c)| {
+        let value = crate::char_to_value(c as char).ok_or(Error::InvalidCharacter)?;
+        Ok(packed | (value as u64) << (58 - 6 * i))
+    })
+}
+
+/// Packs a string of up to 21 characters into a `u128`, in the same layout as [`encode_u64`].
+///
+/// The first character occupies the 6 most significant bits and all remaining low bits are zero, so
+/// values of the same length compare numerically in the same order as the strings.
+///
+/// # Errors
+/// - Returns an [`Error::InvalidCharacter`] if the input contains characters outside the valid range.
+/// - Returns an [`Error::FieldOverflow`] if the input is longer than 21 characters.
+///
+/// # Examples
+///
+/// ```rust
+/// use dec_sixbit::encode_u128;
+///
+/// assert_eq!(encode_u128("A").unwrap(), 33 << 122);
+/// assert!(encode_u128("DSKB:README.TXT").unwrap() < encode_u128("DSKB:SWITCH.INI").unwrap());
+/// ```
+pub fn encode_u128(str: &str) -> Result<u128, Error> {
+    if str.len() > U128_CHARS {
+        return Err(Error::FieldOverflow);
+    }
+    str.bytes().enumerate().try_fold(0u128, |packed, (i, c)| {
+        let value = crate::char_to_value(c as char).ok_or(Error::InvalidCharacter)?;
+        Ok(packed | (value as u128) << (122 - 6 * i))
+    })
+}
+
+/// Widens a value packed by [`encode_u64`] to the layout of [`encode_u128`], for the same length.
+///
+/// # Examples
+///
+/// ```rust
+/// use dec_sixbit::{encode_u128, encode_u64, u64_to_u128};
+///
+/// assert_eq!(u64_to_u128(encode_u64("DSK").unwrap()), encode_u128("DSK").unwrap());
+/// ```
+#[inline]
+pub fn u64_to_u128(value: u64) -> u128 {
+    (value as u128) << 64
+}
+
+#[cfg(all(test, feature = "alloc"))]
 mod tests {
     use super::*;
 
@@ -0,0 +1,56 @@
+//! [`Equivalent`] implementations for looking up `DecSixbit` keys by `&str` in
+//! [hashbrown](https://docs.rs/hashbrown) and [indexmap](https://docs.rs/indexmap) maps.
+//!
+//! Both crates take lookup keys through the `equivalent` crate's trait, so the same implementations
+//! serve hashbrown 0.15 and later and indexmap 2. A `&str` probe hashes like the key it matches (see
+//! [`DecSixbitStr::hash_as_str`](crate::DecSixbitStr::hash_as_str)) and is compared with the packed
+//! characters directly, so lookups neither encode the probe nor allocate. `&DecSixbitStr` probes
+//! already work through `Borrow`.
+
+use crate::{DecSixbit, SharedDecSixbit};
+use equivalent::Equivalent;
+
+impl Equivalent<DecSixbit> for str {
+    #[inline]
+    fn equivalent(&self, key: &DecSixbit) -> bool {
+        key == self
+    }
+}
+
+impl Equivalent<SharedDecSixbit> for str {
+    #[inline]
+    fn equivalent(&self, key: &SharedDecSixbit) -> bool {
+        key.as_sixbit_str() == self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{DecSixbit, SharedDecSixbit};
+    use crate::test_alloc::count_allocations;
+
+    const NAMES: [&str; 4] = ["DSKA", "DSKB", "MTA0", "THE QUICK BROWN FOX JUMPS OVER THE LAZY DOG"];
+
+    #[test]
+    fn test_hashbrown_get_by_str() {
+        let map: hashbrown::HashMap<DecSixbit, usize> = NAMES.iter().enumerate().map(|(i, name)| (DecSixbit::new(name).unwrap(), i)).collect();
+        for (i, name) in NAMES.iter().enumerate() {
+            assert_eq!(count_allocations(|| map.get(*name).copied()), (Some(i), 0), "{name:?}");
+        }
+        assert_eq!(count_allocations(|| map.get("LPT")), (None, 0));
+        assert_eq!(map.get("dska"), None);
+
+        let shared: hashbrown::HashSet<SharedDecSixbit> = NAMES.iter().map(|name| SharedDecSixbit::new(name).unwrap()).collect();
+        assert_eq!(count_allocations(|| shared.contains("MTA0")), (true, 0));
+    }
+
+    #[test]
+    fn test_indexmap_get_by_str() {
+        let map: indexmap::IndexMap<DecSixbit, usize> = NAMES.iter().enumerate().map(|(i, name)| (DecSixbit::new(name).unwrap(), i)).collect();
+        for (i, name) in NAMES.iter().enumerate() {
+            assert_eq!(count_allocations(|| map.get_full(*name).map(|(index, _, &value)| (index, value))), (Some((i, i)), 0), "{name:?}");
+        }
+        assert_eq!(count_allocations(|| map.get("LPT")), (None, 0));
+        assert_eq!(map.get_index_of("DSKB "), None);
+    }
+}
@@ -0,0 +1,233 @@
+//! A C interface to the encoder and decoder, declared in `include/dec_sixbit.h`.
+//!
+//! Build a shared or static library with `cargo rustc --release --features ffi --crate-type cdylib` (or
+//! `staticlib`). The header can be regenerated with `cbindgen --config cbindgen.toml --output
+//! include/dec_sixbit.h`.
+//!
+//! Every function reads and writes only within the lengths it is given, and reports failure through a
+//! negative return code instead of unwinding into the caller. A pointer may be null only when its
+//! length is zero.
+
+use crate::decode::decode_into;
+use crate::{bits, encoded_len, Error};
+use core::ffi::c_char;
+use core::slice;
+use std::panic::{self, AssertUnwindSafe};
+
+/// The call succeeded.
+pub const DEC_SIXBIT_OK: i32 = 0;
+/// The input contains a character outside the range 32-95.
+pub const DEC_SIXBIT_ERR_INVALID_CHARACTER: i32 = -1;
+/// The number of packed bytes is inconsistent with the number of characters.
+pub const DEC_SIXBIT_ERR_INVALID_BYTES_LENGTH: i32 = -2;
+/// The output buffer is too small for the result.
+pub const DEC_SIXBIT_ERR_BUFFER_TOO_SMALL: i32 = -3;
+/// A pointer that must not be null was null.
+pub const DEC_SIXBIT_ERR_NULL_POINTER: i32 = -4;
+/// An unexpected internal error occurred.
+pub const DEC_SIXBIT_ERR_INTERNAL: i32 = -5;
+
+/// Maps an [`Error`] to its return code.
+fn error_code(error: Error) -> i32 {
+    match error {
+        Error::InvalidCharacter => DEC_SIXBIT_ERR_INVALID_CHARACTER,
+        Error::InvalidBytesLength => DEC_SIXBIT_ERR_INVALID_BYTES_LENGTH,
+        Error::TooLong { .. } => DEC_SIXBIT_ERR_BUFFER_TOO_SMALL,
+        _ => DEC_SIXBIT_ERR_INTERNAL,
+    }
+}
+
+/// Runs `f`, turning a panic into [`DEC_SIXBIT_ERR_INTERNAL`] so that it never unwinds across the FFI
+/// boundary.
+fn guard(f: impl FnOnce() -> Result<(), i32>) -> i32 {
+    match panic::catch_unwind(AssertUnwindSafe(f)) {
+        Ok(Ok(())) => DEC_SIXBIT_OK,
+        Ok(Err(code)) => code,
+        Err(_) => DEC_SIXBIT_ERR_INTERNAL,
+    }
+}
+
+/// Borrows `len` elements at `ptr`, which may be null only if `len` is zero.
+///
+/// # Safety
+/// If `len` is nonzero, `ptr` must be valid for reads of `len` elements.
+unsafe fn slice_from_raw<'a, T>(ptr: *const T, len: usize) -> Result<&'a [T], i32> {
+    match (ptr.is_null(), len) {
+        (_, 0) => Ok(&[]),
+        (true, _) => Err(DEC_SIXBIT_ERR_NULL_POINTER),
+        (false, _) => Ok(slice::from_raw_parts(ptr, len)),
+    }
+}
+
+/// Mutably borrows `len` elements at `ptr`, which may be null only if `len` is zero.
+///
+/// # Safety
+/// If `len` is nonzero, `ptr` must be valid for writes of `len` elements.
+unsafe fn slice_from_raw_mut<'a, T>(ptr: *mut T, len: usize) -> Result<&'a mut [T], i32> {
+    match (ptr.is_null(), len) {
+        (_, 0) => Ok(&mut []),
+        (true, _) => Err(DEC_SIXBIT_ERR_NULL_POINTER),
+        (false, _) => Ok(slice::from_raw_parts_mut(ptr, len)),
+    }
+}
+
+/// Returns the number of packed bytes needed to encode `chars` characters.
+#[no_mangle]
+pub extern "C" fn dec_sixbit_encoded_len(chars: usize) -> usize {
+    encoded_len(chars)
+}
+
+/// Encodes the `s_len` characters at `s` into `out`, storing the number of bytes written in `*out_len`.
+///
+/// `s` need not be NUL-terminated. `*out_len` is only written on success.
+///
+/// # Returns
+/// [`DEC_SIXBIT_OK`] on success, or [`DEC_SIXBIT_ERR_INVALID_CHARACTER`],
+/// [`DEC_SIXBIT_ERR_BUFFER_TOO_SMALL`] if `out_cap` is less than
+/// [`dec_sixbit_encoded_len`]`(s_len)`, or [`DEC_SIXBIT_ERR_NULL_POINTER`].
+///
+/// # Safety
+/// `s` must be valid for reads of `s_len` bytes, `out` for writes of `out_cap` bytes, and `out_len` for
+/// a write of one `size_t`.
+#[no_mangle]
+pub unsafe extern "C" fn dec_sixbit_encode(s: *const c_char, s_len: usize, out: *mut u8, out_cap: usize, out_len: *mut usize) -> i32 {
+    guard(|| {
+        let input = slice_from_raw(s.cast::<u8>(), s_len)?;
+        let out = slice_from_raw_mut(out, out_cap)?;
+        if out_len.is_null() {
+            return Err(DEC_SIXBIT_ERR_NULL_POINTER);
+        }
+        // Non-ASCII bytes are rejected as invalid characters, so the input needn't be UTF-8
+        let input = core::str::from_utf8(input).map_err(|_| DEC_SIXBIT_ERR_INVALID_CHARACTER)?;
+        let written = crate::encode_to_slice(input, out).map_err(error_code)?;
+        out_len.write(written);
+        Ok(())
+    })
+}
+
+/// Decodes `char_len` characters from the `byte_len` packed bytes at `bytes` into `out`.
+///
+/// Exactly `char_len` characters are written, without a NUL terminator.
+///
+/// # Returns
+/// [`DEC_SIXBIT_OK`] on success, or [`DEC_SIXBIT_ERR_INVALID_BYTES_LENGTH`] if `byte_len` is not
+/// [`dec_sixbit_encoded_len`]`(char_len)`, [`DEC_SIXBIT_ERR_BUFFER_TOO_SMALL`] if `out_cap` is less than
+/// `char_len`, or [`DEC_SIXBIT_ERR_NULL_POINTER`].
+///
+/// # Safety
+/// `bytes` must be valid for reads of `byte_len` bytes and `out` for writes of `out_cap` bytes.
+#[no_mangle]
+pub unsafe extern "C" fn dec_sixbit_decode(bytes: *const u8, byte_len: usize, char_len: usize, out: *mut c_char, out_cap: usize) -> i32 {
+    guard(|| {
+        let bytes = slice_from_raw(bytes, byte_len)?;
+        if bits::checked_bytes_for_chars(char_len) != Some(bytes.len()) {
+            return Err(DEC_SIXBIT_ERR_INVALID_BYTES_LENGTH);
+        }
+        if out_cap < char_len {
+            return Err(DEC_SIXBIT_ERR_BUFFER_TOO_SMALL);
+        }
+        let out = slice_from_raw_mut(out.cast::<u8>(), out_cap)?;
+        decode_into(bytes, char_len, out);
+        Ok(())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::ptr;
+
+    fn encode(input: &[u8], out: &mut [u8]) -> (i32, usize) {
+        let mut out_len = usize::MAX;
+        let code = unsafe { dec_sixbit_encode(input.as_ptr().cast(), input.len(), out.as_mut_ptr(), out.len(), &mut out_len) };
+        (code, out_len)
+    }
+
+    fn decode(bytes: &[u8], char_len: usize, out: &mut [u8]) -> i32 {
+        unsafe { dec_sixbit_decode(bytes.as_ptr(), bytes.len(), char_len, out.as_mut_ptr().cast(), out.len()) }
+    }
+
+    #[test]
+    fn test_round_trip() {
+        for input in ["", "A", "AB", "ABC", "ABCD", "SYS:LOGIN.SAV", "THE QUICK BROWN FOX JUMPS OVER THE LAZY DOG"] {
+            let mut packed = [0u8; 64];
+            let (code, written) = encode(input.as_bytes(), &mut packed);
+            assert_eq!((code, written), (DEC_SIXBIT_OK, dec_sixbit_encoded_len(input.len())));
+            assert_eq!(&packed[..written], &crate::encode(input).unwrap().0[..]);
+            let mut decoded = [0u8; 64];
+            assert_eq!(decode(&packed[..written], input.len(), &mut decoded), DEC_SIXBIT_OK);
+            assert_eq!(&decoded[..input.len()], input.as_bytes());
+        }
+    }
+
+    #[test]
+    fn test_small_buffers() {
+        // Exact fit
+        let mut packed = [0u8; 4];
+        assert_eq!(encode(b"HELLO", &mut packed), (DEC_SIXBIT_OK, 4));
+        let mut decoded = [0u8; 5];
+        assert_eq!(decode(&packed, 5, &mut decoded), DEC_SIXBIT_OK);
+        assert_eq!(&decoded, b"HELLO");
+
+        // One byte short, with a sentinel after the buffer that must stay untouched
+        let mut guarded = [0xAAu8; 5];
+        let (code, out_len) = encode(b"HELLO", &mut guarded[..3]);
+        assert_eq!((code, out_len), (DEC_SIXBIT_ERR_BUFFER_TOO_SMALL, usize::MAX));
+        assert_eq!(guarded[3..], [0xAA, 0xAA]);
+        let mut guarded = [0xAAu8; 5];
+        assert_eq!(decode(&packed, 5, &mut guarded[..4]), DEC_SIXBIT_ERR_BUFFER_TOO_SMALL);
+        assert_eq!(guarded[4], 0xAA);
+        assert_eq!(encode(b"A", &mut []).0, DEC_SIXBIT_ERR_BUFFER_TOO_SMALL);
+    }
+
+    #[test]
+    fn test_null_pointers() {
+        let mut out_len = usize::MAX;
+        let mut buf = [0u8; 4];
+        unsafe {
+            // Null with zero length is an empty slice
+            assert_eq!(dec_sixbit_encode(ptr::null(), 0, ptr::null_mut(), 0, &mut out_len), DEC_SIXBIT_OK);
+            assert_eq!(out_len, 0);
+            assert_eq!(dec_sixbit_decode(ptr::null(), 0, 0, ptr::null_mut(), 0), DEC_SIXBIT_OK);
+
+            assert_eq!(dec_sixbit_encode(ptr::null(), 1, buf.as_mut_ptr(), 4, &mut out_len), DEC_SIXBIT_ERR_NULL_POINTER);
+            assert_eq!(dec_sixbit_encode(c"A".as_ptr(), 1, ptr::null_mut(), 4, &mut out_len), DEC_SIXBIT_ERR_NULL_POINTER);
+            assert_eq!(dec_sixbit_encode(c"A".as_ptr(), 1, buf.as_mut_ptr(), 4, ptr::null_mut()), DEC_SIXBIT_ERR_NULL_POINTER);
+            assert_eq!(dec_sixbit_decode(ptr::null(), 1, 1, buf.as_mut_ptr().cast(), 4), DEC_SIXBIT_ERR_NULL_POINTER);
+            assert_eq!(dec_sixbit_decode(buf.as_ptr(), 1, 1, ptr::null_mut(), 4), DEC_SIXBIT_ERR_NULL_POINTER);
+        }
+    }
+
+    #[test]
+    fn test_invalid_input() {
+        let mut packed = [0u8; 8];
+        assert_eq!(encode(b"lower", &mut packed).0, DEC_SIXBIT_ERR_INVALID_CHARACTER);
+        assert_eq!(encode(b"\xC3\x84", &mut packed).0, DEC_SIXBIT_ERR_INVALID_CHARACTER);
+        assert_eq!(encode(b"\xFF", &mut packed).0, DEC_SIXBIT_ERR_INVALID_CHARACTER);
+        assert_eq!(decode(&packed[..2], 4, &mut [0; 8]), DEC_SIXBIT_ERR_INVALID_BYTES_LENGTH);
+        assert_eq!(decode(&packed[..4], 4, &mut [0; 8]), DEC_SIXBIT_ERR_INVALID_BYTES_LENGTH);
+        assert_eq!(decode(&packed[..1], usize::MAX / 6 + 1, &mut [0; 8]), DEC_SIXBIT_ERR_INVALID_BYTES_LENGTH);
+    }
+
+    #[test]
+    fn test_header_matches() {
+        let header = include_str!("../include/dec_sixbit.h");
+        for (name, value) in [
+            ("DEC_SIXBIT_OK", DEC_SIXBIT_OK),
+            ("DEC_SIXBIT_ERR_INVALID_CHARACTER", DEC_SIXBIT_ERR_INVALID_CHARACTER),
+            ("DEC_SIXBIT_ERR_INVALID_BYTES_LENGTH", DEC_SIXBIT_ERR_INVALID_BYTES_LENGTH),
+            ("DEC_SIXBIT_ERR_BUFFER_TOO_SMALL", DEC_SIXBIT_ERR_BUFFER_TOO_SMALL),
+            ("DEC_SIXBIT_ERR_NULL_POINTER", DEC_SIXBIT_ERR_NULL_POINTER),
+            ("DEC_SIXBIT_ERR_INTERNAL", DEC_SIXBIT_ERR_INTERNAL),
+        ] {
+            assert!(header.contains(&format!("#define {name} {value}\n")), "{name}");
+        }
+        for prototype in [
+            "size_t dec_sixbit_encoded_len(size_t chars);",
+            "int32_t dec_sixbit_encode(const char *s, size_t s_len, uint8_t *out, size_t out_cap, size_t *out_len);",
+            "int32_t dec_sixbit_decode(const uint8_t *bytes, size_t byte_len, size_t char_len, char *out, size_t out_cap);",
+        ] {
+            assert!(header.contains(prototype), "{prototype}");
+        }
+    }
+}
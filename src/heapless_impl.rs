@@ -0,0 +1,135 @@
+//! Encoding into and decoding from [heapless](https://docs.rs/heapless) fixed-capacity buffers.
+//!
+//! These reuse the same packing as [`encode_to_slice`](crate::encode_to_slice) and
+//! [`decode_to_slice`](crate::decode_to_slice),
+//! so they never touch an allocator. Input that doesn't fit in the buffer's capacity is rejected with an
+//! [`Error::TooLong`] instead of being truncated.
+
+use crate::decode::decode_into;
+use crate::encode::encode_into;
+use crate::{bits, Error};
+use heapless::{String, Vec};
+
+/// Encodes a string like [`encode_to_slice`](crate::encode_to_slice), into a `heapless::Vec` with capacity `N` bytes.
+///
+/// # Parameters
+/// - `str`: The input string to encode. Must contain only ASCII characters in the range 32-95.
+///
+/// # Errors
+/// - Returns an [`Error::TooLong`] if the packed bytes need more than `N` bytes, with `max` set to the
+///   number of characters `N` bytes can hold.
+/// - Returns an [`Error::InvalidCharacter`] if the input contains characters outside the valid range.
+///
+/// # Examples
+///
+/// ```rust
+/// use dec_sixbit::{encode_heapless, encode_to_slice, Error};
+///
+/// let (bytes, len) = encode_heapless::<6>("FILENAME").unwrap();
+/// let mut expected = [0u8; 6];
+/// encode_to_slice("FILENAME", &mut expected).unwrap();
+/// assert_eq!((bytes.as_slice(), len), (&expected[..], 8));
+/// assert_eq!(encode_heapless::<5>("FILENAME"), Err(Error::TooLong { len: 8, max: 6 }));
+/// ```
+pub fn encode_heapless<const N: usize>(str: &str) -> Result<(Vec<u8, N>, usize), Error> {
+    let len = str.len();
+    let mut bytes = Vec::new();
+    // Every 4 characters need 3 bytes, round up
+    if bytes.resize_default((len * 3).div_ceil(4)).is_err() {
+        return Err(Error::TooLong { len, max: N * 4 / 3 });
+    }
+    encode_into(str, &mut bytes)?;
+    Ok((bytes, len))
+}
+
+/// Decodes SIXBIT bytes like [`decode_to_slice`](crate::decode_to_slice), into a `heapless::String` with capacity `N`.
+///
+/// # Parameters
+/// - `bytes`: A slice of bytes containing SIXBIT-encoded data.
+/// - `len`: The length of the original string.
+///
+/// # Errors
+/// - Returns an [`Error::InvalidBytesLength`] if `bytes.len()` and `len` are inconsistent.
+/// - Returns an [`Error::TooLong`] if `len` is greater than `N`.
+///
+/// # Examples
+///
+/// ```rust
+/// use dec_sixbit::{decode_heapless, encode_heapless, Error};
+///
+/// let (bytes, len) = encode_heapless::<4>("HELLO").unwrap();
+/// assert_eq!(decode_heapless::<5>(&bytes, len).unwrap(), "HELLO");
+/// assert_eq!(decode_heapless::<4>(&bytes, len), Err(Error::TooLong { len: 5, max: 4 }));
+/// ```
+pub fn decode_heapless<const N: usize>(bytes: &[u8], len: usize) -> Result<String<N>, Error> {
+    if bits::checked_bytes_for_chars(len) != Some(bytes.len()) {
+        return Err(Error::InvalidBytesLength);
+    }
+    let mut result = Vec::<u8, N>::new();
+    if result.resize_default(len).is_err() {
+        return Err(Error::TooLong { len, max: N });
+    }
+    decode_into(bytes, len, &mut result);
+    // SAFETY: decoded SIXBIT characters are always ASCII
+    Ok(unsafe { String::from_utf8_unchecked(result) })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{decode_heapless, encode_heapless};
+    use crate::test_alloc::count_allocations;
+    use crate::{encode_to_slice, Error};
+
+    /// Encodes with the slice-based function, to compare against.
+    fn encode(input: &str) -> (Vec<u8>, usize) {
+        let mut bytes = vec![0u8; input.len()];
+        let written = encode_to_slice(input, &mut bytes).unwrap();
+        bytes.truncate(written);
+        (bytes, input.len())
+    }
+
+    #[test]
+    fn test_round_trip() {
+        for input in ["", "A", "AB", "ABC", "ABCD", "DSK:FOO.BAR", "THE QUICK BROWN FOX JUMPS OVER THE LAZY DOG"] {
+            let (bytes, len) = encode_heapless::<48>(input).unwrap();
+            assert_eq!(bytes.as_slice(), encode(input).0.as_slice(), "{input:?}");
+            assert_eq!(len, input.len());
+            assert_eq!(decode_heapless::<64>(&bytes, len).unwrap(), input);
+        }
+    }
+
+    #[test]
+    fn test_exact_fit() {
+        let (bytes, len) = encode_heapless::<3>("ABCD").unwrap();
+        assert_eq!((bytes.len(), len), (3, 4));
+        let (bytes, len) = encode_heapless::<4>("ABCDE").unwrap();
+        assert_eq!((bytes.len(), len), (4, 5));
+        assert_eq!(decode_heapless::<5>(&bytes, len).unwrap(), "ABCDE");
+        assert_eq!(decode_heapless::<0>(&[], 0).unwrap(), "");
+    }
+
+    #[test]
+    fn test_over_capacity() {
+        assert_eq!(encode_heapless::<2>("ABCD"), Err(Error::TooLong { len: 4, max: 2 }));
+        assert_eq!(encode_heapless::<3>("ABCDE"), Err(Error::TooLong { len: 5, max: 4 }));
+        assert_eq!(encode_heapless::<0>("A"), Err(Error::TooLong { len: 1, max: 0 }));
+        let (bytes, len) = encode("ABCDE");
+        assert_eq!(decode_heapless::<4>(&bytes, len), Err(Error::TooLong { len: 5, max: 4 }));
+    }
+
+    #[test]
+    fn test_errors() {
+        assert_eq!(encode_heapless::<8>("lower"), Err(Error::InvalidCharacter));
+        assert_eq!(encode_heapless::<8>("ÄB"), Err(Error::InvalidCharacter));
+        assert_eq!(decode_heapless::<8>(&[0, 0], 4), Err(Error::InvalidBytesLength));
+        assert_eq!(decode_heapless::<8>(&[0x80], usize::MAX / 6 + 1), Err(Error::InvalidBytesLength));
+    }
+
+    #[test]
+    fn test_does_not_allocate() {
+        let ((bytes, len), allocations) = count_allocations(|| encode_heapless::<16>("SYS:LOGIN.SAV").unwrap());
+        assert_eq!(allocations, 0);
+        let (decoded, allocations) = count_allocations(|| decode_heapless::<16>(&bytes, len).unwrap());
+        assert_eq!((decoded.as_str(), allocations), ("SYS:LOGIN.SAV", 0));
+    }
+}
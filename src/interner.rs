@@ -0,0 +1,211 @@
+//! Provides `SixbitInterner`, which maps distinct SIXBIT values to dense `u32` ids.
+//!
+//! All interned values are packed back to back in a single byte arena, with an offset table marking
+//! where each one starts, so interning a value never allocates for the value itself. Ids are looked up
+//! in an open-addressing table hashed like `str` (see [`DecSixbitStr::hash_as_str`]), which lets a
+//! `&str` be found by comparing it with the packed characters directly, without encoding it first.
+
+use crate::{DecSixbit, DecSixbitStr, Error};
+use std::collections::hash_map::RandomState;
+use std::hash::BuildHasher;
+
+/// Marks an empty slot of the lookup table.
+const EMPTY: u32 = u32::MAX;
+
+/// Maps distinct SIXBIT values to `u32` ids, numbered from 0 in the order they were first interned.
+///
+/// # Examples
+///
+/// ```rust
+/// use dec_sixbit::SixbitInterner;
+///
+/// let mut interner = SixbitInterner::new();
+/// let dsk = interner.intern("DSK").unwrap();
+/// let sys = interner.intern("SYS").unwrap();
+/// assert_eq!((dsk, sys), (0, 1));
+/// assert_eq!(interner.intern("DSK").unwrap(), dsk);
+/// assert_eq!(interner.resolve(sys).unwrap(), "SYS");
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct SixbitInterner {
+    /// The packed bytes of every value, including any trailing-space marker, back to back.
+    arena: Vec<u8>,
+    /// The end offset in `arena` of each value; a value starts where the previous one ends.
+    ends: Vec<usize>,
+    /// Ids by hash, with linear probing. The length is zero or a power of two.
+    table: Vec<u32>,
+    hasher: RandomState,
+}
+
+impl SixbitInterner {
+    /// Creates an empty interner.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the number of distinct values interned.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.ends.len()
+    }
+
+    /// Returns `true` if no values have been interned.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.ends.is_empty()
+    }
+
+    /// Returns the id of `str`, interning it if it is new.
+    ///
+    /// Looking up a value that is already interned doesn't allocate.
+    ///
+    /// # Parameters
+    /// - `str`: The value. Must contain only ASCII characters in the range 32-95.
+    ///
+    /// # Errors
+    /// Returns an [`Error::InvalidCharacter`] if `str` contains invalid characters.
+    ///
+    /// # Panics
+    /// Panics if `u32::MAX` distinct values have already been interned.
+    pub fn intern(&mut self, str: &str) -> Result<u32, Error> {
+        let hash = self.hasher.hash_one(str);
+        if let Some(id) = self.find(hash, |value| value == str) {
+            return Ok(id);
+        }
+        let sixbit = DecSixbit::new(str)?;
+        Ok(self.insert(hash, &sixbit))
+    }
+
+    /// Returns the id of `sixbit`, interning it if it is new.
+    ///
+    /// # Parameters
+    /// - `sixbit`: The value.
+    ///
+    /// # Panics
+    /// Panics if `u32::MAX` distinct values have already been interned.
+    pub fn intern_sixbit(&mut self, sixbit: &DecSixbitStr) -> u32 {
+        let hash = self.hasher.hash_one(sixbit);
+        match self.find(hash, |value| value == sixbit) {
+            Some(id) => id,
+            None => self.insert(hash, sixbit),
+        }
+    }
+
+    /// Returns the value with the given id, or `None` if no value has that id.
+    ///
+    /// # Parameters
+    /// - `id`: An id returned by this interner.
+    pub fn resolve(&self, id: u32) -> Option<&DecSixbitStr> {
+        let id = id as usize;
+        let end = *self.ends.get(id)?;
+        let start = if id == 0 { 0 } else { self.ends[id - 1] };
+        Some(DecSixbitStr::from_bytes_unchecked(&self.arena[start..end]))
+    }
+
+    /// Returns the id of the value with the given hash for which `eq` returns `true`.
+    fn find(&self, hash: u64, eq: impl Fn(&DecSixbitStr) -> bool) -> Option<u32> {
+        if self.table.is_empty() {
+            return None;
+        }
+        let mask = self.table.len() - 1;
+        let mut slot = hash as usize & mask;
+        loop {
+            match self.table[slot] {
+                EMPTY => return None,
+                id if eq(self.resolve(id).unwrap()) => return Some(id),
+                _ => slot = (slot + 1) & mask,
+            }
+        }
+    }
+
+    /// Appends `sixbit`, which must not be interned yet, and returns its new id.
+    fn insert(&mut self, hash: u64, sixbit: &DecSixbitStr) -> u32 {
+        let id = u32::try_from(self.len()).ok().filter(|&id| id != EMPTY).expect("interner is full");
+        // Keep the table at most half full so probe sequences stay short
+        if (self.len() + 1) * 2 > self.table.len() {
+            self.grow();
+        }
+        self.arena.extend_from_slice(sixbit.as_bytes());
+        self.ends.push(self.arena.len());
+        self.place(hash, id);
+        id
+    }
+
+    /// Puts `id` into the first empty slot for `hash`.
+    fn place(&mut self, hash: u64, id: u32) {
+        let mask = self.table.len() - 1;
+        let mut slot = hash as usize & mask;
+        while self.table[slot] != EMPTY {
+            slot = (slot + 1) & mask;
+        }
+        self.table[slot] = id;
+    }
+
+    /// Doubles the lookup table and re-places every id.
+    fn grow(&mut self) {
+        self.table = vec![EMPTY; (self.table.len() * 2).max(16)];
+        for id in 0..self.len() as u32 {
+            let hash = self.hasher.hash_one(self.resolve(id).unwrap());
+            self.place(hash, id);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SixbitInterner;
+    use crate::{DecSixbit, Error};
+
+    #[test]
+    fn test_intern_and_resolve() {
+        let mut interner = SixbitInterner::new();
+        assert!(interner.is_empty());
+        let inputs = ["DSK", "SYS", "", "ABC ", "ABC", "DSK", "ABC ", ""];
+        let ids: Vec<u32> = inputs.iter().map(|input| interner.intern(input).unwrap()).collect();
+        assert_eq!(ids, [0, 1, 2, 3, 4, 0, 3, 2]);
+        assert_eq!(interner.len(), 5);
+        for (input, id) in inputs.iter().zip(ids) {
+            assert_eq!(interner.resolve(id).unwrap(), *input);
+        }
+        assert_eq!(interner.resolve(5), None);
+
+        assert_eq!(interner.intern_sixbit(&DecSixbit::new("SYS").unwrap()), 1);
+        assert_eq!(interner.intern_sixbit(&DecSixbit::new("LPT").unwrap()), 5);
+        assert_eq!(interner.intern("LPT"), Ok(5));
+        assert_eq!(interner.intern("lpt"), Err(Error::InvalidCharacter));
+        assert_eq!(interner.len(), 6);
+    }
+
+    #[test]
+    fn test_many_labels() {
+        // A fixed-seed linear congruential generator keeps the labels reproducible
+        let mut state = 0x2545_F491_4F6C_DD1Du64;
+        let mut next = move || {
+            state = state.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+            (state >> 33) as usize
+        };
+        let labels: Vec<String> = (0..100_000)
+            .map(|_| {
+                let len = 1 + next() % 12;
+                (0..len).map(|_| (b' ' + (next() % 64) as u8) as char).collect()
+            })
+            .collect();
+
+        let mut interner = SixbitInterner::new();
+        let ids: Vec<u32> = labels.iter().map(|label| interner.intern(label).unwrap()).collect();
+        let mut distinct = labels.clone();
+        distinct.sort();
+        distinct.dedup();
+        assert_eq!(interner.len(), distinct.len());
+        // Ids are dense and assigned in order of first appearance
+        let mut max = None;
+        for &id in &ids {
+            assert!(max.map_or(id == 0, |max| id <= max + 1));
+            max = max.max(Some(id));
+        }
+        for (label, &id) in labels.iter().zip(&ids) {
+            assert_eq!(interner.resolve(id).unwrap(), label.as_str());
+            assert_eq!(interner.intern(label), Ok(id));
+        }
+    }
+}
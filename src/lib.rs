@@ -1,14 +1,150 @@
 #![doc = include_str!("../README.md")]
+#![allow(clippy::needless_doctest_main)]
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+#![cfg_attr(feature = "nightly", feature(ascii_char))]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(feature = "with-struct")]
+use alloc::vec::Vec;
 
 mod encode;
 mod decode;
+mod sixbit_char;
+mod stream;
+mod slice;
+mod word36;
+pub mod rad50;
+pub mod raw;
+#[cfg_attr(not(feature = "with-struct"), allow(dead_code))]
+mod bits;
+#[cfg(feature = "with-struct")]
+mod display;
 #[cfg(feature = "with-struct")]
 mod struct_api;
-
+#[cfg(feature = "with-struct")]
+mod sixbit_str;
+#[cfg(feature = "with-struct")]
+mod shared;
+#[cfg(feature = "with-struct")]
+mod sixbit_array;
+#[cfg(feature = "with-struct")]
+mod sixbit6;
+#[cfg(feature = "with-struct")]
+mod writer;
+#[cfg(feature = "with-struct")]
+mod record;
+#[cfg(feature = "std")]
+mod interner;
+#[cfg(feature = "with-struct")]
+mod builder;
+#[cfg(feature = "serde")]
+pub mod serde_helpers;
+#[cfg(feature = "rkyv")]
+mod archive;
+#[cfg(feature = "borsh")]
+mod borsh_impl;
+#[cfg(feature = "bincode2")]
+mod bincode_impl;
+#[cfg(feature = "minicbor")]
+mod minicbor_impl;
+#[cfg(any(all(test, feature = "alloc"), feature = "proptest"))]
+pub mod proptest;
+#[cfg(any(all(test, feature = "alloc"), feature = "quickcheck"))]
+mod quickcheck_impl;
+#[cfg(feature = "rand")]
+mod random;
+#[cfg(feature = "rusqlite")]
+mod rusqlite_impl;
+#[cfg(feature = "sqlx-postgres")]
+mod sqlx_impl;
+#[cfg(any(feature = "hashbrown", feature = "indexmap"))]
+mod equivalent_impl;
+#[cfg(feature = "compact_str")]
+mod compact_str_impl;
+#[cfg(feature = "smol_str")]
+mod smol_str_impl;
+#[cfg(feature = "ascii")]
+mod ascii_impl;
+#[cfg(all(feature = "nightly", feature = "alloc"))]
+mod ascii_char_impl;
+#[cfg(feature = "defmt")]
+mod defmt_impl;
+#[cfg(feature = "heapless")]
+mod heapless_impl;
+#[cfg(feature = "embedded-io")]
+mod embedded_io_impl;
+#[cfg(any(feature = "allocative", feature = "deepsize"))]
+mod memory_size_impl;
+#[cfg(feature = "bytes")]
+mod bytes_impl;
+#[cfg(feature = "base64")]
+mod base64_impl;
+#[cfg(feature = "wasm")]
+pub mod wasm;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+#[cfg(feature = "python")]
+pub mod python;
+#[cfg(all(test, any(feature = "hashbrown", feature = "indexmap", feature = "compact_str", feature = "smol_str", feature = "heapless")))]
+mod test_alloc;
+#[cfg(all(test, feature = "alloc"))]
+#[allow(dead_code)]
+mod test_fixtures;
+
+#[cfg(feature = "alloc")]
 pub use encode::{encode, encode_unchecked};
-pub use decode::{decode, decode_unchecked};
+pub use encode::{encode_u128, encode_u64, u64_to_u128};
+#[cfg(feature = "alloc")]
+pub use decode::{decode, decode_lowercase, decode_trimmed, decode_u128, decode_u64, decode_unchecked, decode_values};
+pub use decode::u128_to_u64;
+pub use slice::{char_at, decode_chunk, decode_to_slice, encode_chunk, encode_to_slice, encoded_len, integrity_check, validate};
+pub use sixbit_char::{char_to_value, is_sixbit_char, value_to_char, SixbitChar, SIXBIT_CHARS};
+pub use stream::{SixbitDecoder, SixbitEncoder};
+pub use word36::{encode_tops10_filename, encode_tops10_filename_uppercase, WordLayout};
+#[cfg(feature = "alloc")]
+pub use word36::decode_tops10_filename;
 #[cfg(feature = "with-struct")]
 pub use struct_api::DecSixbit;
+#[cfg(feature = "with-struct")]
+pub use sixbit_str::{CharIndices, Chars, DecSixbitStr, Windows};
+#[cfg(feature = "with-struct")]
+pub use shared::SharedDecSixbit;
+#[cfg(feature = "with-struct")]
+pub use sixbit_array::{DecSixbit12, DecSixbit16, DecSixbit6, DecSixbit8, DecSixbitArray};
+#[cfg(feature = "with-struct")]
+pub use sixbit6::Sixbit6;
+#[cfg(feature = "with-struct")]
+pub use writer::SixbitWriter;
+#[cfg(feature = "with-struct")]
+pub use record::SixbitRecordBuilder;
+#[cfg(feature = "std")]
+pub use interner::SixbitInterner;
+#[cfg(feature = "with-struct")]
+pub use builder::DecSixbitBuilder;
+#[cfg(feature = "rkyv")]
+pub use archive::ArchivedDecSixbit;
+#[cfg(any(all(test, feature = "alloc"), feature = "quickcheck"))]
+pub use quickcheck_impl::ValidSixbitString;
+#[cfg(feature = "rand")]
+pub use random::{random_sixbit_string, AlphanumericSixbit, SixbitDistribution};
+#[cfg(feature = "rusqlite")]
+pub use rusqlite_impl::SixbitBlob;
+#[cfg(feature = "sqlx-postgres")]
+pub use sqlx_impl::SixbitBytea;
+#[cfg(feature = "ascii")]
+pub use ascii_impl::{decode_to_ascii_string, encode_ascii};
+#[cfg(all(feature = "nightly", feature = "alloc"))]
+pub use ascii_char_impl::{decode_to_ascii, encode_from_ascii};
+#[cfg(feature = "heapless")]
+pub use heapless_impl::{decode_heapless, encode_heapless};
+#[cfg(feature = "bytes")]
+pub use bytes_impl::{decode_from_bytes, encode_to_bytes_mut, SixbitBytes};
+#[cfg(feature = "base64")]
+pub use base64_impl::{decode_base64, decode_base64_url, encode_base64, encode_base64_url};
+#[cfg(feature = "embedded-io")]
+pub use embedded_io_impl::{SixbitDecodeReader, SixbitEncodeWriter, SixbitIoError};
 
 const MASK_TWO_BITS: u8 = 0b11;
 const MASK_FOUR_BITS: u8 = 0b1111;
@@ -17,9 +153,17 @@ const SHIFT_TWO_BITS: u8 = 2;
 const SHIFT_FOUR_BITS: u8 = 4;
 const SHIFT_SIX_BITS: u8 = 6;
 const ASCII_OFFSET: u8 = 32;
+/// The number of characters that fit in a `u64`, 6 bits each.
+const U64_CHARS: usize = 10;
+/// The number of characters that fit in a `u128`, 6 bits each.
+const U128_CHARS: usize = 21;
 
 /// Represents errors that can occur during encoding or decoding operations.
+///
+/// New variants may be added in minor releases, so matches need a wildcard arm. Code that only
+/// needs to tell failures apart can match on [`Error::kind`] instead, which carries no payloads.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, thiserror::Error)]
+#[non_exhaustive]
 pub enum Error {
     /// Occurs when encoding fails due to invalid character in input.
     #[error("invalid character in input (must be ASCII 32-95)")]
@@ -28,9 +172,224 @@ pub enum Error {
     /// Occurs when decoding fails due to inconsistent input bytes and length.
     #[error("input bytes and length are inconsistent")]
     InvalidBytesLength,
+
+    /// Occurs when a number has more digits than the width of the field it is written into.
+    #[error("value does not fit in the field width")]
+    FieldOverflow,
+
+    /// Occurs when a character index or range lies outside the value.
+    #[error("index out of bounds")]
+    OutOfBounds,
+
+    /// Occurs when the input does not have the exact length required by a fixed-length type.
+    #[error("input length does not match the fixed length")]
+    LengthMismatch,
+
+    /// Occurs when packed bytes end in a trailing-space marker that the content doesn't need.
+    #[error("unexpected trailing-space marker")]
+    UnexpectedMarker,
+
+    /// Occurs when the unused bits after the last character of packed bytes are not zero.
+    #[error("nonzero padding bits after the last character")]
+    NonzeroPadding,
+
+    /// Occurs when a string has more characters than the width of the field it is written into.
+    #[error("input of {len} characters does not fit in {max}")]
+    TooLong {
+        /// The number of characters in the input.
+        len: usize,
+        /// The width of the field.
+        max: usize,
+    },
+
+    /// Occurs when a block size is zero or not a multiple of the 3-byte group size.
+    #[error("block size must be a nonzero multiple of 3")]
+    InvalidBlockSize,
+
+    /// Occurs when a framed value starts with a version byte this release doesn't know.
+    #[error("unsupported frame version {0}")]
+    UnsupportedVersion(u8),
+
+    /// Occurs when text to be decoded is not valid base64.
+    #[error("invalid base64 input")]
+    InvalidBase64,
+
+    /// Occurs when text to be decoded is not a sequence of two-digit hex bytes.
+    #[error("invalid hex input")]
+    InvalidHex,
+}
+
+impl Error {
+    /// Returns the kind of the error, without the details carried by some variants.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use dec_sixbit::{Error, ErrorKind};
+    ///
+    /// let error = Error::TooLong { len: 8, max: 6 };
+    /// assert_eq!(error.kind(), ErrorKind::TooLong);
+    /// ```
+    pub fn kind(&self) -> ErrorKind {
+        match self {
+            Error::InvalidCharacter => ErrorKind::InvalidCharacter,
+            Error::InvalidBytesLength => ErrorKind::InvalidBytesLength,
+            Error::FieldOverflow => ErrorKind::FieldOverflow,
+            Error::OutOfBounds => ErrorKind::OutOfBounds,
+            Error::LengthMismatch => ErrorKind::LengthMismatch,
+            Error::UnexpectedMarker => ErrorKind::UnexpectedMarker,
+            Error::NonzeroPadding => ErrorKind::NonzeroPadding,
+            Error::TooLong { .. } => ErrorKind::TooLong,
+            Error::InvalidBlockSize => ErrorKind::InvalidBlockSize,
+            Error::UnsupportedVersion(_) => ErrorKind::UnsupportedVersion,
+            Error::InvalidBase64 => ErrorKind::InvalidBase64,
+            Error::InvalidHex => ErrorKind::InvalidHex,
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<Error> for std::io::Error {
+    /// Wraps the error in an [`InvalidData`](std::io::ErrorKind::InvalidData) I/O error, from which it
+    /// can be recovered with [`std::io::Error::downcast`].
+    fn from(error: Error) -> Self {
+        std::io::Error::new(std::io::ErrorKind::InvalidData, error)
+    }
+}
+
+/// The kind of an [`Error`], one for each variant without its payload.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[non_exhaustive]
+pub enum ErrorKind {
+    /// See [`Error::InvalidCharacter`].
+    InvalidCharacter,
+    /// See [`Error::InvalidBytesLength`].
+    InvalidBytesLength,
+    /// See [`Error::FieldOverflow`].
+    FieldOverflow,
+    /// See [`Error::OutOfBounds`].
+    OutOfBounds,
+    /// See [`Error::LengthMismatch`].
+    LengthMismatch,
+    /// See [`Error::UnexpectedMarker`].
+    UnexpectedMarker,
+    /// See [`Error::NonzeroPadding`].
+    NonzeroPadding,
+    /// See [`Error::TooLong`].
+    TooLong,
+    /// See [`Error::InvalidBlockSize`].
+    InvalidBlockSize,
+    /// See [`Error::UnsupportedVersion`].
+    UnsupportedVersion,
+    /// See [`Error::InvalidBase64`].
+    InvalidBase64,
+    /// See [`Error::InvalidHex`].
+    InvalidHex,
 }
 
-#[cfg(test)]
+/// Describes which check [`integrity_check`] found violated in stored packed bytes, and where.
+///
+/// Offsets are byte positions in the checked slice.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, thiserror::Error)]
+#[non_exhaustive]
+pub enum IntegrityError {
+    /// Occurs when the number of bytes doesn't fit the length, with or without a marker.
+    #[error("expected {expected} bytes, found {found}")]
+    ByteCount {
+        /// The number of bytes the length calls for, or `usize::MAX` if no slice could hold that many.
+        expected: usize,
+        /// The number of bytes given.
+        found: usize,
+    },
+
+    /// Occurs when the unused bits after the last character are set.
+    #[error("padding bits set in byte {offset}")]
+    NonzeroPadding {
+        /// The offset of the last payload byte.
+        offset: usize,
+    },
+
+    /// Occurs when the content ends in a space at a multiple of 4 characters but the marker byte is absent.
+    #[error("missing trailing-space marker at byte {offset}")]
+    MissingMarker {
+        /// The offset at which the marker belongs.
+        offset: usize,
+    },
+
+    /// Occurs when the bytes end in a marker the content doesn't need.
+    #[error("unexpected trailing-space marker at byte {offset}")]
+    UnexpectedMarker {
+        /// The offset of the marker byte.
+        offset: usize,
+    },
+
+    /// Occurs when the byte in the marker position is not the trailing-space marker.
+    #[error("invalid marker byte {found:#04x} at byte {offset}")]
+    InvalidMarker {
+        /// The offset of the marker byte.
+        offset: usize,
+        /// The byte found instead of the marker.
+        found: u8,
+    },
+}
+
+impl From<IntegrityError> for Error {
+    /// Maps the failed check to the error the strict constructors report for it.
+    fn from(error: IntegrityError) -> Self {
+        match error {
+            IntegrityError::NonzeroPadding { .. } => Error::NonzeroPadding,
+            IntegrityError::UnexpectedMarker { .. } => Error::UnexpectedMarker,
+            IntegrityError::ByteCount { .. } | IntegrityError::MissingMarker { .. } | IntegrityError::InvalidMarker { .. } => Error::InvalidBytesLength,
+        }
+    }
+}
+
+/// Represents errors that can occur when parsing the content of a space-padded field.
+///
+/// This error is returned by [`DecSixbitStr::parse`].
+#[cfg(feature = "with-struct")]
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum ParseFieldError<E> {
+    /// Occurs when the field is empty or contains only spaces.
+    #[error("field is blank")]
+    Blank,
+
+    /// Occurs when the trimmed field content could not be parsed into the target type.
+    #[error("invalid field content: {0}")]
+    Invalid(#[source] E),
+}
+
+/// Represents every invalid character found while building a value, returned by
+/// [`DecSixbitBuilder::finish_strict`].
+///
+/// There is always at least one.
+#[cfg(feature = "with-struct")]
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[error("{} invalid characters, the first {:?} at position {}", .errors.len(), .errors[0].1, .errors[0].0)]
+pub struct InvalidCharacters {
+    errors: Vec<(usize, char)>,
+}
+
+#[cfg(feature = "with-struct")]
+impl InvalidCharacters {
+    /// Wraps a nonempty list of invalid characters.
+    pub(crate) fn new(errors: Vec<(usize, char)>) -> Self {
+        debug_assert!(!errors.is_empty());
+        Self { errors }
+    }
+
+    /// Returns the invalid characters with their positions in the output, in order.
+    pub fn errors(&self) -> &[(usize, char)] {
+        &self.errors
+    }
+
+    /// Returns the invalid characters with their positions, consuming the error.
+    pub fn into_errors(self) -> Vec<(usize, char)> {
+        self.errors
+    }
+}
+
+#[cfg(all(test, feature = "alloc"))]
 mod tests {
     use super::*;
 
@@ -41,40 +400,28 @@ mod tests {
     fn test_packed_storage() {
         let input = "ABCD"; // 4 chars should pack into 3 bytes
 
-        // Calculate expected values
-        let a = 65 - 32; // = 33 = 0b100001
-        let b = 66 - 32; // = 34 = 0b100010
-        let c = 67 - 32; // = 35 = 0b100011
-        let d = 68 - 32; // = 36 = 0b100100
-
-        use std::io::Write;
-        let stderr = std::io::stderr();
-        let mut handle = stderr.lock();
-
-        writeln!(handle, "Input SIXBIT values:").unwrap();
-        writeln!(handle, "  A: dec={} bin={:06b}", a, a).unwrap();
-        writeln!(handle, "  B: dec={} bin={:06b}", b, b).unwrap();
-        writeln!(handle, "  C: dec={} bin={:06b}", c, c).unwrap();
-        writeln!(handle, "  D: dec={} bin={:06b}", d, d).unwrap();
-
-        // Correct expected bytes based on encode_core logic
+        // A = 33 = 0b100001, B = 34 = 0b100010, C = 35 = 0b100011, D = 36 = 0b100100
+        // byte 1 = (A<<2 | B>>4), byte 2 = (B<<4 | C>>2), byte 3 = (C<<6 | D)
         let expected = vec![0b10000110, 0b00101000, 0b11100100];
-        writeln!(handle, "\nExpected packed bytes:").unwrap();
-        writeln!(handle, "  byte 1 = {:08b} = (A<<2 | B>>4)", expected[0]).unwrap();
-        writeln!(handle, "  byte 2 = {:08b} = (B<<4 | C>>2)", expected[1]).unwrap();
-        writeln!(handle, "  byte 3 = {:08b} = (C<<6 | D)", expected[2]).unwrap();
+        assert_eq!(encode(input).unwrap(), (expected.clone(), 4));
 
         // Encapsulated API test
         #[cfg(feature = "with-struct")]
         {
             let sixbit = DecSixbit::new(input).unwrap();
-            writeln!(handle, "\nActual packed bytes:  ").unwrap();
-            writeln!(handle, "  byte 1 = {:08b}", sixbit.bytes[0]).unwrap();
-            writeln!(handle, "  byte 2 = {:08b}", sixbit.bytes[1]).unwrap();
-            writeln!(handle, "  byte 3 = {:08b}", sixbit.bytes[2]).unwrap();
-
-            assert_eq!(sixbit.bytes, expected);
-            assert_eq!(sixbit.len, 4);
+            let dump = sixbit.dump();
+            let lines: Vec<&str> = dump.lines().collect();
+            assert_eq!(
+                lines,
+                [
+                    "bytes:  100001 10|0010 1000|11 100100",
+                    "fields: 100001|10 0010|1000 11|100100",
+                    "chars:    A       B      C       D",
+                ]
+            );
+
+            assert_eq!(sixbit.as_bytes(), expected);
+            assert_eq!(sixbit.len(), 4);
         }
     }
 
@@ -135,8 +482,8 @@ mod tests {
             #[cfg(feature = "with-struct")]
             {
                 let sixbit = DecSixbit::new(input).unwrap();
-                assert_eq!(sixbit.bytes, bytes);
-                assert_eq!(sixbit.len, len);
+                assert_eq!(sixbit.as_bytes(), bytes);
+                assert_eq!(sixbit.len(), len);
                 assert_eq!(sixbit.to_string(), decoded);
             }
         }
@@ -179,13 +526,13 @@ mod tests {
         {
             let sixbit = DecSixbit::new("").unwrap();
             assert!(sixbit.is_empty());
-            assert_eq!(sixbit.len, 0);
+            assert_eq!(sixbit.len(), 0);
             assert!(sixbit.as_bytes().is_empty());
 
-            let decoded = decode(&sixbit.bytes, sixbit.len).unwrap();
+            let decoded = decode(sixbit.as_bytes(), sixbit.len()).unwrap();
             assert_eq!(decoded, "");
 
-            let decoded_unchecked = decode_unchecked(&sixbit.bytes, sixbit.len);
+            let decoded_unchecked = decode_unchecked(sixbit.as_bytes(), sixbit.len());
             assert_eq!(decoded_unchecked, "");
         }
     }
@@ -196,7 +543,7 @@ mod tests {
         {
             let sixbit = DecSixbit::default();
             assert!(sixbit.is_empty());
-            assert_eq!(sixbit.len, 0);
+            assert_eq!(sixbit.len(), 0);
             assert!(sixbit.as_bytes().is_empty());
         }
     }
@@ -250,15 +597,15 @@ mod tests {
         #[cfg(feature = "with-struct")]
         {
             let sixbit = DecSixbit::new(input).unwrap();
-            let decoded = decode::decode(&sixbit.bytes, sixbit.len).unwrap();
+            let decoded = decode::decode(sixbit.as_bytes(), sixbit.len()).unwrap();
             assert_eq!(decoded, input);
 
-            let decoded_unchecked = decode_unchecked(&sixbit.bytes, sixbit.len);
+            let decoded_unchecked = decode_unchecked(sixbit.as_bytes(), sixbit.len());
             assert_eq!(decoded_unchecked, input);
         }
     }
 
-    #[cfg(feature = "with-struct")]
+    #[cfg(feature = "serde")]
     #[test]
     fn test_serde_serialization_readable() {
         let input = "TEST SERIALIZATION";
@@ -275,7 +622,7 @@ mod tests {
         assert_eq!(sixbit, deserialized);
     }
 
-    #[cfg(feature = "with-struct")]
+    #[cfg(feature = "serde")]
     #[test]
     fn test_serde_deserialization_binary() {
         use bincode::Options;
@@ -297,7 +644,7 @@ mod tests {
         assert_eq!(sixbit, deserialized);
     }
 
-    #[cfg(feature = "with-struct")]
+    #[cfg(feature = "serde")]
     #[test]
     fn test_serde_readable_and_binary() {
         let input = "COMPLEX SERIALIZATION TEST";
@@ -320,7 +667,7 @@ mod tests {
         assert_eq!(sixbit, from_binary);
     }
 
-    #[cfg(feature = "with-struct")]
+    #[cfg(feature = "serde")]
     #[test]
     fn test_serde_roundtrip() {
         let inputs = [
@@ -367,4 +714,16 @@ mod tests {
         // The last byte contains DecSixbit::TRAILING_SPACE_MARKER
         assert_eq!(sixbit.as_bytes().len(), 7);
     }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_io_error_conversion() {
+        for error in [Error::InvalidCharacter, Error::TooLong { len: 8, max: 6 }, Error::UnsupportedVersion(7)] {
+            let io_error = std::io::Error::from(error);
+            assert_eq!(io_error.kind(), std::io::ErrorKind::InvalidData);
+            assert_eq!(io_error.to_string(), error.to_string());
+            assert_eq!(io_error.downcast::<Error>().unwrap(), error);
+        }
+        assert_eq!(Error::UnsupportedVersion(7).kind(), ErrorKind::UnsupportedVersion);
+    }
 }
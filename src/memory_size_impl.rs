@@ -0,0 +1,100 @@
+//! Memory accounting for `DecSixbit` and `SharedDecSixbit` with [allocative](https://docs.rs/allocative)
+//! and [deepsize](https://docs.rs/deepsize).
+//!
+//! A `DecSixbit` reports its heap buffer including reserved capacity, and nothing when its bytes are
+//! stored inline. A `SharedDecSixbit` reports its bytes as shared, so clones of one value are counted once.
+
+#[cfg(feature = "allocative")]
+mod allocative_impl {
+    use crate::{DecSixbit, SharedDecSixbit};
+    use allocative::{Allocative, Key, Visitor};
+
+    impl Allocative for DecSixbit {
+        fn visit<'a, 'b: 'a>(&self, visitor: &'a mut Visitor<'b>) {
+            let mut visitor = visitor.enter_self_sized::<Self>();
+            let heap_size = self.heap_size();
+            if heap_size != 0 {
+                let mut buffer = visitor.enter_unique(Key::new("buffer"), core::mem::size_of::<*const u8>());
+                buffer.visit_simple(Key::new("bytes"), heap_size);
+                buffer.exit();
+            }
+            visitor.exit();
+        }
+    }
+
+    impl Allocative for SharedDecSixbit {
+        fn visit<'a, 'b: 'a>(&self, visitor: &'a mut Visitor<'b>) {
+            let mut visitor = visitor.enter_self_sized::<Self>();
+            visitor.visit_field(Key::new("bytes"), &self.bytes);
+            visitor.exit();
+        }
+    }
+}
+
+#[cfg(feature = "deepsize")]
+mod deepsize_impl {
+    use crate::{DecSixbit, SharedDecSixbit};
+    use deepsize::{Context, DeepSizeOf};
+
+    impl DeepSizeOf for DecSixbit {
+        fn deep_size_of_children(&self, _: &mut Context) -> usize {
+            self.heap_size()
+        }
+    }
+
+    impl DeepSizeOf for SharedDecSixbit {
+        /// Counts the shared bytes the first time they are seen in `context`.
+        fn deep_size_of_children(&self, context: &mut Context) -> usize {
+            self.bytes.deep_size_of_children(context)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{DecSixbit, SharedDecSixbit};
+    use core::mem::size_of;
+
+    /// A value long enough to be stored on the heap.
+    const LONG: &str = "THE QUICK BROWN FOX JUMPS OVER THE LAZY DOG";
+
+    #[cfg(feature = "allocative")]
+    #[test]
+    fn test_allocative_size() {
+        use allocative::{size_of_unique, size_of_unique_allocated_data};
+
+        let sixbit = DecSixbit::new(LONG).unwrap();
+        assert_eq!(size_of_unique(&sixbit), size_of::<DecSixbit>() + sixbit.as_bytes().len());
+
+        let mut reserved = DecSixbit::with_capacity(100);
+        reserved.push_str(LONG).unwrap();
+        // 100 characters take 75 bytes and one more for the trailing-space marker
+        assert_eq!(size_of_unique(&reserved), size_of::<DecSixbit>() + 76);
+
+        assert_eq!(size_of_unique_allocated_data(&DecSixbit::new("").unwrap()), 0);
+        assert_eq!(size_of_unique_allocated_data(&DecSixbit::new("DSK:FOO.BAR").unwrap()), 0);
+
+        // Shared bytes are not owned by any one handle
+        let shared = SharedDecSixbit::new(LONG).unwrap();
+        assert_eq!(size_of_unique(&shared), size_of::<SharedDecSixbit>());
+    }
+
+    #[cfg(feature = "deepsize")]
+    #[test]
+    fn test_deepsize_size() {
+        use deepsize::DeepSizeOf;
+
+        let sixbit = DecSixbit::new(LONG).unwrap();
+        assert_eq!(sixbit.deep_size_of(), size_of::<DecSixbit>() + sixbit.as_bytes().len());
+
+        let mut reserved = DecSixbit::with_capacity(100);
+        reserved.push_str(LONG).unwrap();
+        assert_eq!(reserved.deep_size_of(), size_of::<DecSixbit>() + 76);
+
+        assert_eq!(DecSixbit::new("").unwrap().deep_size_of(), size_of::<DecSixbit>());
+
+        let shared = SharedDecSixbit::new(LONG).unwrap();
+        let copies = vec![shared.clone(), shared.clone(), shared];
+        assert_eq!(copies.deep_size_of(), size_of::<Vec<SharedDecSixbit>>() + 3 * size_of::<SharedDecSixbit>() + 33);
+    }
+}
@@ -0,0 +1,98 @@
+//! [minicbor](https://docs.rs/minicbor) `Encode` and `Decode` support for `DecSixbit` and `DecSixbitStr`.
+//!
+//! A value is encoded as a definite 2-element array of its length in characters and its packed bytes,
+//! the same layout the serde binary format produces in CBOR. The dependency is built without `std`,
+//! so only `alloc` is required of minicbor.
+//!
+//! Decoding into `&DecSixbitStr` borrows the packed bytes from the input without copying. Both decodes
+//! reject an array of another length, an indefinite-length array or byte string, and a length that is
+//! inconsistent with the bytes.
+
+use crate::{DecSixbit, DecSixbitStr};
+use alloc::borrow::ToOwned;
+use minicbor::decode::{self, Decoder};
+use minicbor::encode::{self, Encoder, Write};
+use minicbor::{Decode, Encode};
+
+impl<C> Encode<C> for DecSixbitStr {
+    fn encode<W: Write>(&self, e: &mut Encoder<W>, _ctx: &mut C) -> Result<(), encode::Error<W::Error>> {
+        e.array(2)?.u64(self.len() as u64)?.bytes(self.as_bytes())?.ok()
+    }
+}
+
+impl<C> Encode<C> for DecSixbit {
+    fn encode<W: Write>(&self, e: &mut Encoder<W>, ctx: &mut C) -> Result<(), encode::Error<W::Error>> {
+        self.as_sixbit_str().encode(e, ctx)
+    }
+}
+
+impl<'b, C> Decode<'b, C> for &'b DecSixbitStr {
+    fn decode(d: &mut Decoder<'b>, _ctx: &mut C) -> Result<Self, decode::Error> {
+        let start = d.position();
+        if d.array()? != Some(2) {
+            return Err(decode::Error::message("expected a definite array of length and bytes").at(start));
+        }
+        let len = d.u64()?;
+        let bytes_start = d.position();
+        let bytes = d.bytes()?;
+        usize::try_from(len)
+            .ok()
+            .and_then(|len| DecSixbitStr::new(bytes, len).ok())
+            .ok_or_else(|| decode::Error::message("length is inconsistent with the packed bytes").at(bytes_start))
+    }
+}
+
+impl<'b, C> Decode<'b, C> for DecSixbit {
+    fn decode(d: &mut Decoder<'b>, ctx: &mut C) -> Result<Self, decode::Error> {
+        <&DecSixbitStr>::decode(d, ctx).map(ToOwned::to_owned)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_fixtures::assert_round_trips;
+    use crate::{DecSixbit, DecSixbitStr};
+
+    #[test]
+    fn test_round_trip() {
+        assert_round_trips(|sixbit| {
+            let encoded = minicbor::to_vec(sixbit).unwrap();
+            let view = minicbor::decode::<&DecSixbitStr>(&encoded).unwrap();
+            assert_eq!(view, sixbit.as_sixbit_str());
+            assert!(encoded.as_ptr_range().contains(&view.as_bytes().as_ptr()) || view.as_bytes().is_empty());
+            minicbor::decode::<DecSixbit>(&encoded)
+        });
+    }
+
+    #[test]
+    fn test_diagnostic_notation() {
+        let encoded = minicbor::to_vec(DecSixbit::new("HELLO").unwrap()).unwrap();
+        assert_eq!(encoded, [0x82, 0x05, 0x44, 0xa2, 0x5b, 0x2c, 0xbc]);
+        assert_eq!(minicbor::display(&encoded).to_string(), "[5, h'a2 5b 2c bc']");
+
+        let encoded = minicbor::to_vec(DecSixbit::new("ABC ").unwrap()).unwrap();
+        assert_eq!(minicbor::display(&encoded).to_string(), "[4, h'86 28 c0 03']");
+    }
+
+    #[test]
+    fn test_rejects_malformed_input() {
+        let malformed: [&[u8]; 6] = [
+            // [4, h'a25b2cbc'], a mismatched length field
+            &[0x82, 0x04, 0x44, 0xa2, 0x5b, 0x2c, 0xbc],
+            // [6, h'a25b2cbc']
+            &[0x82, 0x06, 0x44, 0xa2, 0x5b, 0x2c, 0xbc],
+            // [5, h'a25b2cbd'], nonzero padding bits
+            &[0x82, 0x05, 0x44, 0xa2, 0x5b, 0x2c, 0xbd],
+            // [5, h'a25b2cbc', 0]
+            &[0x83, 0x05, 0x44, 0xa2, 0x5b, 0x2c, 0xbc, 0x00],
+            // [_ 5, h'a25b2cbc'], an indefinite-length array
+            &[0x9f, 0x05, 0x44, 0xa2, 0x5b, 0x2c, 0xbc, 0xff],
+            // [5, h'a25b2c'], truncated
+            &[0x82, 0x05, 0x44, 0xa2, 0x5b, 0x2c],
+        ];
+        for cbor in malformed {
+            assert!(minicbor::decode::<DecSixbit>(cbor).is_err(), "{}", minicbor::display(cbor));
+            assert!(minicbor::decode::<&DecSixbitStr>(cbor).is_err(), "{}", minicbor::display(cbor));
+        }
+    }
+}
@@ -0,0 +1,135 @@
+//! [proptest](https://docs.rs/proptest) strategies for SIXBIT values.
+//!
+//! The strategies only produce valid input, so property tests don't need to filter or hand-roll
+//! generators. Characters shrink toward space and strings shrink toward shorter ones.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use dec_sixbit::{decode, encode};
+//! use proptest::prelude::*;
+//!
+//! proptest! {
+//!     fn round_trip(input in dec_sixbit::proptest::sixbit_string(0..=32)) {
+//!         let (bytes, len) = encode(&input).unwrap();
+//!         prop_assert_eq!(decode(&bytes, len).unwrap(), input);
+//!     }
+//! }
+//! # round_trip();
+//! ```
+
+#[cfg(feature = "with-struct")]
+use crate::DecSixbit;
+use ::proptest::collection::{self, SizeRange};
+use ::proptest::prelude::*;
+
+/// Returns a strategy for characters in the SIXBIT range `' '..='_'`, shrinking toward space.
+pub fn sixbit_char() -> impl Strategy<Value = char> {
+    (0u8..64).prop_map(|value| (value + crate::ASCII_OFFSET) as char)
+}
+
+/// Returns a strategy for strings of SIXBIT characters with a length in `len`.
+///
+/// # Parameters
+/// - `len`: The range of string lengths, e.g. `0..=32`.
+pub fn sixbit_string(len: impl Into<SizeRange>) -> impl Strategy<Value = String> {
+    collection::vec(sixbit_char(), len).prop_map(|chars| chars.into_iter().collect())
+}
+
+/// Returns a strategy for `DecSixbit` values of up to 64 characters.
+///
+/// About a quarter of the values end in spaces at a multiple of 4 characters, so the trailing-space
+/// marker is regularly exercised.
+#[cfg(feature = "with-struct")]
+pub fn dec_sixbit() -> impl Strategy<Value = DecSixbit> {
+    prop_oneof![
+        3 => sixbit_string(0..=64),
+        1 => marker_string(),
+    ]
+    .prop_map(|input| DecSixbit::new(&input).expect("strategy produces valid input"))
+}
+
+/// Returns a strategy for `(bytes, len)` pairs as returned by [`DecSixbit::as_bytes`] and
+/// [`DecSixbit::len`], accepted by [`DecSixbit::from_parts`].
+///
+/// Like [`dec_sixbit`], it regularly includes pairs whose bytes end in the trailing-space marker.
+#[cfg(feature = "with-struct")]
+pub fn raw_encoded() -> impl Strategy<Value = (Vec<u8>, usize)> {
+    dec_sixbit().prop_map(|sixbit| (sixbit.as_bytes().to_vec(), sixbit.len()))
+}
+
+/// Strings of a nonzero multiple of 4 characters ending in a space, which need the marker.
+#[cfg(feature = "with-struct")]
+fn marker_string() -> impl Strategy<Value = String> {
+    (sixbit_string(0..=15), 1usize..=16).prop_map(|(prefix, groups)| {
+        let len = groups * 4;
+        let mut input: String = prefix.chars().take(len - 1).collect();
+        input.extend(std::iter::repeat(' ').take(len - input.len()));
+        input
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{decode, encode};
+
+    proptest! {
+        #[test]
+        fn prop_sixbit_string_is_valid(input in sixbit_string(0..=40)) {
+            prop_assert!(input.len() <= 40);
+            prop_assert!(input.bytes().all(|b| (b' '..=b'_').contains(&b)));
+        }
+
+        #[test]
+        fn prop_encode_decode_round_trip(input in sixbit_string(0..=64)) {
+            let (bytes, len) = encode(&input).unwrap();
+            prop_assert_eq!(len, input.len());
+            prop_assert_eq!(decode(&bytes, len).unwrap(), input);
+        }
+    }
+
+    #[cfg(feature = "with-struct")]
+    proptest! {
+        #[test]
+        fn prop_dec_sixbit_round_trip(sixbit in dec_sixbit()) {
+            let decoded = sixbit.to_string();
+            prop_assert_eq!(DecSixbit::new(&decoded).unwrap(), sixbit);
+        }
+
+        #[test]
+        fn prop_raw_encoded_is_canonical((bytes, len) in raw_encoded()) {
+            let sixbit = DecSixbit::from_parts(bytes.clone(), len).unwrap();
+            prop_assert_eq!(DecSixbit::try_from_slice(&bytes).unwrap(), sixbit);
+        }
+    }
+
+    #[cfg(feature = "with-struct")]
+    #[test]
+    fn test_raw_encoded_includes_marker() {
+        use ::proptest::strategy::ValueTree;
+        use ::proptest::test_runner::TestRunner;
+
+        let mut runner = TestRunner::deterministic();
+        let with_marker = (0..200)
+            .map(|_| raw_encoded().new_tree(&mut runner).unwrap().current())
+            .filter(|(bytes, len)| bytes.len() > crate::bits::bytes_for_chars(*len))
+            .count();
+        assert!(with_marker > 0);
+    }
+
+    #[test]
+    fn test_shrinks_toward_short_spaces() {
+        use ::proptest::strategy::ValueTree;
+        use ::proptest::test_runner::TestRunner;
+
+        let mut runner = TestRunner::deterministic();
+        let mut tree = sixbit_string(0..=16).new_tree(&mut runner).unwrap();
+        while tree.simplify() {}
+        assert_eq!(tree.current(), "");
+
+        let mut tree = sixbit_string(3..=16).new_tree(&mut runner).unwrap();
+        while tree.simplify() {}
+        assert_eq!(tree.current(), "   ");
+    }
+}
@@ -0,0 +1,173 @@
+//! [PyO3](https://docs.rs/pyo3) bindings for using the encoder and decoder from Python.
+//!
+//! Build and install the `dec_sixbit` extension module into the active virtual environment with
+//! `maturin develop`, which reads the features to enable from `pyproject.toml`. Errors become a
+//! `ValueError` carrying the message of the [`Error`].
+//!
+//! ```python
+//! import dec_sixbit
+//!
+//! data, length = dec_sixbit.encode("HELLO")   # (b"\xa2\x5b\x2c\xbc", 5)
+//! dec_sixbit.decode(data, length)             # "HELLO"
+//! len(dec_sixbit.DecSixbit("HELLO"))          # 5
+//! ```
+
+use crate::{DecSixbit, Error};
+use alloc::string::{String, ToString};
+use core::hash::{Hash, Hasher};
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::types::PyBytes;
+use std::collections::hash_map::DefaultHasher;
+
+impl From<Error> for PyErr {
+    fn from(error: Error) -> Self {
+        PyValueError::new_err(error.to_string())
+    }
+}
+
+/// Encodes a string into packed SIXBIT bytes, like [`encode`](crate::encode).
+///
+/// # Errors
+/// Raises a `ValueError` if the input contains characters outside the range 32-95.
+#[pyfunction]
+pub fn encode<'py>(py: Python<'py>, s: &str) -> PyResult<(Bound<'py, PyBytes>, usize)> {
+    let (bytes, len) = crate::encode(s)?;
+    Ok((PyBytes::new(py, &bytes), len))
+}
+
+/// Decodes `length` characters from packed SIXBIT bytes, like [`decode`](crate::decode).
+///
+/// # Errors
+/// Raises a `ValueError` if the number of bytes is inconsistent with `length`.
+#[pyfunction]
+pub fn decode(b: &[u8], length: usize) -> PyResult<String> {
+    Ok(crate::decode(b, length)?)
+}
+
+/// A [`DecSixbit`] exported to Python as the immutable `DecSixbit` class.
+#[pyclass(name = "DecSixbit", module = "dec_sixbit", frozen)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PyDecSixbit(DecSixbit);
+
+#[pymethods]
+impl PyDecSixbit {
+    /// Encodes `s`, like [`DecSixbit::new`].
+    ///
+    /// # Errors
+    /// Raises a `ValueError` if the input contains characters outside the range 32-95.
+    #[new]
+    pub fn new(s: &str) -> PyResult<Self> {
+        Ok(Self(DecSixbit::new(s)?))
+    }
+
+    /// Returns the decoded string.
+    pub fn __str__(&self) -> String {
+        self.0.to_string()
+    }
+
+    /// Returns the constructor call that recreates the value.
+    pub fn __repr__(&self) -> String {
+        format!("DecSixbit({:?})", self.0.to_string())
+    }
+
+    /// Returns the number of characters.
+    pub fn __len__(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Compares the packed bytes, like `DecSixbit`'s `PartialEq`; other types compare unequal.
+    pub fn __eq__(&self, other: &Bound<'_, PyAny>) -> bool {
+        other.downcast::<Self>().is_ok_and(|other| self.0 == other.get().0)
+    }
+
+    /// Hashes the packed bytes, consistently with `__eq__`.
+    pub fn __hash__(&self) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        self.0.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    /// The packed bytes, including any trailing-space marker byte.
+    #[getter]
+    pub fn bytes<'py>(&self, py: Python<'py>) -> Bound<'py, PyBytes> {
+        PyBytes::new(py, self.0.as_bytes())
+    }
+}
+
+impl From<DecSixbit> for PyDecSixbit {
+    fn from(sixbit: DecSixbit) -> Self {
+        Self(sixbit)
+    }
+}
+
+impl From<PyDecSixbit> for DecSixbit {
+    fn from(sixbit: PyDecSixbit) -> Self {
+        sixbit.0
+    }
+}
+
+/// The `dec_sixbit` Python module.
+#[pymodule]
+fn dec_sixbit(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(encode, m)?)?;
+    m.add_function(wrap_pyfunction!(decode, m)?)?;
+    m.add_class::<PyDecSixbit>()?;
+    Ok(())
+}
+
+/// These embed an interpreter, so they need the Python shared library at link time. The Python API is
+/// tested by `tests/python`.
+#[cfg(test)]
+mod tests {
+    use super::{decode, encode, PyDecSixbit};
+    use crate::Error;
+    use pyo3::exceptions::PyValueError;
+    use pyo3::prelude::*;
+    use pyo3::types::PyBytes;
+
+    fn with_python<R>(f: impl FnOnce(Python<'_>) -> R) -> R {
+        pyo3::prepare_freethreaded_python();
+        Python::with_gil(f)
+    }
+
+    #[test]
+    fn test_round_trip() {
+        with_python(|py| {
+            for input in ["", "A", "ABC ", "HELLO", "SYS:LOGIN.SAV"] {
+                let (bytes, len) = encode(py, input).unwrap();
+                assert_eq!(bytes.as_bytes(), crate::encode(input).unwrap().0);
+                assert_eq!(decode(bytes.as_bytes(), len).unwrap(), input);
+            }
+        });
+    }
+
+    #[test]
+    fn test_errors_become_value_errors() {
+        with_python(|py| {
+            let error = encode(py, "lower").unwrap_err();
+            assert!(error.is_instance_of::<PyValueError>(py));
+            assert_eq!(error.value(py).to_string(), Error::InvalidCharacter.to_string());
+            let error = decode(&[0, 0], 4).unwrap_err();
+            assert_eq!(error.value(py).to_string(), Error::InvalidBytesLength.to_string());
+            assert!(PyDecSixbit::new("~").is_err());
+        });
+    }
+
+    #[test]
+    fn test_class() {
+        with_python(|py| {
+            let sixbit = Bound::new(py, PyDecSixbit::new("ABCD    ").unwrap()).unwrap();
+            let same = Bound::new(py, PyDecSixbit::new("ABCD    ").unwrap()).unwrap();
+            let other = Bound::new(py, PyDecSixbit::new("ABCD").unwrap()).unwrap();
+            assert_eq!(sixbit.str().unwrap().to_string(), "ABCD    ");
+            assert_eq!(sixbit.len().unwrap(), 8);
+            assert!(sixbit.eq(&same).unwrap());
+            assert!(!sixbit.eq(&other).unwrap());
+            assert!(!sixbit.eq("ABCD    ").unwrap());
+            assert_eq!(sixbit.hash().unwrap(), same.hash().unwrap());
+            let bytes = sixbit.getattr("bytes").unwrap();
+            assert_eq!(bytes.downcast::<PyBytes>().unwrap().as_bytes(), sixbit.get().0.as_bytes());
+        });
+    }
+}
@@ -0,0 +1,103 @@
+//! [quickcheck](https://docs.rs/quickcheck) `Arbitrary` implementations for SIXBIT values.
+//!
+//! Generated lengths grow with the generator size, so most values are short. Shrinking removes
+//! characters from the end and then replaces characters with spaces.
+
+#[cfg(feature = "with-struct")]
+use crate::DecSixbit;
+use quickcheck::{Arbitrary, Gen};
+
+/// A `String` of SIXBIT characters, for testing [`encode`](crate::encode) and [`decode`](crate::decode)
+/// with quickcheck.
+///
+/// # Examples
+///
+/// ```rust
+/// use dec_sixbit::{decode, encode, ValidSixbitString};
+///
+/// fn round_trip(ValidSixbitString(input): ValidSixbitString) -> bool {
+///     let (bytes, len) = encode(&input).unwrap();
+///     decode(&bytes, len).unwrap() == input
+/// }
+///
+/// quickcheck::quickcheck(round_trip as fn(ValidSixbitString) -> bool);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ValidSixbitString(pub String);
+
+impl Arbitrary for ValidSixbitString {
+    fn arbitrary(g: &mut Gen) -> Self {
+        let len = usize::arbitrary(g) % (g.size() + 1);
+        ValidSixbitString((0..len).map(|_| (u8::arbitrary(g) % 64 + crate::ASCII_OFFSET) as char).collect())
+    }
+
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        Box::new(shrink_sixbit(&self.0).map(ValidSixbitString))
+    }
+}
+
+#[cfg(feature = "with-struct")]
+impl Arbitrary for DecSixbit {
+    fn arbitrary(g: &mut Gen) -> Self {
+        let ValidSixbitString(input) = ValidSixbitString::arbitrary(g);
+        DecSixbit::new(&input).expect("generated input is valid")
+    }
+
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        Box::new(shrink_sixbit(&self.to_string()).map(|input| DecSixbit::new(&input).expect("shrunk input is valid")))
+    }
+}
+
+/// Returns shorter prefixes of `input`, then copies with one non-space character replaced by a space.
+fn shrink_sixbit(input: &str) -> impl Iterator<Item = String> {
+    let len = input.len();
+    let mut prefix_lens = vec![0, len / 2, len.saturating_sub(1)];
+    prefix_lens.dedup();
+    let prefixes = prefix_lens.into_iter().filter(|&prefix_len| prefix_len < len).map(|prefix_len| input[..prefix_len].to_string());
+    let spaced = input.char_indices().filter(|&(_, c)| c != ' ').map(|(i, _)| {
+        let mut spaced = input.to_string();
+        spaced.replace_range(i..=i, " ");
+        spaced
+    });
+    prefixes.chain(spaced).collect::<Vec<_>>().into_iter()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ValidSixbitString;
+    use crate::{decode, encode};
+    use quickcheck::{Arbitrary, Gen};
+    use quickcheck_macros::quickcheck;
+
+    #[quickcheck]
+    fn prop_encode_decode_round_trip(ValidSixbitString(input): ValidSixbitString) -> bool {
+        let (bytes, len) = encode(&input).unwrap();
+        decode(&bytes, len).unwrap() == input
+    }
+
+    #[cfg(feature = "with-struct")]
+    #[quickcheck]
+    fn prop_dec_sixbit_round_trip(sixbit: crate::DecSixbit) -> bool {
+        crate::DecSixbit::new(&sixbit.to_string()).unwrap() == sixbit
+    }
+
+    #[cfg(feature = "with-struct")]
+    #[quickcheck]
+    fn prop_ord_matches_decoded(a: crate::DecSixbit, b: crate::DecSixbit) -> bool {
+        a.cmp(&b) == a.to_string().cmp(&b.to_string())
+    }
+
+    #[test]
+    fn test_lengths_follow_size() {
+        let mut g = Gen::new(8);
+        assert!((0..100).all(|_| ValidSixbitString::arbitrary(&mut g).0.len() <= 8));
+    }
+
+    #[test]
+    fn test_shrink() {
+        let shrunk: Vec<String> = ValidSixbitString("AB C".to_string()).shrink().map(|s| s.0).collect();
+        assert_eq!(shrunk, ["", "AB", "AB ", " B C", "A  C", "AB  "]);
+        assert_eq!(ValidSixbitString(String::new()).shrink().count(), 0);
+        assert_eq!(ValidSixbitString(" ".to_string()).shrink().map(|s| s.0).collect::<Vec<_>>(), [""]);
+    }
+}
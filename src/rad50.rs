@@ -0,0 +1,197 @@
+//! RAD-50 encoding of 16-bit words, and the FILES-11 ODS-1 filename layout built on it.
+//!
+//! RAD-50 is the PDP-11 counterpart of SIXBIT: a 40-character alphabet of space, `A`-`Z`, `$`, `.`, and
+//! `0`-`9`, with three characters packed into one 16-bit word as `c1 * 1600 + c2 * 40 + c3`. RSX-11 and
+//! VMS ODS-1 volumes store a file name as three such words and its type as a fourth, each component
+//! padded with trailing spaces.
+//!
+//! Code 29 is unassigned and is neither produced nor accepted, and lowercase letters are rejected
+//! rather than folded, like the SIXBIT functions of this crate.
+
+use crate::Error;
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+
+/// The RAD-50 alphabet, indexed by code. Code 29, shown as `%` by some systems, is unassigned.
+const RAD50_CHARS: &[u8; 40] = b" ABCDEFGHIJKLMNOPQRSTUVWXYZ$.%0123456789";
+
+/// The first value that is not a valid word, 40 cubed.
+const WORD_LIMIT: u16 = 40 * 40 * 40;
+
+/// Returns the RAD-50 code of an ASCII character.
+fn char_code(c: u8) -> Result<u16, Error> {
+    match c {
+        b' ' => Ok(0),
+        b'A'..=b'Z' => Ok((c - b'A' + 1) as u16),
+        b'$' => Ok(27),
+        b'.' => Ok(28),
+        b'0'..=b'9' => Ok((c - b'0' + 30) as u16),
+        _ => Err(Error::InvalidCharacter),
+    }
+}
+
+/// Encodes up to 3 characters into a RAD-50 word, padding them with trailing spaces.
+///
+/// # Errors
+/// - Returns an [`Error::TooLong`] if `str` is longer than 3 characters.
+/// - Returns an [`Error::InvalidCharacter`] if `str` contains a character outside the RAD-50 alphabet.
+///
+/// # Examples
+///
+/// ```rust
+/// use dec_sixbit::rad50;
+///
+/// assert_eq!(rad50::encode_word("DIR"), Ok(0o15172));
+/// assert_eq!(rad50::encode_word("A"), rad50::encode_word("A  "));
+/// ```
+pub fn encode_word(str: &str) -> Result<u16, Error> {
+    if str.len() > 3 {
+        return Err(Error::TooLong { len: str.len(), max: 3 });
+    }
+    encode_word_bytes(str.as_bytes())
+}
+
+/// Encodes up to 3 ASCII bytes into a RAD-50 word; non-ASCII bytes are rejected like any other.
+fn encode_word_bytes(chars: &[u8]) -> Result<u16, Error> {
+    let mut codes = [0u16; 3];
+    for (code, &c) in codes.iter_mut().zip(chars) {
+        *code = char_code(c)?;
+    }
+    Ok(codes[0] * 1600 + codes[1] * 40 + codes[2])
+}
+
+/// Decodes a RAD-50 word into its 3 characters, including any space padding.
+///
+/// # Errors
+/// Returns an [`Error::InvalidCharacter`] if the word is 64000 or greater, or holds the unassigned code 29.
+///
+/// # Examples
+///
+/// ```rust
+/// use dec_sixbit::rad50;
+///
+/// assert_eq!(rad50::decode_word(0o15172), Ok(['D', 'I', 'R']));
+/// ```
+pub fn decode_word(word: u16) -> Result<[char; 3], Error> {
+    if word >= WORD_LIMIT {
+        return Err(Error::InvalidCharacter);
+    }
+    let mut chars = [' '; 3];
+    for (i, c) in chars.iter_mut().enumerate() {
+        let code = (word / [1600, 40, 1][i] % 40) as usize;
+        if code == 29 {
+            return Err(Error::InvalidCharacter);
+        }
+        *c = RAD50_CHARS[code] as char;
+    }
+    Ok(chars)
+}
+
+/// Encodes a FILES-11 ODS-1 file name and type into the 4 words of a directory entry.
+///
+/// The name fills the first three words and the type the fourth, each padded with trailing spaces.
+///
+/// # Parameters
+/// - `name`: The file name, up to 9 characters.
+/// - `ext`: The file type, up to 3 characters, without the dot.
+///
+/// # Errors
+/// - Returns an [`Error::TooLong`] if `name` is longer than 9 characters or `ext` longer than 3.
+/// - Returns an [`Error::InvalidCharacter`] if either contains a character outside the RAD-50 alphabet.
+///
+/// # Examples
+///
+/// ```rust
+/// use dec_sixbit::rad50;
+///
+/// // The master file directory, file ID 4,4,0
+/// assert_eq!(rad50::encode_files11_name("000000", "DIR"), Ok([0o140116, 0o140116, 0, 0o15172]));
+/// ```
+pub fn encode_files11_name(name: &str, ext: &str) -> Result<[u16; 4], Error> {
+    if name.len() > 9 {
+        return Err(Error::TooLong { len: name.len(), max: 9 });
+    }
+    let mut words = [0u16; 4];
+    for (word, chunk) in words.iter_mut().zip(name.as_bytes().chunks(3)) {
+        *word = encode_word_bytes(chunk)?;
+    }
+    words[3] = encode_word(ext)?;
+    Ok(words)
+}
+
+/// Decodes the 4 words of a FILES-11 ODS-1 directory entry into the file name and type, with the
+/// space padding trimmed.
+///
+/// This function requires the `alloc` feature, which is enabled by default.
+///
+/// # Errors
+/// Returns an [`Error::InvalidCharacter`] if a word is not valid RAD-50, as for [`decode_word`].
+///
+/// # Examples
+///
+/// ```rust
+/// use dec_sixbit::rad50;
+///
+/// let words = rad50::encode_files11_name("SWAP", "SYS").unwrap();
+/// assert_eq!(rad50::decode_files11_name(&words).unwrap(), ("SWAP".to_string(), "SYS".to_string()));
+/// ```
+#[cfg(feature = "alloc")]
+pub fn decode_files11_name(words: &[u16; 4]) -> Result<(String, String), Error> {
+    let mut name = String::with_capacity(9);
+    for &word in &words[..3] {
+        name.extend(decode_word(word)?);
+    }
+    let mut ext: String = decode_word(words[3])?.iter().collect();
+    name.truncate(name.trim_end_matches(' ').len());
+    ext.truncate(ext.trim_end_matches(' ').len());
+    Ok((name, ext))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_word_round_trip() {
+        for (str, word) in [("   ", 0), ("A  ", 1600), ("ABC", 1683), ("$.9", 27 * 1600 + 28 * 40 + 39), ("999", 63999)] {
+            assert_eq!(encode_word(str), Ok(word), "{str:?}");
+            assert_eq!(decode_word(word).map(|chars| chars.iter().collect::<String>()), Ok(str.to_string()));
+        }
+    }
+
+    #[test]
+    fn test_word_errors() {
+        assert_eq!(encode_word("ABCD"), Err(Error::TooLong { len: 4, max: 3 }));
+        assert_eq!(encode_word("a"), Err(Error::InvalidCharacter));
+        assert_eq!(encode_word("%"), Err(Error::InvalidCharacter));
+        assert_eq!(decode_word(64000), Err(Error::InvalidCharacter));
+        assert_eq!(decode_word(29), Err(Error::InvalidCharacter));
+        assert_eq!(decode_word(29 * 1600), Err(Error::InvalidCharacter));
+    }
+
+    #[test]
+    fn test_files11_known_entries() {
+        // Reserved files of an ODS-1 volume
+        assert_eq!(encode_files11_name("000000", "DIR"), Ok([0o140116, 0o140116, 0o000000, 0o015172]));
+        assert_eq!(encode_files11_name("INDEXF", "SYS"), Ok([0o035164, 0o021406, 0o000000, 0o075273]));
+        assert_eq!(encode_files11_name("SWAP", "SYS"), Ok([0o075131, 0o062000, 0o000000, 0o075273]));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_files11_round_trip() {
+        for (name, ext) in [("000000", "DIR"), ("SWAP", "SYS"), ("A", ""), ("", "TSK"), ("ABCDEFGHI", "XYZ"), ("RSX11M", "")] {
+            let words = encode_files11_name(name, ext).unwrap();
+            assert_eq!(decode_files11_name(&words).unwrap(), (name.to_string(), ext.to_string()), "{name:?}.{ext:?}");
+        }
+        assert_eq!(decode_files11_name(&[0, 0, 0, 64000]), Err(Error::InvalidCharacter));
+    }
+
+    #[test]
+    fn test_files11_errors() {
+        assert_eq!(encode_files11_name("ABCDEFGHIJ", "SYS"), Err(Error::TooLong { len: 10, max: 9 }));
+        assert_eq!(encode_files11_name("SWAP", "SYSX"), Err(Error::TooLong { len: 4, max: 3 }));
+        assert_eq!(encode_files11_name("SWAP-1", "SYS"), Err(Error::InvalidCharacter));
+        assert_eq!(encode_files11_name("SWAPÉ", "SYS"), Err(Error::InvalidCharacter));
+    }
+}
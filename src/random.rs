@@ -0,0 +1,159 @@
+//! [rand](https://docs.rs/rand) distributions for generating random SIXBIT values.
+//!
+//! Values are packed directly from random bits, without building and validating an intermediate
+//! `String`.
+
+use crate::{bits, DecSixbit, ASCII_OFFSET};
+use rand::distributions::Distribution;
+use rand::Rng;
+
+/// The 6-bit values of `'0'..='9'` and `'A'..='Z'`.
+const ALPHANUMERIC_VALUES: [u8; 36] = {
+    let mut values = [0; 36];
+    let mut i = 0;
+    while i < 10 {
+        values[i] = b'0' - ASCII_OFFSET + i as u8;
+        i += 1;
+    }
+    while i < 36 {
+        values[i] = b'A' - ASCII_OFFSET + (i - 10) as u8;
+        i += 1;
+    }
+    values
+};
+
+/// Samples `DecSixbit` values of `len` characters, each uniformly distributed over all 64 SIXBIT
+/// characters.
+///
+/// # Examples
+///
+/// ```rust
+/// use dec_sixbit::SixbitDistribution;
+/// use rand::Rng;
+///
+/// let key = rand::thread_rng().sample(SixbitDistribution { len: 12 });
+/// assert_eq!(key.len(), 12);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SixbitDistribution {
+    /// The number of characters in each sampled value.
+    pub len: usize,
+}
+
+impl Distribution<DecSixbit> for SixbitDistribution {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> DecSixbit {
+        // Every 6-bit field of uniformly random bytes is itself uniform, so only the padding is fixed up
+        let mut bytes = vec![0; bits::bytes_for_chars(self.len)];
+        rng.fill_bytes(&mut bytes);
+        bits::clear_padding(&mut bytes, self.len);
+        DecSixbit::from_encoded(bytes, self.len)
+    }
+}
+
+/// Samples `DecSixbit` values of `len` characters, each uniformly distributed over `'0'..='9'` and
+/// `'A'..='Z'`.
+///
+/// # Examples
+///
+/// ```rust
+/// use dec_sixbit::AlphanumericSixbit;
+/// use rand::Rng;
+///
+/// let key = rand::thread_rng().sample(AlphanumericSixbit { len: 8 });
+/// assert!(key.is_alphanumeric());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AlphanumericSixbit {
+    /// The number of characters in each sampled value.
+    pub len: usize,
+}
+
+impl Distribution<DecSixbit> for AlphanumericSixbit {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> DecSixbit {
+        let mut bytes = vec![0; bits::bytes_for_chars(self.len)];
+        for index in 0..self.len {
+            bits::set_value(&mut bytes, index, ALPHANUMERIC_VALUES[rng.gen_range(0..ALPHANUMERIC_VALUES.len())]);
+        }
+        DecSixbit::from_encoded(bytes, self.len)
+    }
+}
+
+/// Returns a random `String` of `len` characters, each uniformly distributed over all 64 SIXBIT
+/// characters.
+///
+/// # Parameters
+/// - `rng`: The random number generator to sample from.
+/// - `len`: The number of characters to generate.
+///
+/// # Examples
+///
+/// ```rust
+/// use dec_sixbit::{encode, random_sixbit_string};
+///
+/// let input = random_sixbit_string(&mut rand::thread_rng(), 16);
+/// assert_eq!(input.len(), 16);
+/// assert!(encode(&input).is_ok());
+/// ```
+pub fn random_sixbit_string<R: Rng + ?Sized>(rng: &mut R, len: usize) -> String {
+    (0..len).map(|_| (rng.gen_range(0..64u8) + ASCII_OFFSET) as char).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{random_sixbit_string, AlphanumericSixbit, SixbitDistribution};
+    use crate::{bits, encode, DecSixbit};
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    #[test]
+    fn test_all_values_appear() {
+        let mut rng = StdRng::seed_from_u64(1);
+        let mut counts = [0usize; 64];
+        for sixbit in (&mut rng).sample_iter(SixbitDistribution { len: 13 }).take(2000) {
+            for (count, frequency) in counts.iter_mut().zip(sixbit.char_frequencies()) {
+                *count += frequency;
+            }
+        }
+        // 26,000 samples put about 406 on each value
+        assert!(counts.iter().all(|&count| (250..600).contains(&count)), "{counts:?}");
+
+        let mut counts = [0usize; 64];
+        for c in random_sixbit_string(&mut rng, 26_000).bytes() {
+            counts[(c - b' ') as usize] += 1;
+        }
+        assert!(counts.iter().all(|&count| (250..600).contains(&count)), "{counts:?}");
+    }
+
+    #[test]
+    fn test_lengths_and_validity() {
+        let mut rng = StdRng::seed_from_u64(2);
+        for len in 0..=40 {
+            for _ in 0..20 {
+                let sixbit = rng.sample(SixbitDistribution { len });
+                assert_eq!(sixbit.len(), len);
+                assert!(bits::is_canonical(sixbit.as_bytes(), len));
+                assert_eq!(DecSixbit::new(&sixbit.to_string()).unwrap(), sixbit);
+
+                let alphanumeric = rng.sample(AlphanumericSixbit { len });
+                assert_eq!(alphanumeric.len(), len);
+                assert!(alphanumeric.to_string().bytes().all(|c| c.is_ascii_alphanumeric()));
+                assert_eq!(DecSixbit::new(&alphanumeric.to_string()).unwrap(), alphanumeric);
+
+                let input = random_sixbit_string(&mut rng, len);
+                assert_eq!(input.len(), len);
+                assert!(encode(&input).is_ok());
+            }
+        }
+    }
+
+    #[test]
+    fn test_alphanumeric_covers_charset() {
+        let mut rng = StdRng::seed_from_u64(3);
+        let sixbit = rng.sample(AlphanumericSixbit { len: 3600 });
+        let frequencies = sixbit.char_frequencies();
+        for (value, &frequency) in frequencies.iter().enumerate() {
+            let c = (value as u8 + b' ') as char;
+            assert_eq!(frequency > 0, c.is_ascii_alphanumeric(), "{c:?}");
+        }
+    }
+}
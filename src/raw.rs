@@ -0,0 +1,279 @@
+//! Comparisons and lookups on packed bytes and their character count, without allocating.
+//!
+//! These serve callers that keep packed SIXBIT in their own storage instead of [`DecSixbit`](crate::DecSixbit),
+//! and they are what the struct API uses internally. Every function takes the packed bytes in the form
+//! [`encode`](crate::encode) and [`encode_to_slice`](crate::encode_to_slice) produce them, without the
+//! trailing-space marker, together with the number of characters they hold. Every function works with the
+//! `alloc` and `std` features disabled.
+//!
+//! A pair is consistent when `bytes.len()` is [`encoded_len(len)`](crate::encoded_len) and the padding bits
+//! after the last character are zero. Functions returning `bool` return `false` for an inconsistent pair,
+//! and functions returning `Result` report it as an error.
+
+use crate::{bits, Error, ASCII_OFFSET};
+use core::cmp::Ordering;
+
+pub use crate::slice::char_at;
+
+/// Checks that `bytes` holds exactly `len` characters with zeroed padding bits.
+///
+/// # Errors
+/// - Returns an [`Error::InvalidBytesLength`] if `bytes.len()` and `len` are inconsistent.
+/// - Returns an [`Error::NonzeroPadding`] if the unused bits after the last character are set.
+///
+/// # Examples
+///
+/// ```rust
+/// use dec_sixbit::{raw, Error};
+///
+/// let (bytes, len) = dec_sixbit::encode("HELLO").unwrap();
+/// assert_eq!(raw::check(&bytes, len), Ok(()));
+/// assert_eq!(raw::check(&bytes, 6), Err(Error::InvalidBytesLength));
+/// ```
+pub fn check(bytes: &[u8], len: usize) -> Result<(), Error> {
+    if bits::checked_bytes_for_chars(len) != Some(bytes.len()) {
+        return Err(Error::InvalidBytesLength);
+    }
+    let used_bits = len * 6 % 8;
+    if used_bits != 0 && bytes[bytes.len() - 1] & (0xFF >> used_bits) != 0 {
+        return Err(Error::NonzeroPadding);
+    }
+    Ok(())
+}
+
+/// Returns `true` if the two pairs hold the same characters.
+///
+/// Returns `false` if either pair is inconsistent.
+///
+/// # Examples
+///
+/// ```rust
+/// use dec_sixbit::raw;
+///
+/// let (a, a_len) = dec_sixbit::encode("DSK").unwrap();
+/// let (b, b_len) = dec_sixbit::encode("DSK ").unwrap();
+/// assert!(raw::eq(&a, a_len, &a, a_len));
+/// assert!(!raw::eq(&a, a_len, &b, b_len));
+/// ```
+pub fn eq(a_bytes: &[u8], a_len: usize, b_bytes: &[u8], b_len: usize) -> bool {
+    check(a_bytes, a_len).is_ok() && check(b_bytes, b_len).is_ok() && eq_unchecked(a_bytes, a_len, b_bytes, b_len)
+}
+
+/// Compares the two pairs lexicographically by their decoded characters, like `str`.
+///
+/// # Errors
+/// Returns the first error [`check`] reports for either pair.
+///
+/// # Examples
+///
+/// ```rust
+/// use dec_sixbit::raw;
+/// use std::cmp::Ordering;
+///
+/// let (a, a_len) = dec_sixbit::encode("B").unwrap();
+/// let (b, b_len) = dec_sixbit::encode("AAAA").unwrap();
+/// assert_eq!(raw::cmp(&a, a_len, &b, b_len), Ok(Ordering::Greater));
+/// ```
+pub fn cmp(a_bytes: &[u8], a_len: usize, b_bytes: &[u8], b_len: usize) -> Result<Ordering, Error> {
+    check(a_bytes, a_len)?;
+    check(b_bytes, b_len)?;
+    Ok(cmp_unchecked(a_bytes, a_len, b_bytes, b_len))
+}
+
+/// Returns `true` if the characters of the pair start with `prefix`.
+///
+/// Returns `false` if the pair is inconsistent. An empty prefix matches any consistent pair.
+///
+/// # Examples
+///
+/// ```rust
+/// use dec_sixbit::raw;
+///
+/// let (bytes, len) = dec_sixbit::encode("SYS:LOGIN").unwrap();
+/// assert!(raw::starts_with(&bytes, len, "SYS:"));
+/// assert!(!raw::starts_with(&bytes, len, "sys:"));
+/// ```
+pub fn starts_with(bytes: &[u8], len: usize, prefix: &str) -> bool {
+    check(bytes, len).is_ok() && starts_with_unchecked(bytes, len, prefix)
+}
+
+/// Returns `true` if the characters of the pair end with `suffix`.
+///
+/// Returns `false` if the pair is inconsistent. An empty suffix matches any consistent pair.
+///
+/// # Examples
+///
+/// ```rust
+/// use dec_sixbit::raw;
+///
+/// let (bytes, len) = dec_sixbit::encode("PIP.SAV").unwrap();
+/// assert!(raw::ends_with(&bytes, len, ".SAV"));
+/// assert!(!raw::ends_with(&bytes, len, ".REL"));
+/// ```
+pub fn ends_with(bytes: &[u8], len: usize, suffix: &str) -> bool {
+    check(bytes, len).is_ok() && ends_with_unchecked(bytes, len, suffix)
+}
+
+/// Returns `true` if the characters of the pair contain `substring`.
+///
+/// Returns `false` if the pair is inconsistent. An empty substring matches any consistent pair.
+///
+/// # Examples
+///
+/// ```rust
+/// use dec_sixbit::raw;
+///
+/// let (bytes, len) = dec_sixbit::encode("SYS:LOGIN").unwrap();
+/// assert!(raw::contains(&bytes, len, "LOG"));
+/// assert!(!raw::contains(&bytes, len, "DSK"));
+/// ```
+pub fn contains(bytes: &[u8], len: usize, substring: &str) -> bool {
+    check(bytes, len).is_ok() && contains_unchecked(bytes, len, substring)
+}
+
+/// Returns `true` if the characters of the pair are exactly `str`.
+///
+/// Returns `false` if the pair is inconsistent.
+///
+/// # Examples
+///
+/// ```rust
+/// use dec_sixbit::raw;
+///
+/// let (bytes, len) = dec_sixbit::encode("HELLO").unwrap();
+/// assert!(raw::eq_str(&bytes, len, "HELLO"));
+/// assert!(!raw::eq_str(&bytes, len, "HELLO "));
+/// ```
+pub fn eq_str(bytes: &[u8], len: usize, str: &str) -> bool {
+    check(bytes, len).is_ok() && eq_str_unchecked(bytes, len, str)
+}
+
+/// Compares two consistent pairs for equality.
+#[inline]
+pub(crate) fn eq_unchecked(a_bytes: &[u8], a_len: usize, b_bytes: &[u8], b_len: usize) -> bool {
+    a_len == b_len && a_bytes == b_bytes
+}
+
+/// Orders two consistent pairs.
+///
+/// See [`DecSixbit::lexicographic_cmp`](crate::DecSixbit::lexicographic_cmp) for why the bytes can be
+/// compared directly.
+#[inline]
+pub(crate) fn cmp_unchecked(a_bytes: &[u8], a_len: usize, b_bytes: &[u8], b_len: usize) -> Ordering {
+    a_bytes.cmp(b_bytes).then(a_len.cmp(&b_len))
+}
+
+/// Checks a prefix against a consistent pair.
+#[inline]
+pub(crate) fn starts_with_unchecked(bytes: &[u8], len: usize, prefix: &str) -> bool {
+    prefix.len() <= len && values_match(bytes, prefix)
+}
+
+/// Checks a suffix against a consistent pair.
+#[inline]
+pub(crate) fn ends_with_unchecked(bytes: &[u8], len: usize, suffix: &str) -> bool {
+    match len.checked_sub(suffix.len()) {
+        Some(start) => values_match_at(bytes, start, suffix),
+        None => false,
+    }
+}
+
+/// Searches a consistent pair for a substring, trying every start position.
+pub(crate) fn contains_unchecked(bytes: &[u8], len: usize, substring: &str) -> bool {
+    match len.checked_sub(substring.len()) {
+        Some(last) => (0..=last).any(|start| values_match_at(bytes, start, substring)),
+        None => false,
+    }
+}
+
+/// Compares a consistent pair with a string.
+#[inline]
+pub(crate) fn eq_str_unchecked(bytes: &[u8], len: usize, str: &str) -> bool {
+    len == str.len() && values_match(bytes, str)
+}
+
+/// Returns `true` if the first `str.len()` characters of `bytes` are the bytes of `str`.
+fn values_match(bytes: &[u8], str: &str) -> bool {
+    let mut expected = str.bytes();
+    bits::all_values(bytes, str.len(), |value| expected.next() == Some(value + ASCII_OFFSET))
+}
+
+/// Returns `true` if the `str.len()` characters of `bytes` from `start` are the bytes of `str`.
+fn values_match_at(bytes: &[u8], start: usize, str: &str) -> bool {
+    str.bytes().enumerate().all(|(offset, byte)| bits::value_at(bytes, start + offset) + ASCII_OFFSET == byte)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_inconsistent_pairs() {
+        // "HELLO" packed into 4 bytes, and the same with a padding bit set
+        let mut bytes = [0u8; 4];
+        crate::encode_to_slice("HELLO", &mut bytes).unwrap();
+        let mut padded = bytes;
+        padded[3] |= 1;
+
+        assert_eq!(check(&bytes[..3], 5), Err(Error::InvalidBytesLength));
+        assert_eq!(check(&padded, 5), Err(Error::NonzeroPadding));
+        assert!(!eq(&bytes, 5, &padded, 5));
+        assert!(!eq(&bytes, 4, &bytes, 4));
+        assert_eq!(cmp(&bytes, 5, &padded, 5), Err(Error::NonzeroPadding));
+        assert_eq!(cmp(&bytes, 6, &bytes, 5), Err(Error::InvalidBytesLength));
+        assert!(!starts_with(&padded, 5, "HE"));
+        assert!(!starts_with(&bytes, 6, ""));
+        assert!(!ends_with(&padded, 5, "LO"));
+        assert!(!contains(&bytes, 6, ""));
+        assert!(!eq_str(&padded, 5, "HELLO"));
+        assert!(!eq_str(&bytes, 6, "HELLO "));
+        assert_eq!(char_at(&bytes, 6, 0), Err(Error::InvalidBytesLength));
+
+        // The bit count of this length wraps around to 2, which would otherwise fit in one byte
+        let huge = usize::MAX / 6 + 1;
+        assert_eq!(check(&[0x80], huge), Err(Error::InvalidBytesLength));
+        assert!(!eq(&[0x80], huge, &[0x80], huge));
+        assert!(!starts_with(&[0x80], huge, "0"));
+    }
+
+    #[test]
+    fn test_non_ascii_input() {
+        let mut bytes = [0u8; 3];
+        crate::encode_to_slice("ABC", &mut bytes).unwrap();
+        assert!(!eq_str(&bytes, 3, "AÉ"));
+        assert!(!starts_with(&bytes, 3, "AÉ"));
+        assert!(!starts_with(&bytes, 3, "abc"));
+        assert!(!ends_with(&bytes, 3, "BÉ"));
+        assert!(!contains(&bytes, 3, "É"));
+    }
+
+    #[cfg(feature = "alloc")]
+    proptest::proptest! {
+        #[test]
+        fn prop_matches_decoded(a in crate::proptest::sixbit_string(0..=24), b in crate::proptest::sixbit_string(0..=24)) {
+            let (a_bytes, a_len) = crate::encode(&a).unwrap();
+            let (b_bytes, b_len) = crate::encode(&b).unwrap();
+            proptest::prop_assert_eq!(eq(&a_bytes, a_len, &b_bytes, b_len), a == b);
+            proptest::prop_assert_eq!(cmp(&a_bytes, a_len, &b_bytes, b_len), Ok(a.cmp(&b)));
+            proptest::prop_assert_eq!(starts_with(&a_bytes, a_len, &b), a.starts_with(&b));
+            proptest::prop_assert_eq!(eq_str(&a_bytes, a_len, &b), a == b);
+            proptest::prop_assert_eq!(ends_with(&a_bytes, a_len, &b), a.ends_with(&b));
+            proptest::prop_assert_eq!(contains(&a_bytes, a_len, &b), a.contains(&b));
+            for (index, c) in a.chars().enumerate() {
+                proptest::prop_assert_eq!(char_at(&a_bytes, a_len, index), Ok(c));
+            }
+        }
+
+        #[test]
+        fn prop_prefixes_match_decoded(a in crate::proptest::sixbit_string(0..=24), split in 0usize..=24) {
+            let (bytes, len) = crate::encode(&a).unwrap();
+            let prefix = &a[..split.min(a.len())];
+            proptest::prop_assert!(starts_with(&bytes, len, prefix));
+            proptest::prop_assert!(eq_str(&bytes, len, &a));
+            proptest::prop_assert!(ends_with(&bytes, len, &a[split.min(a.len())..]));
+            for start in 0..=a.len() {
+                proptest::prop_assert!(contains(&bytes, len, &a[start..split.max(start).min(a.len())]));
+            }
+        }
+    }
+}
@@ -0,0 +1,132 @@
+//! Provides `SixbitRecordBuilder` for assembling fixed-width records field by field.
+//!
+//! A record starts out as all spaces. Fields are written into character ranges in any order, and
+//! writing a field replaces everything in its range, so a shorter value never leaves old characters
+//! behind. [`DecSixbitStr::field`](crate::DecSixbitStr::field) reads the fields back.
+
+use crate::{DecSixbit, Error, SixbitChar};
+use alloc::vec::Vec;
+use core::convert::TryFrom;
+use core::ops::Range;
+
+/// Builds a fixed-width `DecSixbit` record from fields at known character positions.
+///
+/// # Examples
+///
+/// ```rust
+/// use dec_sixbit::SixbitRecordBuilder;
+///
+/// let mut builder = SixbitRecordBuilder::new(14);
+/// builder.set_field(0..6, "BOLT").unwrap().set_field(6..9, "EA").unwrap().set_uint(9..14, 12).unwrap();
+/// let record = builder.build();
+/// assert_eq!(record.to_string(), "BOLT  EA    12");
+/// assert_eq!(record.field_str(0..6).as_deref(), Some("BOLT"));
+/// ```
+#[derive(Debug, Clone)]
+pub struct SixbitRecordBuilder {
+    record: DecSixbit,
+}
+
+impl SixbitRecordBuilder {
+    /// Creates a builder for a record of `width` characters, all spaces.
+    ///
+    /// # Parameters
+    /// - `width`: The number of characters in the record.
+    pub fn new(width: usize) -> Self {
+        let mut record = DecSixbit::with_capacity(width);
+        record.resize(width, ' ').expect("space is a valid SIXBIT character");
+        Self { record }
+    }
+
+    /// Writes `value` into the characters in `range`, left-justified and padded with trailing spaces.
+    ///
+    /// # Parameters
+    /// - `range`: The character positions of the field.
+    /// - `value`: The field content. Must contain only ASCII characters in the range 32-95.
+    ///
+    /// # Errors
+    /// - Returns an [`Error::OutOfBounds`] if `range` is not within the record.
+    /// - Returns an [`Error::InvalidCharacter`] if `value` contains invalid characters.
+    /// - Returns an [`Error::TooLong`] if `value` has more characters than `range` is wide.
+    ///
+    /// The record is left unchanged on error.
+    pub fn set_field(&mut self, range: Range<usize>, value: &str) -> Result<&mut Self, Error> {
+        if range.start > range.end || range.end > self.record.len() {
+            return Err(Error::OutOfBounds);
+        }
+        let chars = value.chars().map(SixbitChar::try_from).collect::<Result<Vec<_>, _>>()?;
+        let width = range.end - range.start;
+        if chars.len() > width {
+            return Err(Error::TooLong { len: chars.len(), max: width });
+        }
+        let padded = chars.into_iter().chain(core::iter::repeat(SixbitChar::SPACE));
+        for (index, c) in range.zip(padded) {
+            self.record.set_char(index, c.as_char())?;
+        }
+        Ok(self)
+    }
+
+    /// Writes `value` in decimal into the characters in `range`, right-justified and padded with
+    /// leading spaces.
+    ///
+    /// # Parameters
+    /// - `range`: The character positions of the field.
+    /// - `value`: The number to render.
+    ///
+    /// # Errors
+    /// - Returns an [`Error::OutOfBounds`] if `range` is not within the record.
+    /// - Returns an [`Error::FieldOverflow`] if `value` has more digits than `range` is wide.
+    ///
+    /// The record is left unchanged on error.
+    pub fn set_uint(&mut self, range: Range<usize>, value: u64) -> Result<&mut Self, Error> {
+        self.record.overwrite_field(range, value)?;
+        Ok(self)
+    }
+
+    /// Returns the finished record.
+    pub fn build(self) -> DecSixbit {
+        self.record
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SixbitRecordBuilder;
+    use crate::{DecSixbit, Error};
+
+    #[test]
+    fn test_build_record() {
+        let mut builder = SixbitRecordBuilder::new(16);
+        // Fields are written out of order, and 14..16 is never set
+        builder.set_uint(9..14, 12).unwrap();
+        builder.set_field(6..9, "EA").unwrap().set_field(0..6, "BOLT").unwrap();
+        let record = builder.build();
+        assert_eq!(record.to_string(), "BOLT  EA    12  ");
+        assert_eq!(record.as_bytes(), DecSixbit::new("BOLT  EA    12  ").unwrap().as_bytes());
+        assert_eq!(record.field_str(0..6).as_deref(), Some("BOLT"));
+        assert_eq!(record.field_str(6..9).as_deref(), Some("EA"));
+        assert_eq!(record.field_str(9..14).as_deref(), Some("12"));
+        assert_eq!(record.field_str(14..16).as_deref(), Some(""));
+    }
+
+    #[test]
+    fn test_overwrite_clears_field() {
+        let mut builder = SixbitRecordBuilder::new(8);
+        builder.set_field(0..6, "SYSTEM").unwrap().set_uint(6..8, 99).unwrap();
+        builder.set_field(0..6, "DSK").unwrap().set_uint(6..8, 7).unwrap();
+        assert_eq!(builder.build().to_string(), "DSK    7");
+    }
+
+    #[test]
+    fn test_errors_leave_record_unchanged() {
+        let mut builder = SixbitRecordBuilder::new(8);
+        builder.set_field(0..4, "KEEP").unwrap();
+        assert_eq!(builder.set_field(4..8, "TOOLONG").unwrap_err(), Error::TooLong { len: 7, max: 4 });
+        assert_eq!(builder.set_field(4..8, "bad").unwrap_err(), Error::InvalidCharacter);
+        assert_eq!(builder.set_field(4..9, "X").unwrap_err(), Error::OutOfBounds);
+        assert_eq!(builder.set_uint(4..6, 100).unwrap_err(), Error::FieldOverflow);
+        assert_eq!(builder.set_uint(7..9, 1).unwrap_err(), Error::OutOfBounds);
+        assert_eq!(builder.build(), DecSixbit::new("KEEP    ").unwrap());
+        assert_eq!(SixbitRecordBuilder::new(0).build(), DecSixbit::default());
+    }
+}
@@ -0,0 +1,152 @@
+//! [rusqlite](https://docs.rs/rusqlite) `ToSql` and `FromSql` support for `DecSixbit`.
+//!
+//! A `DecSixbit` binds as TEXT holding the decoded string. Wrap it in [`SixbitBlob`] to bind the
+//! packed bytes as a BLOB instead. Reading accepts either: TEXT is validated as SIXBIT input, and a
+//! BLOB must hold packed bytes as written by `SixbitBlob`, with the length inferred from them.
+//!
+//! Blobs holding the raw output of [`encode`](crate::encode) lack the trailing-space marker, so a value
+//! of a multiple of 4 characters ending in a space would read back one character short. Blobs that
+//! aren't in the form `SixbitBlob` writes, such as ones with nonzero padding bits, are rejected.
+
+use crate::{bits, DecSixbit};
+use rusqlite::types::{FromSql, FromSqlError, FromSqlResult, ToSql, ToSqlOutput, ValueRef};
+
+/// A `DecSixbit` stored as a BLOB of its packed bytes, about three quarters the size of TEXT.
+///
+/// # Examples
+///
+/// ```rust
+/// use dec_sixbit::{DecSixbit, SixbitBlob};
+/// use rusqlite::Connection;
+///
+/// let conn = Connection::open_in_memory().unwrap();
+/// conn.execute("CREATE TABLE files (name BLOB)", []).unwrap();
+///
+/// let name = DecSixbit::new("README.TXT").unwrap();
+/// conn.execute("INSERT INTO files (name) VALUES (?1)", [SixbitBlob(name.clone())]).unwrap();
+///
+/// let read: DecSixbit = conn.query_row("SELECT name FROM files", [], |row| row.get(0)).unwrap();
+/// assert_eq!(read, name);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct SixbitBlob(pub DecSixbit);
+
+impl ToSql for DecSixbit {
+    fn to_sql(&self) -> rusqlite::Result<ToSqlOutput<'_>> {
+        Ok(ToSqlOutput::from(self.to_string()))
+    }
+}
+
+impl FromSql for DecSixbit {
+    fn column_result(value: ValueRef<'_>) -> FromSqlResult<Self> {
+        match value {
+            ValueRef::Text(text) => {
+                let text = std::str::from_utf8(text).map_err(|error| FromSqlError::Other(Box::new(error)))?;
+                DecSixbit::new(text).map_err(|error| FromSqlError::Other(Box::new(error)))
+            },
+            ValueRef::Blob(bytes) => {
+                // Rejects blobs that don't decode back to the same bytes, such as ones with nonzero padding
+                DecSixbit::from_parts(bytes.to_vec(), bits::infer_len(bytes)).map_err(|error| FromSqlError::Other(Box::new(error)))
+            },
+            _ => Err(FromSqlError::InvalidType),
+        }
+    }
+}
+
+impl ToSql for SixbitBlob {
+    fn to_sql(&self) -> rusqlite::Result<ToSqlOutput<'_>> {
+        Ok(ToSqlOutput::Borrowed(ValueRef::Blob(self.0.as_bytes())))
+    }
+}
+
+impl FromSql for SixbitBlob {
+    fn column_result(value: ValueRef<'_>) -> FromSqlResult<Self> {
+        DecSixbit::column_result(value).map(SixbitBlob)
+    }
+}
+
+impl From<DecSixbit> for SixbitBlob {
+    fn from(sixbit: DecSixbit) -> Self {
+        SixbitBlob(sixbit)
+    }
+}
+
+impl From<SixbitBlob> for DecSixbit {
+    fn from(blob: SixbitBlob) -> Self {
+        blob.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SixbitBlob;
+    use crate::test_fixtures::INPUTS;
+    use crate::DecSixbit;
+    use rusqlite::Connection;
+
+    fn connection() -> Connection {
+        let conn = Connection::open_in_memory().unwrap();
+        conn.execute("CREATE TABLE names (id INTEGER PRIMARY KEY, text TEXT, blob BLOB)", []).unwrap();
+        conn
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let conn = connection();
+        for (id, input) in INPUTS.iter().enumerate() {
+            let sixbit = DecSixbit::new(input).unwrap();
+            conn.execute(
+                "INSERT INTO names (id, text, blob) VALUES (?1, ?2, ?3)",
+                rusqlite::params![id, sixbit, SixbitBlob(sixbit.clone())],
+            )
+            .unwrap();
+
+            let (text, blob, text_type, blob_type): (DecSixbit, SixbitBlob, String, String) = conn
+                .query_row(
+                    "SELECT text, blob, typeof(text), typeof(blob) FROM names WHERE id = ?1",
+                    [id],
+                    |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?, row.get(3)?)),
+                )
+                .unwrap();
+            assert_eq!(text, sixbit);
+            assert_eq!(blob.0, sixbit);
+            assert_eq!((text_type.as_str(), blob_type.as_str()), ("text", "blob"));
+
+            let stored: String = conn.query_row("SELECT text FROM names WHERE id = ?1", [id], |row| row.get(0)).unwrap();
+            assert_eq!(stored, *input);
+            let stored: Vec<u8> = conn.query_row("SELECT blob FROM names WHERE id = ?1", [id], |row| row.get(0)).unwrap();
+            assert_eq!(stored, sixbit.as_bytes());
+        }
+    }
+
+    #[test]
+    fn test_null_into_option() {
+        let conn = connection();
+        conn.execute("INSERT INTO names (id) VALUES (1)", []).unwrap();
+        let (text, blob): (Option<DecSixbit>, Option<SixbitBlob>) =
+            conn.query_row("SELECT text, blob FROM names", [], |row| Ok((row.get(0)?, row.get(1)?))).unwrap();
+        assert_eq!((text, blob), (None, None));
+
+        let result = conn.query_row("SELECT text FROM names", [], |row| row.get::<_, DecSixbit>(0));
+        assert!(matches!(result, Err(rusqlite::Error::InvalidColumnType(..))));
+    }
+
+    #[test]
+    fn test_invalid_values_are_errors() {
+        let conn = connection();
+        conn.execute("INSERT INTO names (id, text, blob) VALUES (1, 'hello', x'a25b2cbd')", []).unwrap();
+
+        for column in ["text", "blob"] {
+            let result = conn.query_row(&format!("SELECT {column} FROM names"), [], |row| row.get::<_, DecSixbit>(0));
+            match result {
+                Err(rusqlite::Error::FromSqlConversionFailure(_, _, error)) => {
+                    assert!(error.downcast_ref::<crate::Error>().is_some(), "{column}: {error}");
+                },
+                other => panic!("{column}: unexpected {other:?}"),
+            }
+        }
+
+        let result = conn.query_row("SELECT 42", [], |row| row.get::<_, DecSixbit>(0));
+        assert!(matches!(result, Err(rusqlite::Error::InvalidColumnType(..))));
+    }
+}
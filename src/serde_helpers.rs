@@ -0,0 +1,344 @@
+//! Helpers for `#[serde(with = "...")]` on fields holding the raw `(Vec<u8>, usize)` pair returned by
+//! [`encode`](crate::encode).
+//!
+//! Both helpers write the same layouts as [`DecSixbit`], so a field can switch between the raw pair and
+//! `DecSixbit` without changing its serialized form. The pair is validated when serializing, and the
+//! decoded value is validated when deserializing.
+//!
+//! - [`as_str`]: A string in human-readable formats such as JSON, and the length with the packed bytes
+//!   in binary formats such as bincode, exactly like `DecSixbit`.
+//! - [`as_bytes`]: The length with the packed bytes in every format.
+//!
+//! Separate `bytes` and `len` fields can use the helpers by grouping them into a `(Vec<u8>, usize)` field.
+//!
+//! For `Option<DecSixbit>` fields where an empty string means absent, [`empty_as_none`] and
+//! [`none_as_null`] read both `""` and `null` as `None`, writing `None` as `""` and `null` respectively.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use dec_sixbit::encode;
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Record {
+//!     #[serde(with = "dec_sixbit::serde_helpers::as_str")]
+//!     name: (Vec<u8>, usize),
+//!     #[serde(with = "dec_sixbit::serde_helpers::as_bytes")]
+//!     code: (Vec<u8>, usize),
+//! }
+//!
+//! let record = Record { name: encode("HELLO").unwrap(), code: encode("AB").unwrap() };
+//! let json = serde_json::to_string(&record).unwrap();
+//! assert_eq!(json, r#"{"name":"HELLO","code":[2,[134,32]]}"#);
+//!
+//! let decoded: Record = serde_json::from_str(&json).unwrap();
+//! assert_eq!(decoded.name, record.name);
+//! assert!(serde_json::from_str::<Record>(r#"{"name":"hello","code":[2,[134,32]]}"#).is_err());
+//! ```
+
+use crate::{bits, DecSixbit, Error};
+use alloc::vec::Vec;
+
+/// Checks a raw pair and converts it into a `DecSixbit`, adding the trailing-space marker if needed.
+///
+/// Unlike `decode`, this also rejects nonzero padding bits.
+fn to_sixbit(value: &(Vec<u8>, usize)) -> Result<DecSixbit, Error> {
+    DecSixbit::from_payload(value.0.clone(), value.1)
+}
+
+/// Converts a `DecSixbit` into the raw pair, dropping the trailing-space marker.
+fn into_raw(sixbit: DecSixbit) -> (Vec<u8>, usize) {
+    let len = sixbit.len();
+    let mut bytes = sixbit.into_boxed_bytes().into_vec();
+    bytes.truncate(bits::bytes_for_chars(len));
+    (bytes, len)
+}
+
+/// Serializes a raw pair as a string in human-readable formats and as packed bytes otherwise.
+pub mod as_str {
+    use super::{into_raw, to_sixbit};
+    use crate::DecSixbit;
+    use alloc::vec::Vec;
+    use serde::{Deserialize, Serialize};
+
+    /// Serializes `value` like the equivalent [`DecSixbit`].
+    ///
+    /// # Errors
+    /// Fails if the bytes and length are inconsistent.
+    pub fn serialize<S: serde::Serializer>(value: &(Vec<u8>, usize), serializer: S) -> Result<S::Ok, S::Error> {
+        to_sixbit(value).map_err(serde::ser::Error::custom)?.serialize(serializer)
+    }
+
+    /// Deserializes a raw pair from anything a [`DecSixbit`] accepts.
+    ///
+    /// # Errors
+    /// Fails if the string contains characters outside the SIXBIT range, or the packed bytes and length
+    /// are inconsistent.
+    pub fn deserialize<'de, D: serde::Deserializer<'de>>(deserializer: D) -> Result<(Vec<u8>, usize), D::Error> {
+        DecSixbit::deserialize(deserializer).map(into_raw)
+    }
+}
+
+/// Serializes a raw pair as its length and packed bytes in every format.
+pub mod as_bytes {
+    use super::{into_raw, to_sixbit};
+    use crate::DecSixbit;
+    use alloc::vec::Vec;
+
+    /// Serializes `value` as the length followed by the packed bytes.
+    ///
+    /// # Errors
+    /// Fails if the bytes and length are inconsistent.
+    pub fn serialize<S: serde::Serializer>(value: &(Vec<u8>, usize), serializer: S) -> Result<S::Ok, S::Error> {
+        to_sixbit(value).map_err(serde::ser::Error::custom)?.serialize_packed(serializer)
+    }
+
+    /// Deserializes a raw pair from its length and packed bytes.
+    ///
+    /// # Errors
+    /// Fails if the packed bytes and length are inconsistent.
+    pub fn deserialize<'de, D: serde::Deserializer<'de>>(deserializer: D) -> Result<(Vec<u8>, usize), D::Error> {
+        DecSixbit::deserialize_packed(deserializer).map(into_raw)
+    }
+}
+
+/// Serializes a `DecSixbit` like its own `Serialize`, and deserializes packed bytes leniently.
+///
+/// Binary formats normally reject packed bytes that aren't in the canonical form, such as data written
+/// by older versions with a spurious trailing-space marker or nonzero padding bits. This helper reads
+/// them with [`DecSixbit::from_parts_lenient`] instead, normalizing the value. Human-readable formats
+/// are read as usual.
+///
+/// ```rust
+/// use dec_sixbit::DecSixbit;
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Record {
+///     #[serde(with = "dec_sixbit::serde_helpers::canonicalizing")]
+///     name: DecSixbit,
+/// }
+///
+/// // Length 2 with nonzero padding bits in the last byte
+/// let legacy = [2u8, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0x86, 0x2F];
+/// let record: Record = bincode::deserialize(&legacy).unwrap();
+/// assert_eq!(record.name.to_string(), "AB");
+/// assert!(record.name.is_canonical());
+/// ```
+pub mod canonicalizing {
+    use crate::DecSixbit;
+    use serde::{Deserialize, Serialize};
+
+    /// Serializes `value` exactly like `DecSixbit`.
+    pub fn serialize<S: serde::Serializer>(value: &DecSixbit, serializer: S) -> Result<S::Ok, S::Error> {
+        value.serialize(serializer)
+    }
+
+    /// Deserializes a `DecSixbit`, normalizing packed bytes that aren't in the canonical form.
+    ///
+    /// # Errors
+    /// Fails if the string contains characters outside the SIXBIT range, or the packed bytes are too
+    /// short for the length or followed by anything but the trailing-space marker.
+    pub fn deserialize<'de, D: serde::Deserializer<'de>>(deserializer: D) -> Result<DecSixbit, D::Error> {
+        if deserializer.is_human_readable() {
+            // Packed bytes in self-describing formats are already read leniently
+            DecSixbit::deserialize(deserializer)
+        } else {
+            DecSixbit::deserialize_packed_with(deserializer, DecSixbit::from_parts_lenient)
+        }
+    }
+}
+
+/// Reads an optional value, treating an empty value as `None`.
+fn deserialize_non_empty<'de, D: serde::Deserializer<'de>>(deserializer: D) -> Result<Option<DecSixbit>, D::Error> {
+    use serde::Deserialize;
+    Ok(Option::<DecSixbit>::deserialize(deserializer)?.filter(|sixbit| !sixbit.is_empty()))
+}
+
+/// Serializes an `Option<DecSixbit>` with `None` as an empty value, reading `""` and `null` as `None`.
+///
+/// Use it together with `default` so a missing field is also `None`:
+///
+/// ```rust
+/// use dec_sixbit::DecSixbit;
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Record {
+///     #[serde(with = "dec_sixbit::serde_helpers::empty_as_none", default)]
+///     alias: Option<DecSixbit>,
+/// }
+///
+/// let record: Record = serde_json::from_str(r#"{"alias":""}"#).unwrap();
+/// assert_eq!(record.alias, None);
+/// assert_eq!(serde_json::to_string(&record).unwrap(), r#"{"alias":""}"#);
+///
+/// let record: Record = serde_json::from_str("{}").unwrap();
+/// assert_eq!(record.alias, None);
+/// ```
+pub mod empty_as_none {
+    use crate::DecSixbit;
+    use serde::{Deserialize, Serialize};
+
+    /// Serializes `None` as an empty [`DecSixbit`], which is `""` in human-readable formats.
+    pub fn serialize<S: serde::Serializer>(value: &Option<DecSixbit>, serializer: S) -> Result<S::Ok, S::Error> {
+        match value {
+            Some(sixbit) => sixbit.serialize(serializer),
+            None => DecSixbit::default().serialize(serializer),
+        }
+    }
+
+    /// Deserializes `""`, `null`, and an empty value as `None`.
+    ///
+    /// # Errors
+    /// Fails if a non-empty value is not a valid [`DecSixbit`].
+    pub fn deserialize<'de, D: serde::Deserializer<'de>>(deserializer: D) -> Result<Option<DecSixbit>, D::Error> {
+        if deserializer.is_human_readable() {
+            super::deserialize_non_empty(deserializer)
+        } else {
+            // Binary formats always hold a value, since `serialize` never writes `None`
+            let sixbit = DecSixbit::deserialize(deserializer)?;
+            Ok(Some(sixbit).filter(|sixbit| !sixbit.is_empty()))
+        }
+    }
+}
+
+/// Serializes an `Option<DecSixbit>` with `None` as `null`, reading `""` and `null` as `None`.
+///
+/// Use it together with `default` so a missing field is also `None`.
+pub mod none_as_null {
+    use crate::DecSixbit;
+    use serde::Serialize;
+
+    /// Serializes `None` as `null`, and an empty value as `None` too.
+    pub fn serialize<S: serde::Serializer>(value: &Option<DecSixbit>, serializer: S) -> Result<S::Ok, S::Error> {
+        value.as_ref().filter(|sixbit| !sixbit.is_empty()).serialize(serializer)
+    }
+
+    /// Deserializes `""`, `null`, and an empty value as `None`.
+    ///
+    /// # Errors
+    /// Fails if a non-empty value is not a valid [`DecSixbit`].
+    pub fn deserialize<'de, D: serde::Deserializer<'de>>(deserializer: D) -> Result<Option<DecSixbit>, D::Error> {
+        super::deserialize_non_empty(deserializer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_fixtures::INPUTS;
+    use crate::{encode, DecSixbit};
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Record {
+        #[serde(with = "crate::serde_helpers::as_str")]
+        name: (Vec<u8>, usize),
+        #[serde(with = "crate::serde_helpers::as_bytes")]
+        code: (Vec<u8>, usize),
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Owned {
+        name: DecSixbit,
+        code: DecSixbit,
+    }
+
+    fn record(name: &str, code: &str) -> Record {
+        Record { name: encode(name).unwrap(), code: encode(code).unwrap() }
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Optional {
+        #[serde(with = "crate::serde_helpers::empty_as_none", default)]
+        empty: Option<DecSixbit>,
+        #[serde(with = "crate::serde_helpers::none_as_null", default)]
+        null: Option<DecSixbit>,
+    }
+
+    #[test]
+    fn test_optional_deserialize() {
+        let hello = Some(DecSixbit::new("HELLO").unwrap());
+        let cases = [
+            ("{}", None),
+            (r#"{"empty":null,"null":null}"#, None),
+            (r#"{"empty":"","null":""}"#, None),
+            (r#"{"empty":"HELLO","null":"HELLO"}"#, hello.clone()),
+        ];
+        for (json, expected) in cases {
+            let optional = serde_json::from_str::<Optional>(json).unwrap();
+            assert_eq!(optional, Optional { empty: expected.clone(), null: expected }, "{json}");
+        }
+
+        assert!(serde_json::from_str::<Optional>(r#"{"empty":"hello"}"#).is_err());
+        assert!(serde_json::from_str::<Optional>(r#"{"null":"hello"}"#).is_err());
+    }
+
+    #[test]
+    fn test_optional_serialize() {
+        let none = Optional { empty: None, null: None };
+        assert_eq!(serde_json::to_string(&none).unwrap(), r#"{"empty":"","null":null}"#);
+        let empty = Optional { empty: Some(DecSixbit::default()), null: Some(DecSixbit::default()) };
+        assert_eq!(serde_json::to_string(&empty).unwrap(), r#"{"empty":"","null":null}"#);
+
+        let hello = Optional { empty: Some(DecSixbit::new("HELLO").unwrap()), null: Some(DecSixbit::new("HELLO").unwrap()) };
+        assert_eq!(serde_json::to_string(&hello).unwrap(), r#"{"empty":"HELLO","null":"HELLO"}"#);
+
+        for optional in [none, hello] {
+            let binary = bincode::serialize(&optional).unwrap();
+            assert_eq!(bincode::deserialize::<Optional>(&binary).unwrap(), optional);
+        }
+    }
+
+    #[test]
+    fn test_round_trip() {
+        for input in INPUTS {
+            let record = record(input, input);
+            let json = serde_json::to_string(&record).unwrap();
+            assert_eq!(serde_json::from_str::<Record>(&json).unwrap(), record);
+            let binary = bincode::serialize(&record).unwrap();
+            assert_eq!(bincode::deserialize::<Record>(&binary).unwrap(), record);
+        }
+    }
+
+    #[test]
+    fn test_layout_matches_dec_sixbit() {
+        for input in INPUTS {
+            let record = record(input, input);
+            let owned = Owned { name: DecSixbit::new(input).unwrap(), code: DecSixbit::new(input).unwrap() };
+            let binary = bincode::serialize(&record).unwrap();
+            assert_eq!(binary, bincode::serialize(&owned).unwrap());
+            assert_eq!(bincode::deserialize::<Owned>(&binary).unwrap(), owned);
+
+            let json = serde_json::to_value(&record).unwrap();
+            assert_eq!(json["name"], serde_json::to_value(&owned.name).unwrap());
+            assert_eq!(json["code"][0], input.len());
+        }
+    }
+
+    #[test]
+    fn test_deserialize_invalid_character() {
+        let error = serde_json::from_str::<Record>(r#"{"name":"hello","code":[0,[]]}"#).unwrap_err();
+        assert!(error.to_string().contains("invalid character"), "{error}");
+    }
+
+    #[test]
+    fn test_deserialize_inconsistent_length() {
+        assert!(serde_json::from_str::<Record>(r#"{"name":"","code":[3,[132]]}"#).is_err());
+        let binary = bincode::serialize(&(0u64, Vec::<u8>::new(), 3u64, vec![0x84u8])).unwrap();
+        assert!(bincode::deserialize::<Record>(&binary).is_err());
+    }
+
+    #[test]
+    fn test_serialize_rejects_inconsistent_pair() {
+        let mut bad = record("AB", "AB");
+        bad.name.1 = 5;
+        assert!(serde_json::to_string(&bad).is_err());
+        assert!(bincode::serialize(&bad).is_err());
+
+        let mut bad = record("AB", "AB");
+        bad.code.0[1] |= 1; // Padding bits set
+        assert!(serde_json::to_string(&bad).is_err());
+    }
+}
@@ -0,0 +1,285 @@
+//! Provides the `SharedDecSixbit` type, a reference-counted SIXBIT value with O(1) cloning.
+//!
+//! `SharedDecSixbit` holds its packed bytes in an `Arc<[u8]>`, so clones share one allocation and can be
+//! sent across threads freely. It dereferences to [`DecSixbitStr`] like [`DecSixbit`] does, and compares,
+//! hashes, orders, and serializes exactly like the equivalent `DecSixbit`.
+
+use crate::{display, DecSixbit, DecSixbitStr, Error};
+use alloc::borrow::ToOwned;
+use alloc::sync::Arc;
+use core::borrow::Borrow;
+use core::cmp::Ordering;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::ops::Deref;
+
+/// An immutable SIXBIT value whose clones share the same bytes.
+///
+/// # Examples
+///
+/// ```rust
+/// use dec_sixbit::{DecSixbit, SharedDecSixbit};
+///
+/// let shared = SharedDecSixbit::from(DecSixbit::new("DSKB").unwrap());
+/// let copy = shared.clone();
+/// assert_eq!(copy.as_bytes().as_ptr(), shared.as_bytes().as_ptr());
+/// assert_eq!(copy.to_string(), "DSKB");
+/// ```
+#[derive(Clone)]
+pub struct SharedDecSixbit {
+    /// Original string length
+    len: usize,
+    /// Packed bytes exactly as returned by [`DecSixbit::as_bytes`]
+    pub(crate) bytes: Arc<[u8]>,
+}
+
+impl SharedDecSixbit {
+    /// Creates a new `SharedDecSixbit` instance by encoding the input string.
+    ///
+    /// # Parameters
+    /// - `str`: The input string to encode. Must contain only ASCII characters in the range 32-95.
+    ///
+    /// # Errors
+    /// Returns an [`Error::InvalidCharacter`] if the input contains invalid characters.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use dec_sixbit::SharedDecSixbit;
+    ///
+    /// let shared = SharedDecSixbit::new("HELLO").unwrap();
+    /// assert_eq!(shared.len(), 5);
+    /// ```
+    pub fn new(str: &str) -> Result<Self, Error> {
+        DecSixbit::new(str).map(Self::from)
+    }
+
+    /// Returns the number of encoded characters.
+    ///
+    /// This reads the stored length instead of inferring it from the bytes.
+    #[inline(always)]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Checks if the value holds no characters.
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Borrows the value as a [`DecSixbitStr`] view.
+    #[inline(always)]
+    pub fn as_sixbit_str(&self) -> &DecSixbitStr {
+        DecSixbitStr::from_bytes_unchecked(&self.bytes)
+    }
+
+    /// Copies the bytes into a new, independently owned [`DecSixbit`].
+    pub fn to_dec_sixbit(&self) -> DecSixbit {
+        self.as_sixbit_str().to_owned()
+    }
+}
+
+impl From<DecSixbit> for SharedDecSixbit {
+    fn from(sixbit: DecSixbit) -> Self {
+        Self {
+            len: sixbit.len(),
+            bytes: sixbit.into_boxed_bytes().into(),
+        }
+    }
+}
+
+impl From<&DecSixbitStr> for SharedDecSixbit {
+    fn from(sixbit: &DecSixbitStr) -> Self {
+        Self {
+            len: sixbit.len(),
+            bytes: sixbit.as_bytes().into(),
+        }
+    }
+}
+
+impl From<SharedDecSixbit> for DecSixbit {
+    fn from(shared: SharedDecSixbit) -> Self {
+        shared.to_dec_sixbit()
+    }
+}
+
+impl core::str::FromStr for SharedDecSixbit {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::new(s)
+    }
+}
+
+impl Deref for SharedDecSixbit {
+    type Target = DecSixbitStr;
+
+    #[inline(always)]
+    fn deref(&self) -> &DecSixbitStr {
+        self.as_sixbit_str()
+    }
+}
+
+impl Borrow<DecSixbitStr> for SharedDecSixbit {
+    fn borrow(&self) -> &DecSixbitStr {
+        self.as_sixbit_str()
+    }
+}
+
+impl AsRef<DecSixbitStr> for SharedDecSixbit {
+    fn as_ref(&self) -> &DecSixbitStr {
+        self.as_sixbit_str()
+    }
+}
+
+impl AsRef<[u8]> for SharedDecSixbit {
+    fn as_ref(&self) -> &[u8] {
+        &self.bytes
+    }
+}
+
+impl PartialEq for SharedDecSixbit {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        // Clones share their bytes, which makes the common case a pointer comparison
+        Arc::ptr_eq(&self.bytes, &other.bytes) || self.as_sixbit_str() == other.as_sixbit_str()
+    }
+}
+
+impl Eq for SharedDecSixbit {}
+
+impl PartialEq<DecSixbit> for SharedDecSixbit {
+    fn eq(&self, other: &DecSixbit) -> bool {
+        self.as_sixbit_str() == other.as_sixbit_str()
+    }
+}
+
+impl PartialEq<SharedDecSixbit> for DecSixbit {
+    fn eq(&self, other: &SharedDecSixbit) -> bool {
+        self.as_sixbit_str() == other.as_sixbit_str()
+    }
+}
+
+impl PartialOrd for SharedDecSixbit {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for SharedDecSixbit {
+    /// Orders values lexicographically by their decoded characters, like `str`.
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.as_sixbit_str().cmp(other.as_sixbit_str())
+    }
+}
+
+impl Hash for SharedDecSixbit {
+    /// Hashes the same way as [`DecSixbit`] and [`DecSixbitStr`].
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_sixbit_str().hash(state);
+    }
+}
+
+impl fmt::Debug for SharedDecSixbit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        display::debug_packed(f, "SharedDecSixbit", &self.bytes, self.len)
+    }
+}
+
+impl fmt::Display for SharedDecSixbit {
+    #[inline(always)]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self.as_sixbit_str(), f)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for SharedDecSixbit {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.as_sixbit_str().serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for SharedDecSixbit {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<SharedDecSixbit, D::Error> {
+        DecSixbit::deserialize(deserializer).map(Self::from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SharedDecSixbit;
+    use crate::test_fixtures::INPUTS;
+    use crate::DecSixbit;
+    use std::collections::HashSet;
+    use std::hash::{BuildHasher, RandomState};
+
+    #[test]
+    fn test_send_sync() {
+        fn assert_send_sync<T: Send + Sync>() {}
+        assert_send_sync::<SharedDecSixbit>();
+    }
+
+    #[test]
+    fn test_clone_shares_bytes() {
+        let shared = SharedDecSixbit::new("SPHINX OF BLACK QUARTZ, JUDGE MY VOW!").unwrap();
+        let clone = shared.clone();
+        assert_eq!(shared.as_bytes().as_ptr(), clone.as_bytes().as_ptr());
+        assert_eq!(shared, clone);
+    }
+
+    #[test]
+    fn test_agrees_with_owned() {
+        let state = RandomState::new();
+        for input in INPUTS {
+            let owned = DecSixbit::new(input).unwrap();
+            let shared = SharedDecSixbit::from(owned.clone());
+            assert_eq!(shared, owned);
+            assert_eq!(owned, shared);
+            assert_eq!(shared.len(), owned.len());
+            assert_eq!(shared.as_bytes(), owned.as_bytes());
+            assert_eq!(shared.to_string(), owned.to_string());
+            assert_eq!(state.hash_one(&shared), state.hash_one(&owned));
+            assert_eq!(DecSixbit::from(shared.clone()), owned);
+            #[cfg(feature = "serde")]
+            {
+                assert_eq!(serde_json::to_string(&shared).unwrap(), serde_json::to_string(&owned).unwrap());
+                assert_eq!(bincode::serialize(&shared).unwrap(), bincode::serialize(&owned).unwrap());
+            }
+        }
+    }
+
+    #[test]
+    fn test_ordering_matches_owned() {
+        for a in INPUTS {
+            for b in INPUTS {
+                let a_shared = SharedDecSixbit::new(a).unwrap();
+                let b_shared = SharedDecSixbit::new(b).unwrap();
+                let expected = DecSixbit::new(a).unwrap().cmp(&DecSixbit::new(b).unwrap());
+                assert_eq!(a_shared.cmp(&b_shared), expected);
+            }
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_round_trip() {
+        let shared = SharedDecSixbit::new("HELLO WORLD").unwrap();
+        let json = serde_json::to_string(&shared).unwrap();
+        assert_eq!(serde_json::from_str::<SharedDecSixbit>(&json).unwrap(), shared);
+        let binary = bincode::serialize(&shared).unwrap();
+        assert_eq!(bincode::deserialize::<SharedDecSixbit>(&binary).unwrap(), shared);
+    }
+
+    #[test]
+    fn test_set_lookup_by_view() {
+        let set: HashSet<SharedDecSixbit> = INPUTS.iter().map(|s| SharedDecSixbit::new(s).unwrap()).collect();
+        let owned = DecSixbit::new("HELLO WORLD").unwrap();
+        assert!(set.contains(owned.as_sixbit_str()));
+    }
+}
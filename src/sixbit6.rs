@@ -0,0 +1,243 @@
+//! Provides the `Sixbit6` type for the classic 6-character space-padded DEC name field.
+//!
+//! Task names, device names, file names, and many other identifiers in DEC software are stored as
+//! exactly six SIXBIT characters, padded with trailing spaces. `Sixbit6` packs such a name into 5 bytes
+//! and normalizes shorter input to the padded form, so `"FOO"` and `"FOO   "` are the same value.
+
+use crate::{bits, decode::decode_unchecked, display, encode::encode_into, DecSixbit, DecSixbit6, Error};
+use alloc::string::String;
+use core::fmt;
+
+/// A 6-character space-padded SIXBIT name packed into 5 bytes.
+///
+/// The 36 bits of the six characters fill the first 4.5 bytes; the last 4 bits are always zero.
+/// Values order lexicographically by their padded form, like `str`.
+///
+/// # Examples
+///
+/// ```rust
+/// use dec_sixbit::Sixbit6;
+///
+/// let task = Sixbit6::new("PIP").unwrap();
+/// assert_eq!(task, Sixbit6::new("PIP   ").unwrap());
+/// assert_eq!(task.to_string(), "PIP   ");
+/// assert_eq!(task.to_string_trimmed(), "PIP");
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Sixbit6([u8; 5]);
+
+impl Sixbit6 {
+    /// The number of characters in a name.
+    pub const LEN: usize = 6;
+
+    /// Creates a new `Sixbit6` by encoding a name of up to 6 characters, padding it with trailing spaces.
+    ///
+    /// # Parameters
+    /// - `str`: The name to encode. Must contain only ASCII characters in the range 32-95.
+    ///
+    /// # Errors
+    /// - Returns an [`Error::FieldOverflow`] if `str` is longer than 6 characters.
+    /// - Returns an [`Error::InvalidCharacter`] if the input contains invalid characters.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use dec_sixbit::{Error, Sixbit6};
+    ///
+    /// assert!(Sixbit6::new("RSX11M").is_ok());
+    /// assert_eq!(Sixbit6::new("RSX11M+"), Err(Error::FieldOverflow));
+    /// ```
+    pub fn new(str: &str) -> Result<Self, Error> {
+        if str.len() > Self::LEN {
+            return Err(Error::FieldOverflow);
+        }
+        // Spaces are SIXBIT 0, so the zeroed tail is already padding
+        let mut bytes = [0; 5];
+        encode_into(str, &mut bytes)?;
+        Ok(Self(bytes))
+    }
+
+    /// Creates a `Sixbit6` from its packed bytes.
+    ///
+    /// # Errors
+    /// Returns an [`Error::InvalidBytesLength`] if the last 4 bits are not zero.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use dec_sixbit::Sixbit6;
+    ///
+    /// let name = Sixbit6::new("DSKB").unwrap();
+    /// assert_eq!(Sixbit6::from_bytes(*name.as_bytes()), Ok(name));
+    /// ```
+    pub fn from_bytes(bytes: [u8; 5]) -> Result<Self, Error> {
+        if bytes[4] & 0b1111 != 0 {
+            return Err(Error::InvalidBytesLength);
+        }
+        Ok(Self(bytes))
+    }
+
+    /// Returns a reference to the 5 packed bytes.
+    #[inline(always)]
+    pub fn as_bytes(&self) -> &[u8; 5] {
+        &self.0
+    }
+
+    /// Returns the number of characters before the trailing space padding.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use dec_sixbit::Sixbit6;
+    ///
+    /// assert_eq!(Sixbit6::new("PIP").unwrap().trimmed_len(), 3);
+    /// assert_eq!(Sixbit6::new("").unwrap().trimmed_len(), 0);
+    /// ```
+    pub fn trimmed_len(&self) -> usize {
+        // The characters occupy the top 36 bits; count the trailing 6-bit zero fields
+        let word = self.0.iter().fold(0u64, |acc, &b| (acc << 8) | b as u64) >> 4;
+        if word == 0 {
+            return 0;
+        }
+        Self::LEN - word.trailing_zeros() as usize / 6
+    }
+
+    /// Returns `true` if the name consists only of spaces.
+    pub fn is_blank(&self) -> bool {
+        self.0 == [0; 5]
+    }
+
+    /// Decodes the name without its trailing space padding.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use dec_sixbit::Sixbit6;
+    ///
+    /// assert_eq!(Sixbit6::new("A B").unwrap().to_string_trimmed(), "A B");
+    /// ```
+    pub fn to_string_trimmed(&self) -> String {
+        decode_unchecked(&self.0, self.trimmed_len())
+    }
+}
+
+impl From<Sixbit6> for DecSixbit {
+    /// Converts to the padded 6-character form.
+    fn from(name: Sixbit6) -> Self {
+        DecSixbit::from_encoded(name.0.to_vec(), Sixbit6::LEN)
+    }
+}
+
+impl From<Sixbit6> for DecSixbit6 {
+    fn from(name: Sixbit6) -> Self {
+        DecSixbit6::from_bytes_unchecked(name.0)
+    }
+}
+
+impl From<DecSixbit6> for Sixbit6 {
+    /// Clears the padding bits, which a `DecSixbit6` cast from memory may have set.
+    fn from(name: DecSixbit6) -> Self {
+        let mut bytes = *name.as_bytes();
+        bits::clear_padding(&mut bytes, Self::LEN);
+        Self(bytes)
+    }
+}
+
+impl core::str::FromStr for Sixbit6 {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::new(s)
+    }
+}
+
+impl AsRef<[u8]> for Sixbit6 {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl fmt::Debug for Sixbit6 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        display::debug_packed(f, "Sixbit6", &self.0, Self::LEN)
+    }
+}
+
+impl fmt::Display for Sixbit6 {
+    /// Shows the padded 6-character form.
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        display::pad_packed(f, &self.0, Self::LEN)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new_pads_short_input() {
+        let full = "ABCDEF";
+        for len in 0..=6 {
+            let name = Sixbit6::new(&full[..len]).unwrap();
+            assert_eq!(name.to_string(), format!("{:<6}", &full[..len]));
+            assert_eq!(name.to_string_trimmed(), &full[..len]);
+            assert_eq!(name.trimmed_len(), len);
+            assert_eq!(name, Sixbit6::new(&format!("{:<6}", &full[..len])).unwrap());
+        }
+        assert!(Sixbit6::new("").unwrap().is_blank());
+    }
+
+    #[test]
+    fn test_new_too_long() {
+        assert_eq!(Sixbit6::new("ABCDEFG"), Err(Error::FieldOverflow));
+        assert_eq!(Sixbit6::new("abc"), Err(Error::InvalidCharacter));
+    }
+
+    #[test]
+    fn test_known_bytes() {
+        let name = Sixbit6::new("RSX11M").unwrap();
+        assert_eq!(name.as_bytes(), &[0xCB, 0x3E, 0x11, 0x46, 0xD0]);
+        assert_eq!(format!("{:?}", name), r#"Sixbit6("RSX11M", len=6, bytes=[0xcb, 0x3e, 0x11, 0x46, 0xd0])"#);
+        assert_eq!(Sixbit6::from_bytes([0xCB, 0x3E, 0x11, 0x46, 0xD1]), Err(Error::InvalidBytesLength));
+    }
+
+    #[test]
+    fn test_trimmed_keeps_inner_spaces() {
+        let name = Sixbit6::new(" A  B").unwrap();
+        assert_eq!(name.trimmed_len(), 5);
+        assert_eq!(name.to_string_trimmed(), " A  B");
+    }
+
+    #[test]
+    fn test_round_trips() {
+        for input in ["RSX11M", "DSKB", "", "A    B"] {
+            let name = Sixbit6::new(input).unwrap();
+            let sixbit = DecSixbit::from(name);
+            assert_eq!(sixbit.to_string(), format!("{:<6}", input));
+            assert_eq!(sixbit, DecSixbit::new(&format!("{:<6}", input)).unwrap());
+            assert_eq!(Sixbit6::from(DecSixbit6::from(name)), name);
+            assert_eq!(Sixbit6::from_bytes(*name.as_bytes()), Ok(name));
+        }
+    }
+
+    #[test]
+    fn test_from_array_clears_padding() {
+        // "ABCD  " with the padding bits set, as a zerocopy or bytemuck cast can produce it
+        let cast = DecSixbit6::from_bytes_unchecked([0x86, 0x28, 0xe4, 0x00, 0x0f]);
+        let name = Sixbit6::from(cast);
+        assert_eq!(name, Sixbit6::new("ABCD").unwrap());
+        assert_eq!(Sixbit6::from_bytes(*name.as_bytes()), Ok(name));
+    }
+
+    #[test]
+    fn test_ordering_matches_padded_str() {
+        let inputs = ["", "A", "AB", "A B", "B", "ZZZZZZ", "A_"];
+        for a in inputs {
+            for b in inputs {
+                let expected = format!("{:<6}", a).cmp(&format!("{:<6}", b));
+                assert_eq!(Sixbit6::new(a).unwrap().cmp(&Sixbit6::new(b).unwrap()), expected);
+            }
+        }
+    }
+}
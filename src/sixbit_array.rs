@@ -0,0 +1,350 @@
+//! Provides the `DecSixbitArray` type for fixed-length SIXBIT values stored without allocating.
+//!
+//! The packed bytes live in an array, so the type is `Copy` and can be embedded in other structures or
+//! used as a hash map key at no allocation cost. Stable Rust cannot yet compute an array length from a
+//! const parameter, so the byte count is a second parameter checked at compile time; the type aliases
+//! such as [`DecSixbit12`] spell out the common sizes.
+//!
+//! The type is `#[repr(transparent)]` over its byte array, and with the `zerocopy` or `bytemuck` feature
+//! it implements their traits for casting memory-mapped tables of packed fields without copying.
+
+use crate::{bits, display, encode::encode_into, DecSixbit, DecSixbitStr, Error};
+use core::fmt;
+
+/// A SIXBIT value of exactly `N` characters, packed into `M` bytes on the stack.
+///
+/// `M` must equal the number of bytes needed for `N` characters, `(N * 6).div_ceil(8)`; using any other
+/// value fails to compile when the type is constructed. Prefer the aliases, e.g. [`DecSixbit12`].
+///
+/// ```compile_fail
+/// use dec_sixbit::DecSixbitArray;
+///
+/// let wrong = DecSixbitArray::<12, 8>::new("SYS:DSKB.DAT");
+/// ```
+///
+/// Unlike [`DecSixbit`], the length is part of the type, so [`as_bytes`](Self::as_bytes) never includes
+/// the trailing-space marker byte. Values of the same type order lexicographically, like `str`.
+///
+/// Every bit pattern is a valid value: each 6-bit group decodes to some SIXBIT character, so bytes cast
+/// from memory with the `zerocopy` or `bytemuck` feature never need validation. The padding bits after
+/// the last character are normally zero; when they aren't, they are ignored by decoding and cleared by
+/// [`to_dec_sixbit`](Self::to_dec_sixbit), but take part in comparisons, which see the raw bytes.
+///
+/// # Examples
+///
+/// ```rust
+/// use dec_sixbit::DecSixbit12;
+///
+/// let key = DecSixbit12::new("SYS:DSKB.DAT").unwrap();
+/// let copy = key;
+/// assert_eq!(key, copy);
+/// assert_eq!(key.to_string(), "SYS:DSKB.DAT");
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(zerocopy::FromBytes, zerocopy::IntoBytes, zerocopy::Unaligned, zerocopy::Immutable, zerocopy::KnownLayout)
+)]
+#[repr(transparent)]
+pub struct DecSixbitArray<const N: usize, const M: usize> {
+    /// Packed bytes where every 3 bytes contain 4 characters (6 bits each)
+    bytes: [u8; M],
+}
+
+/// A 6-character fixed-length SIXBIT value.
+pub type DecSixbit6 = DecSixbitArray<6, 5>;
+/// An 8-character fixed-length SIXBIT value.
+pub type DecSixbit8 = DecSixbitArray<8, 6>;
+/// A 12-character fixed-length SIXBIT value.
+pub type DecSixbit12 = DecSixbitArray<12, 9>;
+/// A 16-character fixed-length SIXBIT value.
+pub type DecSixbit16 = DecSixbitArray<16, 12>;
+
+impl<const N: usize, const M: usize> DecSixbitArray<N, M> {
+    /// Evaluated on construction so that a mismatched `M` is a compile error.
+    const VALID: () = assert!(M == bits::bytes_for_chars(N), "M must be (N * 6).div_ceil(8)");
+
+    /// Creates a new value by encoding a string of exactly `N` characters.
+    ///
+    /// # Parameters
+    /// - `str`: The input string to encode. Must contain only ASCII characters in the range 32-95.
+    ///
+    /// # Errors
+    /// - Returns an [`Error::LengthMismatch`] if `str` is not exactly `N` characters long.
+    /// - Returns an [`Error::InvalidCharacter`] if the input contains invalid characters.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use dec_sixbit::{DecSixbit8, Error};
+    ///
+    /// assert!(DecSixbit8::new("FILENAME").is_ok());
+    /// assert_eq!(DecSixbit8::new("FILE"), Err(Error::LengthMismatch));
+    /// ```
+    pub fn new(str: &str) -> Result<Self, Error> {
+        if str.len() != N {
+            return Err(Error::LengthMismatch);
+        }
+        Self::new_padded(str)
+    }
+
+    /// Creates a new value by encoding a string of up to `N` characters, padding it with trailing spaces.
+    ///
+    /// # Parameters
+    /// - `str`: The input string to encode. Must contain only ASCII characters in the range 32-95.
+    ///
+    /// # Errors
+    /// - Returns an [`Error::FieldOverflow`] if `str` is longer than `N` characters.
+    /// - Returns an [`Error::InvalidCharacter`] if the input contains invalid characters.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use dec_sixbit::DecSixbit8;
+    ///
+    /// let name = DecSixbit8::new_padded("FILE").unwrap();
+    /// assert_eq!(name.to_string(), "FILE    ");
+    /// ```
+    pub fn new_padded(str: &str) -> Result<Self, Error> {
+        let () = Self::VALID;
+        if str.len() > N {
+            return Err(Error::FieldOverflow);
+        }
+        // Spaces are SIXBIT 0, so the zeroed tail is already padding
+        let mut bytes = [0; M];
+        encode_into(str, &mut bytes)?;
+        Ok(Self { bytes })
+    }
+
+    /// Wraps packed bytes that are known to hold `N` valid characters.
+    #[inline(always)]
+    pub(crate) fn from_bytes_unchecked(bytes: [u8; M]) -> Self {
+        Self { bytes }
+    }
+
+    /// Returns a reference to the packed SIXBIT bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use dec_sixbit::DecSixbit6;
+    ///
+    /// let name = DecSixbit6::new("RSX11M").unwrap();
+    /// assert_eq!(name.as_bytes().len(), 5);
+    /// ```
+    #[inline(always)]
+    pub fn as_bytes(&self) -> &[u8; M] {
+        &self.bytes
+    }
+
+    /// Returns the number of characters, which is always `N`.
+    #[inline(always)]
+    pub const fn len(&self) -> usize {
+        N
+    }
+
+    /// Checks if the value holds no characters, which is only the case when `N` is 0.
+    #[inline(always)]
+    pub const fn is_empty(&self) -> bool {
+        N == 0
+    }
+
+    /// Converts the value into a [`DecSixbit`] holding the same characters.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use dec_sixbit::{DecSixbit, DecSixbit6};
+    ///
+    /// let name = DecSixbit6::new("RSX11M").unwrap();
+    /// assert_eq!(name.to_dec_sixbit(), DecSixbit::new("RSX11M").unwrap());
+    /// ```
+    pub fn to_dec_sixbit(&self) -> DecSixbit {
+        let mut bytes = self.bytes.to_vec();
+        // Values cast from memory may have nonzero padding bits, which DecSixbit never stores
+        bits::clear_padding(&mut bytes, N);
+        DecSixbit::from_encoded(bytes, N)
+    }
+}
+
+// SAFETY: the type is `#[repr(transparent)]` over `[u8; M]`, for which every bit pattern, including all
+// zeros, is a valid value
+#[cfg(feature = "bytemuck")]
+unsafe impl<const N: usize, const M: usize> bytemuck::Zeroable for DecSixbitArray<N, M> {}
+#[cfg(feature = "bytemuck")]
+unsafe impl<const N: usize, const M: usize> bytemuck::Pod for DecSixbitArray<N, M> {}
+
+impl<const N: usize, const M: usize> From<DecSixbitArray<N, M>> for DecSixbit {
+    fn from(array: DecSixbitArray<N, M>) -> Self {
+        array.to_dec_sixbit()
+    }
+}
+
+impl<const N: usize, const M: usize> TryFrom<&DecSixbitStr> for DecSixbitArray<N, M> {
+    type Error = Error;
+
+    /// Copies the packed bytes of a value with exactly `N` characters, without decoding.
+    ///
+    /// Returns an [`Error::LengthMismatch`] if the value does not hold exactly `N` characters.
+    fn try_from(sixbit: &DecSixbitStr) -> Result<Self, Self::Error> {
+        let () = Self::VALID;
+        if sixbit.len() != N {
+            return Err(Error::LengthMismatch);
+        }
+        let mut bytes = [0; M];
+        bytes.copy_from_slice(&sixbit.as_bytes()[..M]);
+        Ok(Self { bytes })
+    }
+}
+
+impl<const N: usize, const M: usize> TryFrom<&DecSixbit> for DecSixbitArray<N, M> {
+    type Error = Error;
+
+    fn try_from(sixbit: &DecSixbit) -> Result<Self, Self::Error> {
+        Self::try_from(sixbit.as_sixbit_str())
+    }
+}
+
+impl<const N: usize, const M: usize> core::str::FromStr for DecSixbitArray<N, M> {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::new(s)
+    }
+}
+
+impl<const N: usize, const M: usize> AsRef<[u8]> for DecSixbitArray<N, M> {
+    fn as_ref(&self) -> &[u8] {
+        &self.bytes
+    }
+}
+
+impl<const N: usize, const M: usize> fmt::Debug for DecSixbitArray<N, M> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        display::debug_packed(f, "DecSixbitArray", &self.bytes, N)
+    }
+}
+
+impl<const N: usize, const M: usize> fmt::Display for DecSixbitArray<N, M> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        display::pad_packed(f, &self.bytes, N)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new_exact_length() {
+        let key = DecSixbit12::new("SYS:DSKB.DAT").unwrap();
+        assert_eq!(key.len(), 12);
+        assert_eq!(key.to_string(), "SYS:DSKB.DAT");
+        assert_eq!(key.as_bytes(), &crate::encode("SYS:DSKB.DAT").unwrap().0[..]);
+    }
+
+    #[test]
+    fn test_debug() {
+        let name = DecSixbit6::new("RSX11M").unwrap();
+        assert_eq!(format!("{:?}", name), r#"DecSixbitArray("RSX11M", len=6, bytes=[0xcb, 0x3e, 0x11, 0x46, 0xd0])"#);
+    }
+
+    #[test]
+    fn test_new_wrong_length() {
+        assert_eq!(DecSixbit12::new("SHORT"), Err(Error::LengthMismatch));
+        assert_eq!(DecSixbit12::new("THIRTEEN CHAR"), Err(Error::LengthMismatch));
+        assert_eq!(DecSixbit12::new("lowercase ok"), Err(Error::InvalidCharacter));
+    }
+
+    #[test]
+    fn test_new_padded() {
+        assert_eq!(DecSixbit8::new_padded("").unwrap().to_string(), "        ");
+        assert_eq!(DecSixbit8::new_padded("FILE").unwrap(), DecSixbit8::new("FILE    ").unwrap());
+        assert_eq!(DecSixbit8::new_padded("FILENAME1"), Err(Error::FieldOverflow));
+    }
+
+    #[test]
+    fn test_copy_semantics() {
+        let original = DecSixbit6::new("RSX11M").unwrap();
+        let mut copies = [original; 3];
+        copies[1] = DecSixbit6::new("IAS   ").unwrap();
+        assert_eq!(copies[0], original);
+        assert_eq!(copies[2], original);
+        assert_ne!(copies[1], original);
+    }
+
+    #[test]
+    fn test_round_trip_through_dec_sixbit() {
+        // Includes values whose DecSixbit form carries the trailing-space marker
+        for input in ["RSX11M", "DSKB    ", "AB  CD  ", "        "] {
+            let sixbit = DecSixbit::new(input).unwrap();
+            match input.len() {
+                6 => {
+                    let array = DecSixbit6::try_from(&sixbit).unwrap();
+                    assert_eq!(DecSixbit::from(array), sixbit);
+                },
+                8 => {
+                    let array = DecSixbit8::try_from(&sixbit).unwrap();
+                    assert_eq!(array.to_string(), input);
+                    assert_eq!(DecSixbit::from(array), sixbit);
+                },
+                _ => unreachable!(),
+            }
+        }
+        let sixbit = DecSixbit::new("TOO LONG FOR SIX").unwrap();
+        assert_eq!(DecSixbit6::try_from(&sixbit), Err(Error::LengthMismatch));
+    }
+
+    #[test]
+    fn test_ordering_matches_str() {
+        let inputs = ["AAAAAA", "AAAAA ", "AAAAAB", "B     ", "      "];
+        for a in inputs {
+            for b in inputs {
+                let a_array = DecSixbit6::new(a).unwrap();
+                let b_array = DecSixbit6::new(b).unwrap();
+                assert_eq!(a_array.cmp(&b_array), a.cmp(b));
+            }
+        }
+    }
+
+    /// Three packed 12-character fields as they would appear in a memory-mapped table.
+    #[cfg(any(feature = "zerocopy", feature = "bytemuck"))]
+    fn packed_table() -> Vec<u8> {
+        ["SYS:DSKB.DAT", "DSKA:FOO.BAR", "            "].iter().flat_map(|name| crate::encode(name).unwrap().0).collect()
+    }
+
+    #[cfg(feature = "zerocopy")]
+    #[test]
+    fn test_zerocopy_cast() {
+        use zerocopy::{FromBytes, IntoBytes};
+
+        let table = packed_table();
+        let fields = <[DecSixbit12]>::ref_from_bytes(&table).unwrap();
+        let names: Vec<_> = fields.iter().map(ToString::to_string).collect();
+        assert_eq!(names, ["SYS:DSKB.DAT", "DSKA:FOO.BAR", "            "]);
+        assert_eq!(fields.as_bytes(), &table[..]);
+        assert!(<[DecSixbit12]>::ref_from_bytes(&table[1..]).is_err());
+
+        // Nonzero padding bits decode like zero ones
+        let name = DecSixbit6::read_from_bytes(&[0x86, 0x28, 0xe4, 0x00, 0x0f]).unwrap();
+        assert_eq!(name.to_string(), "ABCD  ");
+        assert_eq!(name.to_dec_sixbit(), DecSixbit::new("ABCD  ").unwrap());
+        assert_eq!(crate::Sixbit6::from(name), crate::Sixbit6::new("ABCD").unwrap());
+    }
+
+    #[cfg(feature = "bytemuck")]
+    #[test]
+    fn test_bytemuck_cast() {
+        let table = packed_table();
+        let fields: &[DecSixbit12] = bytemuck::cast_slice(&table);
+        let names: Vec<_> = fields.iter().map(ToString::to_string).collect();
+        assert_eq!(names, ["SYS:DSKB.DAT", "DSKA:FOO.BAR", "            "]);
+        assert_eq!(bytemuck::cast_slice::<_, u8>(fields), &table[..]);
+        assert!(bytemuck::try_cast_slice::<_, DecSixbit12>(&table[1..]).is_err());
+
+        let name: DecSixbit6 = bytemuck::cast([0x86u8, 0x28, 0xe4, 0x00, 0x0f]);
+        assert_eq!(name.to_dec_sixbit(), DecSixbit::new("ABCD  ").unwrap());
+        assert_eq!(<DecSixbit6 as bytemuck::Zeroable>::zeroed(), DecSixbit6::new("      ").unwrap());
+    }
+}
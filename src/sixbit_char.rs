@@ -0,0 +1,250 @@
+//! Provides the `SixbitChar` type, a single character validated to be in the SIXBIT range.
+//!
+//! Converting to `SixbitChar` moves validation to the boundary: APIs that take or yield it, such as
+//! `DecSixbit::push_sixbit_char` and `FromIterator<SixbitChar>`, can then be infallible.
+
+use crate::{Error, ASCII_OFFSET};
+use core::convert::TryFrom;
+use core::fmt;
+
+/// The 64 SIXBIT characters in value order, from space (value 0) through underscore (value 63).
+///
+/// # Examples
+///
+/// ```rust
+/// use dec_sixbit::SIXBIT_CHARS;
+///
+/// assert_eq!(SIXBIT_CHARS[0], ' ');
+/// assert_eq!(SIXBIT_CHARS[33], 'A');
+/// assert_eq!(SIXBIT_CHARS[63], '_');
+/// ```
+pub const SIXBIT_CHARS: [char; 64] = {
+    let mut chars = [' '; 64];
+    let mut value = 0;
+    while value < 64 {
+        chars[value] = (value as u8 + ASCII_OFFSET) as char;
+        value += 1;
+    }
+    chars
+};
+
+/// Returns `true` if `c` is a SIXBIT character, in the range `' '..='_'`.
+///
+/// # Examples
+///
+/// ```rust
+/// use dec_sixbit::is_sixbit_char;
+///
+/// assert!(is_sixbit_char('A'));
+/// assert!(!is_sixbit_char('a'));
+/// ```
+#[inline(always)]
+pub const fn is_sixbit_char(c: char) -> bool {
+    matches!(c, ' '..='_')
+}
+
+/// Returns the 6-bit value of `c`, or `None` if it is not a SIXBIT character.
+///
+/// # Examples
+///
+/// ```rust
+/// use dec_sixbit::char_to_value;
+///
+/// assert_eq!(char_to_value(' '), Some(0));
+/// assert_eq!(char_to_value('A'), Some(33));
+/// assert_eq!(char_to_value('a'), None);
+/// ```
+#[inline(always)]
+pub const fn char_to_value(c: char) -> Option<u8> {
+    if is_sixbit_char(c) {
+        Some(c as u8 - ASCII_OFFSET)
+    } else {
+        None
+    }
+}
+
+/// Returns the SIXBIT character with the 6-bit value `value`, or `None` if `value` is 64 or greater.
+///
+/// # Examples
+///
+/// ```rust
+/// use dec_sixbit::value_to_char;
+///
+/// assert_eq!(value_to_char(33), Some('A'));
+/// assert_eq!(value_to_char(64), None);
+/// ```
+#[inline(always)]
+pub const fn value_to_char(value: u8) -> Option<char> {
+    if value < 64 {
+        Some(SIXBIT_CHARS[value as usize])
+    } else {
+        None
+    }
+}
+
+/// A SIXBIT character, stored as its 6-bit value in a single byte.
+///
+/// Values order and hash by their character, like `char`.
+///
+/// # Examples
+///
+/// ```rust
+/// use dec_sixbit::SixbitChar;
+/// use std::convert::TryFrom;
+///
+/// let c = SixbitChar::try_from('A').unwrap();
+/// assert_eq!(c.as_value(), 33);
+/// assert_eq!(char::from(c), 'A');
+/// assert!(SixbitChar::try_from('a').is_err());
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[repr(transparent)]
+pub struct SixbitChar(u8);
+
+impl SixbitChar {
+    /// The space character, SIXBIT value 0.
+    pub const SPACE: Self = Self(0);
+
+    /// The underscore character, the highest SIXBIT value 63.
+    pub const MAX: Self = Self(63);
+
+    /// Creates a `SixbitChar` from its 6-bit value.
+    ///
+    /// # Errors
+    /// Returns an [`Error::InvalidCharacter`] if `value` is 64 or greater.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use dec_sixbit::SixbitChar;
+    ///
+    /// assert_eq!(SixbitChar::from_value(0), Ok(SixbitChar::SPACE));
+    /// assert!(SixbitChar::from_value(64).is_err());
+    /// ```
+    #[inline]
+    pub const fn from_value(value: u8) -> Result<Self, Error> {
+        if value < 64 {
+            Ok(Self(value))
+        } else {
+            Err(Error::InvalidCharacter)
+        }
+    }
+
+    /// Wraps a value the caller has already masked to 6 bits.
+    #[cfg(feature = "with-struct")]
+    #[inline(always)]
+    pub(crate) const fn from_value_unchecked(value: u8) -> Self {
+        Self(value)
+    }
+
+    /// Returns the 6-bit value, `0..=63`.
+    #[inline(always)]
+    pub const fn as_value(self) -> u8 {
+        self.0
+    }
+
+    /// Returns the character, in the range `' '..='_'`.
+    #[inline(always)]
+    pub const fn as_char(self) -> char {
+        SIXBIT_CHARS[self.0 as usize]
+    }
+}
+
+impl TryFrom<char> for SixbitChar {
+    type Error = Error;
+
+    /// Converts a character in the range `' '..='_'`, returning an [`Error::InvalidCharacter`] otherwise.
+    #[inline]
+    fn try_from(c: char) -> Result<Self, Error> {
+        match char_to_value(c) {
+            Some(value) => Ok(Self(value)),
+            None => Err(Error::InvalidCharacter),
+        }
+    }
+}
+
+impl From<SixbitChar> for char {
+    #[inline(always)]
+    fn from(c: SixbitChar) -> Self {
+        c.as_char()
+    }
+}
+
+impl fmt::Debug for SixbitChar {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("SixbitChar").field(&self.as_char()).finish()
+    }
+}
+
+impl fmt::Display for SixbitChar {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.as_char(), f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{char_to_value, is_sixbit_char, value_to_char, SixbitChar, SIXBIT_CHARS};
+    use crate::Error;
+    use std::collections::HashSet;
+    use std::convert::TryFrom;
+
+    #[test]
+    fn test_char_table() {
+        assert_eq!(SIXBIT_CHARS.to_vec(), (32..=95).map(char::from).collect::<Vec<_>>());
+        assert_eq!(SIXBIT_CHARS.iter().collect::<HashSet<_>>().len(), 64);
+        for value in 0..=u8::MAX {
+            let c = value_to_char(value);
+            assert_eq!(c.is_some(), value < 64);
+            if let Some(c) = c {
+                assert_eq!(char_to_value(c), Some(value));
+                assert_eq!(SixbitChar::from_value(value).unwrap().as_char(), c);
+            }
+        }
+    }
+
+    #[test]
+    fn test_predicates_agree_with_encode() {
+        for byte in 0..=u8::MAX {
+            let c = char::from(byte);
+            let valid = crate::validate(c.encode_utf8(&mut [0; 4])).is_ok();
+            assert_eq!(is_sixbit_char(c), valid, "{c:?}");
+            assert_eq!(char_to_value(c).is_some(), valid);
+            assert_eq!(SixbitChar::try_from(c).is_ok(), valid);
+            assert_eq!(crate::encode_u64(c.encode_utf8(&mut [0; 4])).is_ok(), valid);
+        }
+        assert!(!is_sixbit_char('é') && char_to_value('\u{2020}').is_none());
+    }
+
+    #[test]
+    fn test_boundary_characters() {
+        for (c, value) in [(' ', 0), ('?', 31), ('@', 32), ('_', 63)] {
+            let sixbit = SixbitChar::try_from(c).unwrap();
+            assert_eq!(sixbit.as_value(), value);
+            assert_eq!(sixbit.as_char(), c);
+            assert_eq!(char::from(sixbit), c);
+            assert_eq!(SixbitChar::from_value(value), Ok(sixbit));
+        }
+        assert_eq!(SixbitChar::try_from(' '), Ok(SixbitChar::SPACE));
+        assert_eq!(SixbitChar::try_from('_'), Ok(SixbitChar::MAX));
+        assert_eq!(SixbitChar::default(), SixbitChar::SPACE);
+    }
+
+    #[test]
+    fn test_rejects_out_of_range() {
+        for c in ['a', '~', '\x1f', '`', 'é'] {
+            assert_eq!(SixbitChar::try_from(c), Err(Error::InvalidCharacter), "{c:?}");
+        }
+        assert_eq!(SixbitChar::from_value(64), Err(Error::InvalidCharacter));
+    }
+
+    #[test]
+    fn test_layout_and_order() {
+        assert_eq!(std::mem::size_of::<SixbitChar>(), 1);
+        assert_eq!(std::mem::size_of::<Option<SixbitChar>>(), 2);
+        let mut chars: Vec<SixbitChar> = "Z0 A_".chars().map(|c| SixbitChar::try_from(c).unwrap()).collect();
+        chars.sort();
+        assert_eq!(chars.iter().map(|c| c.as_char()).collect::<String>(), " 0AZ_");
+        assert_eq!(format!("{:?} {}", chars[2], chars[2]), "SixbitChar('A') A");
+    }
+}
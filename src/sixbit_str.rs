@@ -0,0 +1,1984 @@
+//! Provides the `DecSixbitStr` type, a borrowed view of SIXBIT-encoded data.
+//!
+//! `DecSixbitStr` is to [`DecSixbit`] what `str` is to `String`: an unsized type that is always used
+//! behind a reference, pointing at packed bytes owned elsewhere. [`DecSixbit`] dereferences to it,
+//! so the read-only API is implemented here once and shared by both types.
+//!
+//! ## Features
+//! - Zero-copy views over packed bytes in a larger buffer, such as a memory-mapped file.
+//! - Equality, ordering, and hashing consistent with `DecSixbit`.
+//! - `ToOwned`/`Borrow` integration, so `DecSixbit` keys can be looked up by `&DecSixbitStr`.
+
+use crate::{bits, decode::decode_into, display, raw, DecSixbit, Error, ParseFieldError, ASCII_OFFSET};
+use alloc::borrow::ToOwned;
+use alloc::string::String;
+use alloc::vec::Vec;
+use alloc::{format, vec};
+use core::borrow::Borrow;
+use core::cmp::Ordering;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::iter::FusedIterator;
+use core::ops::Range;
+
+/// A borrowed view of SIXBIT-encoded data.
+///
+/// The view wraps the packed bytes exactly as [`DecSixbit::as_bytes`] returns them, including the
+/// trailing-space marker byte when present. The character length is derived from the bytes, so a
+/// view can never disagree with the data it points at.
+///
+/// # Examples
+///
+/// ```rust
+/// use dec_sixbit::{DecSixbit, DecSixbitStr};
+///
+/// let owned = DecSixbit::new("DSKB").unwrap();
+/// let mut buffer = vec![0xFF];
+/// buffer.extend_from_slice(owned.as_bytes());
+///
+/// let view = DecSixbitStr::new(&buffer[1..], 4).unwrap();
+/// assert_eq!(view, &*owned);
+/// assert_eq!(view.to_string(), "DSKB");
+/// ```
+#[repr(transparent)]
+pub struct DecSixbitStr {
+    /// Packed bytes where every 3 bytes contain 4 characters (6 bits each), plus the optional marker
+    bytes: [u8],
+}
+
+impl DecSixbitStr {
+    /// SIXBIT values of the digits `0`-`9`.
+    const DIGIT_VALUES: core::ops::RangeInclusive<u8> = 16..=25;
+
+    /// SIXBIT values of the letters `A`-`Z`.
+    const LETTER_VALUES: core::ops::RangeInclusive<u8> = 33..=58;
+
+    /// Creates a view over SIXBIT-encoded bytes without copying them.
+    ///
+    /// # Parameters
+    /// - `bytes`: A slice of bytes containing SIXBIT-encoded data, as returned by [`DecSixbit::as_bytes`].
+    /// - `len`: The number of characters encoded in `bytes`.
+    ///
+    /// # Errors
+    /// - Returns an [`Error::InvalidBytesLength`] if `bytes` doesn't encode `len` characters.
+    /// - Returns an [`Error::NonzeroPadding`] if the unused bits after the last character are set.
+    /// - Returns an [`Error::UnexpectedMarker`] if the bytes end in a marker the content doesn't need.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use dec_sixbit::{encode, DecSixbitStr};
+    ///
+    /// let (bytes, len) = encode("HELLO").unwrap();
+    /// let view = DecSixbitStr::new(&bytes, len).unwrap();
+    /// assert_eq!(view.len(), 5);
+    /// ```
+    pub fn new(bytes: &[u8], len: usize) -> Result<&Self, Error> {
+        bits::check_canonical(bytes, len)?;
+        Ok(Self::from_bytes_unchecked(bytes))
+    }
+
+    /// Wraps packed bytes without checking them; the length is always inferred from `bytes`.
+    #[inline(always)]
+    pub(crate) fn from_bytes_unchecked(bytes: &[u8]) -> &Self {
+        // SAFETY: `DecSixbitStr` is `repr(transparent)` over `[u8]`
+        unsafe { &*(bytes as *const [u8] as *const Self) }
+    }
+
+    /// Returns a reference to the encoded SIXBIT bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use dec_sixbit::DecSixbit;
+    ///
+    /// let sixbit = DecSixbit::new("HELLO").unwrap();
+    /// assert_eq!(sixbit.as_sixbit_str().as_bytes(), sixbit.as_bytes());
+    /// ```
+    #[inline(always)]
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    /// Returns the number of encoded characters.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use dec_sixbit::DecSixbit;
+    ///
+    /// let sixbit = DecSixbit::new("HELLO").unwrap();
+    /// assert_eq!(sixbit.as_sixbit_str().len(), 5);
+    /// ```
+    #[inline(always)]
+    pub fn len(&self) -> usize {
+        bits::infer_len(&self.bytes)
+    }
+
+    /// Checks if the view holds no characters.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use dec_sixbit::DecSixbit;
+    ///
+    /// let sixbit = DecSixbit::new("").unwrap();
+    /// assert!(sixbit.as_sixbit_str().is_empty());
+    /// ```
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.bytes.is_empty()
+    }
+
+    /// Returns `true` if the packed bytes are in the canonical form that [`DecSixbit::new`] produces:
+    /// zeroed padding bits, and the trailing-space marker present exactly when it is needed.
+    ///
+    /// Values built by this crate are always canonical, which is what lets equality, ordering, and hashing
+    /// work on the packed bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use dec_sixbit::DecSixbit;
+    ///
+    /// assert!(DecSixbit::new("FOUR").unwrap().is_canonical());
+    /// assert!(DecSixbit::new("ABC ").unwrap().is_canonical());
+    /// ```
+    #[inline]
+    pub fn is_canonical(&self) -> bool {
+        bits::is_canonical(&self.bytes, self.len())
+    }
+
+    /// Runs [`integrity_check`](crate::integrity_check) on the packed bytes, reporting which check failed
+    /// and at what byte offset.
+    ///
+    /// Values built by this crate always pass; this is a self-check for values reconstructed from storage
+    /// without validation.
+    ///
+    /// # Errors
+    /// Returns the [`IntegrityError`](crate::IntegrityError) for the first check that fails.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use dec_sixbit::DecSixbit;
+    ///
+    /// assert_eq!(DecSixbit::new("ABC ").unwrap().verify(), Ok(()));
+    /// ```
+    #[inline]
+    pub fn verify(&self) -> Result<(), crate::IntegrityError> {
+        crate::integrity_check(&self.bytes, self.len())
+    }
+
+    /// Gets the character at the specified position.
+    ///
+    /// # Parameters
+    /// - `index`: The position of the character to retrieve.
+    ///
+    /// # Returns
+    /// An `Option<char>` which is `Some(char)` if the index is valid, or `None` otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use dec_sixbit::DecSixbit;
+    ///
+    /// let sixbit = DecSixbit::new("HELLO").unwrap();
+    /// assert_eq!(sixbit.get(1), Some('E'));
+    /// assert_eq!(sixbit.get(5), None);
+    /// ```
+    pub fn get(&self, index: usize) -> Option<char> {
+        raw::char_at(self.payload(), self.len(), index).ok()
+    }
+
+    /// Checks if the string starts with the given prefix.
+    ///
+    /// # Parameters
+    /// - `prefix`: The prefix string to check.
+    ///
+    /// # Returns
+    /// `true` if the string starts with the given prefix, otherwise `false`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use dec_sixbit::DecSixbit;
+    ///
+    /// let sixbit = DecSixbit::new("HELLO").unwrap();
+    /// assert!(sixbit.starts_with("HE"));
+    /// assert!(!sixbit.starts_with("EL"));
+    /// ```
+    pub fn starts_with<P: AsRef<str>>(&self, prefix: P) -> bool {
+        raw::starts_with_unchecked(self.payload(), self.len(), prefix.as_ref())
+    }
+
+    /// Checks if the string ends with the given suffix.
+    ///
+    /// # Parameters
+    /// - `suffix`: The suffix string to check.
+    ///
+    /// # Returns
+    /// `true` if the string ends with the given suffix, otherwise `false`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use dec_sixbit::DecSixbit;
+    ///
+    /// let sixbit = DecSixbit::new("HELLO").unwrap();
+    /// assert!(sixbit.ends_with("LO"));
+    /// assert!(!sixbit.ends_with("HE"));
+    /// ```
+    pub fn ends_with<P: AsRef<str>>(&self, suffix: P) -> bool {
+        raw::ends_with_unchecked(self.payload(), self.len(), suffix.as_ref())
+    }
+
+    /// Checks if the string starts with the given encoded prefix, comparing the packed bytes directly.
+    ///
+    /// # Parameters
+    /// - `prefix`: The prefix to check.
+    ///
+    /// # Returns
+    /// `true` if the string starts with `prefix`, otherwise `false`. An empty prefix always matches.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use dec_sixbit::DecSixbit;
+    ///
+    /// let namespace = DecSixbit::new("SYS:").unwrap();
+    /// assert!(DecSixbit::new("SYS:LOGIN").unwrap().starts_with_sixbit(&namespace));
+    /// assert!(!DecSixbit::new("DSK:LOGIN").unwrap().starts_with_sixbit(&namespace));
+    /// ```
+    pub fn starts_with_sixbit(&self, prefix: &DecSixbitStr) -> bool {
+        prefix.len() <= self.len() && bits::eq_at(self.payload(), 0, prefix.payload(), prefix.len())
+    }
+
+    /// Checks if the string ends with the given encoded suffix, comparing the packed bytes directly.
+    ///
+    /// # Parameters
+    /// - `suffix`: The suffix to check.
+    ///
+    /// # Returns
+    /// `true` if the string ends with `suffix`, otherwise `false`. An empty suffix always matches.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use dec_sixbit::DecSixbit;
+    ///
+    /// let extension = DecSixbit::new(".SAV").unwrap();
+    /// assert!(DecSixbit::new("PIP.SAV").unwrap().ends_with_sixbit(&extension));
+    /// assert!(!DecSixbit::new("PIP.REL").unwrap().ends_with_sixbit(&extension));
+    /// ```
+    pub fn ends_with_sixbit(&self, suffix: &DecSixbitStr) -> bool {
+        match self.len().checked_sub(suffix.len()) {
+            Some(start) => bits::eq_at(self.payload(), start, suffix.payload(), suffix.len()),
+            None => false,
+        }
+    }
+
+    /// Checks if the string contains the given substring.
+    ///
+    /// # Parameters
+    /// - `substring`: The substring to search for.
+    ///
+    /// # Returns
+    /// `true` if the string contains the given substring, otherwise `false`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use dec_sixbit::DecSixbit;
+    ///
+    /// let sixbit = DecSixbit::new("HELLO").unwrap();
+    /// assert!(sixbit.contains("ELL"));
+    /// assert!(!sixbit.contains("XYZ"));
+    /// ```
+    pub fn contains<P: AsRef<str>>(&self, substring: P) -> bool {
+        raw::contains_unchecked(self.payload(), self.len(), substring.as_ref())
+    }
+
+    /// Returns an iterator over the characters, decoding them lazily from the packed bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use dec_sixbit::DecSixbit;
+    ///
+    /// let sixbit = DecSixbit::new("HELLO").unwrap();
+    /// assert!(sixbit.chars().eq("HELLO".chars()));
+    /// ```
+    #[inline(always)]
+    pub fn chars(&self) -> Chars<'_> {
+        Chars {
+            bytes: &self.bytes,
+            front: 0,
+            back: self.len(),
+        }
+    }
+
+    /// Returns an iterator over the characters and their positions.
+    ///
+    /// Every SIXBIT character occupies exactly one position, so the index is the character index,
+    /// which also equals the byte index within the decoded string.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use dec_sixbit::DecSixbit;
+    ///
+    /// let sixbit = DecSixbit::new("HEY").unwrap();
+    /// let pairs: Vec<(usize, char)> = sixbit.char_indices().collect();
+    /// assert_eq!(pairs, [(0, 'H'), (1, 'E'), (2, 'Y')]);
+    /// ```
+    #[inline(always)]
+    pub fn char_indices(&self) -> CharIndices<'_> {
+        CharIndices { chars: self.chars() }
+    }
+
+    /// Returns an iterator over the raw 6-bit values of the characters, `0..=63`, without the ASCII offset.
+    ///
+    /// [`DecSixbit::from_sixbit_values`] is the inverse.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use dec_sixbit::DecSixbit;
+    ///
+    /// let sixbit = DecSixbit::new("A 0").unwrap();
+    /// assert!(sixbit.sixbit_values().eq([33, 0, 16]));
+    /// ```
+    #[inline]
+    pub fn sixbit_values(&self) -> impl DoubleEndedIterator<Item = u8> + ExactSizeIterator + '_ {
+        (0..self.len()).map(|index| bits::value_at(&self.bytes, index))
+    }
+
+    /// Returns an iterator over the characters as [`SixbitChar`](crate::SixbitChar) values.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use dec_sixbit::{DecSixbit, SixbitChar};
+    ///
+    /// let sixbit = DecSixbit::new("A B").unwrap();
+    /// assert_eq!(sixbit.sixbit_chars().nth(1), Some(SixbitChar::SPACE));
+    /// let copy: DecSixbit = sixbit.sixbit_chars().collect();
+    /// assert_eq!(copy, sixbit);
+    /// ```
+    #[inline]
+    pub fn sixbit_chars(&self) -> impl DoubleEndedIterator<Item = crate::SixbitChar> + ExactSizeIterator + '_ {
+        self.sixbit_values().map(crate::SixbitChar::from_value_unchecked)
+    }
+
+    /// Checks if every character is a space.
+    ///
+    /// The packed bytes are scanned directly: whole groups must be zero bytes, and the fields of
+    /// a partial final group must be zero. The trailing-space marker byte is not part of the content.
+    ///
+    /// # Returns
+    /// `true` if the value is empty or consists only of spaces, otherwise `false`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use dec_sixbit::DecSixbit;
+    ///
+    /// assert!(DecSixbit::new("    ").unwrap().is_blank());
+    /// assert!(!DecSixbit::new("  X ").unwrap().is_blank());
+    /// ```
+    pub fn is_blank(&self) -> bool {
+        let len = self.len();
+        let full_chunks = len / 4;
+        self.bytes[..full_chunks * 3].iter().all(|&b| b == 0)
+            && (full_chunks * 4..len).all(|i| bits::value_at(&self.bytes, i) == 0)
+    }
+
+    /// Checks if every character is an ASCII digit (`0`-`9`).
+    ///
+    /// # Returns
+    /// `true` if all characters are digits or the value is empty, matching `str::chars().all(..)`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use dec_sixbit::DecSixbit;
+    ///
+    /// assert!(DecSixbit::new("0123").unwrap().is_numeric());
+    /// assert!(!DecSixbit::new("12 3").unwrap().is_numeric());
+    /// ```
+    pub fn is_numeric(&self) -> bool {
+        self.all_values(|v| Self::DIGIT_VALUES.contains(&v))
+    }
+
+    /// Checks if every character is an uppercase ASCII letter (`A`-`Z`).
+    ///
+    /// # Returns
+    /// `true` if all characters are letters or the value is empty, matching `str::chars().all(..)`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use dec_sixbit::DecSixbit;
+    ///
+    /// assert!(DecSixbit::new("ABC").unwrap().is_alphabetic());
+    /// assert!(!DecSixbit::new("AB1").unwrap().is_alphabetic());
+    /// ```
+    pub fn is_alphabetic(&self) -> bool {
+        self.all_values(|v| Self::LETTER_VALUES.contains(&v))
+    }
+
+    /// Checks if every character is an uppercase ASCII letter or a digit.
+    ///
+    /// # Returns
+    /// `true` if all characters are letters or digits, or the value is empty, matching `str::chars().all(..)`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use dec_sixbit::DecSixbit;
+    ///
+    /// assert!(DecSixbit::new("RSX11M").unwrap().is_alphanumeric());
+    /// assert!(!DecSixbit::new("RSX-11M").unwrap().is_alphanumeric());
+    /// ```
+    pub fn is_alphanumeric(&self) -> bool {
+        self.all_values(|v| Self::DIGIT_VALUES.contains(&v) || Self::LETTER_VALUES.contains(&v))
+    }
+
+    /// Returns `true` if `f` holds for the SIXBIT value of every character, without decoding.
+    #[inline(always)]
+    fn all_values<F: FnMut(u8) -> bool>(&self, f: F) -> bool {
+        bits::all_values(&self.bytes, self.len(), f)
+    }
+
+    /// Parses the content of a space-padded field into another type.
+    ///
+    /// Leading and trailing spaces are trimmed before the remainder is handed to [`FromStr`](std::str::FromStr).
+    /// Fields of up to 64 characters are decoded into a stack buffer, so the common case of numeric
+    /// fields does not allocate.
+    ///
+    /// # Errors
+    /// - Returns [`ParseFieldError::Blank`] if the field is empty or contains only spaces.
+    /// - Returns [`ParseFieldError::Invalid`] wrapping the parse error of `T` otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use dec_sixbit::DecSixbit;
+    ///
+    /// let field = DecSixbit::new("   42 ").unwrap();
+    /// assert_eq!(field.parse::<u32>(), Ok(42));
+    /// ```
+    pub fn parse<T: core::str::FromStr>(&self) -> Result<T, ParseFieldError<T::Err>> {
+        const STACK_BUFFER_LEN: usize = 64;
+
+        let len = self.len();
+        // SIXBIT value 0 is a space
+        let Some(start) = (0..len).find(|&i| bits::value_at(&self.bytes, i) != 0) else {
+            return Err(ParseFieldError::Blank);
+        };
+        // A non-space character exists, so searching backwards always succeeds
+        let end = (start..len).rev().find(|&i| bits::value_at(&self.bytes, i) != 0).unwrap() + 1;
+
+        let parse = |buf: &[u8]| {
+            // SAFETY: Every decoded SIXBIT character is printable ASCII
+            let s = unsafe { core::str::from_utf8_unchecked(buf) };
+            s.parse::<T>().map_err(ParseFieldError::Invalid)
+        };
+        if end - start <= STACK_BUFFER_LEN {
+            let mut buf = [0u8; STACK_BUFFER_LEN];
+            for (slot, i) in buf.iter_mut().zip(start..end) {
+                *slot = bits::value_at(&self.bytes, i) + ASCII_OFFSET;
+            }
+            parse(&buf[..end - start])
+        } else {
+            let buf: Vec<u8> = (start..end).map(|i| bits::value_at(&self.bytes, i) + ASCII_OFFSET).collect();
+            parse(&buf)
+        }
+    }
+
+    /// Returns an adapter that displays the value with trailing spaces removed, without allocating.
+    ///
+    /// DEC software conventionally pads fixed-width fields with spaces; this renders just the content.
+    /// Leading and interior spaces are kept, and width, fill, alignment, and precision apply to the
+    /// trimmed text.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use dec_sixbit::DecSixbit;
+    ///
+    /// let device = DecSixbit::new("DSKB  ").unwrap();
+    /// assert_eq!(device.display_trimmed().to_string(), "DSKB");
+    /// assert_eq!(format!("[{:>6}]", device.display_trimmed()), "[  DSKB]");
+    /// ```
+    pub fn display_trimmed(&self) -> impl fmt::Display + '_ {
+        display::Trimmed(self)
+    }
+
+    /// Decodes the characters into a `String` with the letters `A`-`Z` mapped to lowercase.
+    ///
+    /// Digits, punctuation, and spaces are unchanged, and the string is allocated once at its final size.
+    /// See [`decode_lowercase`](crate::decode_lowercase) for the raw API.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use dec_sixbit::DecSixbit;
+    ///
+    /// let sixbit = DecSixbit::new("DSKB:[1,2]").unwrap();
+    /// assert_eq!(sixbit.to_lowercase_string(), "dskb:[1,2]");
+    /// ```
+    pub fn to_lowercase_string(&self) -> String {
+        crate::decode::decode_lowercase_core(self.payload(), self.len())
+    }
+
+    /// Decodes the characters into a `String` without the trailing spaces.
+    ///
+    /// Only the characters before the padding are decoded, so the string is allocated once at its final
+    /// size. Leading and interior spaces are kept. See [`decode_trimmed`](crate::decode_trimmed) for the raw API.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use dec_sixbit::DecSixbit;
+    ///
+    /// let field = DecSixbit::new(" PIP SAV    ").unwrap();
+    /// assert_eq!(field.to_trimmed_string(), " PIP SAV");
+    /// ```
+    pub fn to_trimmed_string(&self) -> String {
+        crate::decode::decode_unchecked(&self.bytes, self.trimmed_len())
+    }
+
+    /// Returns the characters in `range` with leading and trailing spaces removed.
+    ///
+    /// The trimmed bounds are found first, so the characters are copied only once.
+    ///
+    /// # Parameters
+    /// - `range`: The character positions of the field.
+    ///
+    /// # Returns
+    /// `None` if `range` is not within the value.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use dec_sixbit::DecSixbit;
+    ///
+    /// let record = DecSixbit::new("BOLT  EA   12").unwrap();
+    /// assert_eq!(record.field(0..6).unwrap().to_string(), "BOLT");
+    /// assert_eq!(record.field(6..9).unwrap().to_string(), "EA");
+    /// assert_eq!(record.field(9..14), None);
+    /// ```
+    pub fn field(&self, range: Range<usize>) -> Option<DecSixbit> {
+        let range = self.trimmed_range(range)?;
+        Some(self.slice_unchecked(range.start, range.end))
+    }
+
+    /// Returns the characters in `range` with leading and trailing spaces removed, decoded to a `String`.
+    ///
+    /// # Parameters
+    /// - `range`: The character positions of the field.
+    ///
+    /// # Returns
+    /// `None` if `range` is not within the value.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use dec_sixbit::DecSixbit;
+    ///
+    /// let record = DecSixbit::new("BOLT  EA   12").unwrap();
+    /// assert_eq!(record.field_str(9..13).as_deref(), Some("12"));
+    /// ```
+    pub fn field_str(&self, range: Range<usize>) -> Option<String> {
+        let range = self.trimmed_range(range)?;
+        Some(range.map(|i| (bits::value_at(&self.bytes, i) + ASCII_OFFSET) as char).collect())
+    }
+
+    /// Narrows `range` to exclude leading and trailing spaces, or returns `None` if it is out of bounds.
+    fn trimmed_range(&self, range: Range<usize>) -> Option<Range<usize>> {
+        if range.start > range.end || range.end > self.len() {
+            return None;
+        }
+        // SIXBIT value 0 is a space
+        let is_space = |&i: &usize| bits::value_at(&self.bytes, i) == 0;
+        let start = range.clone().find(|i| !is_space(i)).unwrap_or(range.end);
+        let end = (start..range.end).rev().find(|i| !is_space(i)).map_or(start, |i| i + 1);
+        Some(start..end)
+    }
+
+    /// Returns the number of characters before any trailing spaces.
+    pub(crate) fn trimmed_len(&self) -> usize {
+        bits::trimmed_len(&self.bytes, self.len())
+    }
+
+    /// Renders the packed bit layout as a multi-line annotated view for debugging.
+    ///
+    /// The first line shows the payload bytes in binary with `|` between bytes, the second the same bits
+    /// with `|` between 6-bit character fields and unused padding bits as `.`, and the third each decoded
+    /// character under its field. A fourth line is added when the trailing-space marker byte is present.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use dec_sixbit::DecSixbit;
+    ///
+    /// let sixbit = DecSixbit::new("ABCD").unwrap();
+    /// assert_eq!(
+    ///     sixbit.dump(),
+    ///     "bytes:  100001 10|0010 1000|11 100100\n\
+    ///      fields: 100001|10 0010|1000 11|100100\n\
+    ///      chars:    A       B      C       D"
+    /// );
+    /// ```
+    pub fn dump(&self) -> String {
+        let payload = self.payload();
+        let field_bits = self.len() * 6;
+        let mut bytes_line = String::from("bytes:  ");
+        let mut fields_line = String::from("fields: ");
+        let mut chars_line = String::from("chars:  ");
+        for bit_idx in 0..payload.len() * 8 {
+            let byte_boundary = bit_idx % 8 == 0;
+            let field_boundary = bit_idx % 6 == 0 && bit_idx <= field_bits;
+            if bit_idx > 0 && (byte_boundary || field_boundary) {
+                bytes_line.push(if byte_boundary { '|' } else { ' ' });
+                fields_line.push(if field_boundary { '|' } else { ' ' });
+                chars_line.push(' ');
+            }
+            let bit = char::from(b'0' + ((payload[bit_idx / 8] >> (7 - bit_idx % 8)) & 1));
+            bytes_line.push(bit);
+            if bit_idx < field_bits {
+                fields_line.push(bit);
+                chars_line.push(if bit_idx % 6 == 2 {
+                    char::from(bits::value_at(&self.bytes, bit_idx / 6) + ASCII_OFFSET)
+                } else {
+                    ' '
+                });
+            } else {
+                fields_line.push('.');
+            }
+        }
+        let mut dump = format!("{}\n{}\n{}", bytes_line, fields_line, chars_line.trim_end());
+        if self.bytes.len() > payload.len() {
+            dump.push_str(&format!("\nmarker: {:08b} (trailing-space marker)", self.bytes[payload.len()]));
+        }
+        dump
+    }
+
+    /// Renders the value as 36-bit words of 12-digit octal numbers, the form used by TOPS-10
+    /// documentation and crash dumps.
+    ///
+    /// Each word holds 6 characters, the first in the most significant bits, and the final word is
+    /// padded with spaces. [`DecSixbit::from_octal_words`] is the inverse.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use dec_sixbit::DecSixbit;
+    ///
+    /// let sixbit = DecSixbit::new("DSK").unwrap();
+    /// assert_eq!(sixbit.to_octal_words(), ["446353000000"]);
+    /// ```
+    pub fn to_octal_words(&self) -> Vec<String> {
+        let len = self.len();
+        (0..len.div_ceil(6))
+            .map(|word_idx| {
+                let word = (word_idx * 6..word_idx * 6 + 6)
+                    .map(|i| if i < len { bits::value_at(&self.bytes, i) } else { 0 })
+                    .fold(0u64, |acc, value| (acc << 6) | value as u64);
+                format!("{:012o}", word)
+            })
+            .collect()
+    }
+
+    /// Splits the packed bytes into blocks of `block_size` bytes, padding the last block with spaces.
+    ///
+    /// Blocks start on group boundaries, so each one decodes on its own as `block_size / 3 * 4`
+    /// characters. The trailing-space marker is never included, so keep the character count to pass to
+    /// [`DecSixbit::from_blocks`].
+    ///
+    /// # Parameters
+    /// - `block_size`: The number of bytes in each block.
+    ///
+    /// # Errors
+    /// Returns an [`Error::InvalidBlockSize`] if `block_size` is zero or not a multiple of 3.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use dec_sixbit::DecSixbit;
+    ///
+    /// let sixbit = DecSixbit::new("HELLO WORLD").unwrap();
+    /// let blocks = sixbit.to_blocks(6).unwrap();
+    /// assert_eq!(blocks.len(), 2);
+    /// assert_eq!(DecSixbit::from_blocks(&blocks, sixbit.len()).unwrap(), sixbit);
+    /// ```
+    pub fn to_blocks(&self, block_size: usize) -> Result<Vec<Vec<u8>>, Error> {
+        if block_size == 0 || block_size % 3 != 0 {
+            return Err(Error::InvalidBlockSize);
+        }
+        Ok(self
+            .payload()
+            .chunks(block_size)
+            .map(|chunk| {
+                // Space is the zero value, so the padding is zero bytes
+                let mut block = chunk.to_vec();
+                block.resize(block_size, 0);
+                block
+            })
+            .collect())
+    }
+
+    /// Returns the value in a self-describing frame: a version byte, the character count, and the packed
+    /// bytes.
+    ///
+    /// The version byte is [`DecSixbit::FRAME_VERSION`] and the count is an unsigned LEB128 varint. The
+    /// packed bytes follow without the trailing-space marker, since the count already gives the length.
+    /// [`DecSixbit::from_framed_bytes`] is the inverse.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use dec_sixbit::DecSixbit;
+    ///
+    /// let sixbit = DecSixbit::new("ABC ").unwrap();
+    /// assert_eq!(sixbit.to_framed_bytes(), [DecSixbit::FRAME_VERSION, 4, 0x86, 0x28, 0xC0]);
+    /// assert_eq!(DecSixbit::from_framed_bytes(&sixbit.to_framed_bytes()), Ok((sixbit, 5)));
+    /// ```
+    pub fn to_framed_bytes(&self) -> Vec<u8> {
+        let mut out = Vec::new();
+        self.write_framed_bytes(&mut out);
+        out
+    }
+
+    /// Appends the frame returned by [`to_framed_bytes`](Self::to_framed_bytes) to `out`.
+    ///
+    /// # Parameters
+    /// - `out`: The buffer to append the frame to.
+    pub fn write_framed_bytes(&self, out: &mut Vec<u8>) {
+        let payload = self.payload();
+        out.reserve(1 + 10 + payload.len());
+        out.push(DecSixbit::FRAME_VERSION);
+        let mut len = self.len();
+        while len >= 0x80 {
+            out.push(len as u8 | 0x80);
+            len >>= 7;
+        }
+        out.push(len as u8);
+        out.extend_from_slice(payload);
+    }
+
+    /// Returns the packed payload bytes, marker excluded, as lowercase hex.
+    ///
+    /// This is the same text as the [`LowerHex`](fmt::LowerHex) formatting without the `#` flag, and
+    /// [`DecSixbit::from_hex`] is the inverse.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use dec_sixbit::DecSixbit;
+    ///
+    /// let sixbit = DecSixbit::new("ABC ").unwrap();
+    /// assert_eq!(sixbit.to_hex(), "8628c0");
+    /// assert_eq!(DecSixbit::from_hex(&sixbit.to_hex(), 4), Ok(sixbit));
+    /// ```
+    pub fn to_hex(&self) -> String {
+        format!("{self:x}")
+    }
+
+    /// Returns the number of 3-byte groups the characters are packed into, one per 4 characters.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use dec_sixbit::DecSixbit;
+    ///
+    /// assert_eq!(DecSixbit::new("ABCDEFGHI").unwrap().group_count(), 3);
+    /// assert_eq!(DecSixbit::new("").unwrap().group_count(), 0);
+    /// ```
+    #[inline]
+    pub fn group_count(&self) -> usize {
+        self.len().div_ceil(4)
+    }
+
+    /// Returns an iterator over the packed 3-byte groups, each with the number of its 4 character slots
+    /// in use.
+    ///
+    /// Every group but the last holds 4 characters. The bytes of a short last group past the payload
+    /// are zero, and the trailing-space marker is never included.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use dec_sixbit::DecSixbit;
+    ///
+    /// let sixbit = DecSixbit::new("ABCDEFGHI").unwrap();
+    /// let counts: Vec<usize> = sixbit.groups().map(|(_, count)| count).collect();
+    /// assert_eq!(counts, [4, 4, 1]);
+    /// ```
+    pub fn groups(&self) -> impl DoubleEndedIterator<Item = ([u8; 3], usize)> + ExactSizeIterator + '_ {
+        let len = self.len();
+        self.payload().chunks(3).enumerate().map(move |(index, chunk)| {
+            let mut group = [0; 3];
+            group[..chunk.len()].copy_from_slice(chunk);
+            (group, (len - index * 4).min(4))
+        })
+    }
+
+    /// Packs the value into a `u64` if it has at most 10 characters, in the layout of [`encode_u64`](crate::encode_u64).
+    ///
+    /// # Returns
+    /// `None` if the value is longer than 10 characters.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use dec_sixbit::{encode_u64, DecSixbit};
+    ///
+    /// let sixbit = DecSixbit::new("SYSTAT").unwrap();
+    /// assert_eq!(sixbit.to_u64(), Some(encode_u64("SYSTAT").unwrap()));
+    /// assert_eq!(DecSixbit::new("ELEVEN CHAR").unwrap().to_u64(), None);
+    /// ```
+    pub fn to_u64(&self) -> Option<u64> {
+        let len = self.len();
+        if len > crate::U64_CHARS {
+            return None;
+        }
+        Some((0..len).fold(0, |packed, i| packed | (bits::value_at(&self.bytes, i) as u64) << (58 - 6 * i)))
+    }
+
+    /// Packs the value into a `u128` if it has at most 21 characters, in the layout of
+    /// [`encode_u128`](crate::encode_u128).
+    ///
+    /// # Returns
+    /// `None` if the value is longer than 21 characters.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use dec_sixbit::{encode_u128, DecSixbit};
+    ///
+    /// let sixbit = DecSixbit::new("DSKB:README.TXT").unwrap();
+    /// assert_eq!(sixbit.to_u128(), Some(encode_u128("DSKB:README.TXT").unwrap()));
+    /// ```
+    pub fn to_u128(&self) -> Option<u128> {
+        let len = self.len();
+        if len > crate::U128_CHARS {
+            return None;
+        }
+        Some((0..len).fold(0, |packed, i| packed | (bits::value_at(&self.bytes, i) as u128) << (122 - 6 * i)))
+    }
+
+    /// Returns a key whose bytewise order matches the order of the decoded strings, for key-value stores
+    /// such as RocksDB or LMDB that compare keys with `memcmp`.
+    ///
+    /// Each character is written as its SIXBIT value plus one in a 7-bit field, most significant bit first,
+    /// followed by a 7-bit terminator of zero and zero padding to a whole byte. Characters never encode to
+    /// zero, so a value that is a prefix of another always has the smaller key, even when the rest of the
+    /// longer value is spaces. A key of `n` characters takes `(n + 1) * 7 / 8` bytes, rounded up.
+    /// [`DecSixbit::from_sort_key`] is the inverse.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use dec_sixbit::DecSixbit;
+    ///
+    /// let ab = DecSixbit::new("AB").unwrap();
+    /// let ab_space = DecSixbit::new("AB ").unwrap();
+    /// assert!(ab.to_sort_key() < ab_space.to_sort_key());
+    /// assert_eq!(DecSixbit::from_sort_key(&ab.to_sort_key()), Ok(ab));
+    /// ```
+    pub fn to_sort_key(&self) -> Vec<u8> {
+        let mut key = Vec::new();
+        self.write_sort_key(&mut key);
+        key
+    }
+
+    /// Appends the key returned by [`to_sort_key`](Self::to_sort_key) to `out`, e.g. after a key prefix.
+    ///
+    /// # Parameters
+    /// - `out`: The buffer to append the key to.
+    pub fn write_sort_key(&self, out: &mut Vec<u8>) {
+        let len = self.len();
+        out.reserve(((len + 1) * 7).div_ceil(8));
+        let mut acc = 0u16;
+        let mut pending = 0;
+        let fields = (0..len).map(|i| bits::value_at(&self.bytes, i) + 1).chain(core::iter::once(0));
+        for field in fields {
+            acc = (acc << 7) | field as u16;
+            pending += 7;
+            if pending >= 8 {
+                pending -= 8;
+                out.push((acc >> pending) as u8);
+            }
+        }
+        if pending > 0 {
+            out.push((acc << (8 - pending)) as u8);
+        }
+    }
+
+    /// Counts the occurrences of a character without decoding the value.
+    ///
+    /// # Parameters
+    /// - `c`: The character to count.
+    ///
+    /// # Returns
+    /// The number of occurrences of `c`, or `0` if `c` is not a valid SIXBIT character.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use dec_sixbit::DecSixbit;
+    ///
+    /// let sixbit = DecSixbit::new("HELLO WORLD").unwrap();
+    /// assert_eq!(sixbit.count_char('L'), 3);
+    /// assert_eq!(sixbit.count_char('l'), 0);
+    /// ```
+    pub fn count_char(&self, c: char) -> usize {
+        let Ok(target) = bits::char_value(c) else {
+            return 0;
+        };
+        let mut count = 0;
+        bits::for_each_value(&self.bytes, self.len(), |v| count += (v == target) as usize);
+        count
+    }
+
+    /// Counts the occurrences of every SIXBIT character without decoding the value.
+    ///
+    /// # Returns
+    /// An array indexed by SIXBIT value, where the count for character `c` is found at
+    /// index `c as usize - 32`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use dec_sixbit::DecSixbit;
+    ///
+    /// let sixbit = DecSixbit::new("A,B,C").unwrap();
+    /// let frequencies = sixbit.char_frequencies();
+    /// assert_eq!(frequencies[b',' as usize - 32], 2);
+    /// assert_eq!(frequencies[b'A' as usize - 32], 1);
+    /// ```
+    pub fn char_frequencies(&self) -> [usize; 64] {
+        let mut frequencies = [0usize; 64];
+        bits::for_each_value(&self.bytes, self.len(), |v| frequencies[v as usize] += 1);
+        frequencies
+    }
+
+    /// Returns the number of leading characters shared with another value.
+    ///
+    /// Whole groups are compared three bytes at a time, since equal bytes prove four equal characters,
+    /// and only the first differing group is resolved character by character. Nothing is decoded or allocated.
+    ///
+    /// # Parameters
+    /// - `other`: The value to compare against.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use dec_sixbit::DecSixbit;
+    ///
+    /// let a = DecSixbit::new("DSKB:FOO").unwrap();
+    /// let b = DecSixbit::new("DSKC:FOO").unwrap();
+    /// assert_eq!(a.common_prefix_len(&b), 3);
+    /// ```
+    pub fn common_prefix_len(&self, other: &DecSixbitStr) -> usize {
+        let min_len = self.len().min(other.len());
+        let full_chunks = min_len / 4;
+        let equal_chunks = self.bytes[..full_chunks * 3]
+            .chunks_exact(3)
+            .zip(other.bytes[..full_chunks * 3].chunks_exact(3))
+            .take_while(|(a, b)| a == b)
+            .count();
+        (equal_chunks * 4..min_len)
+            .find(|&i| bits::value_at(&self.bytes, i) != bits::value_at(&other.bytes, i))
+            .unwrap_or(min_len)
+    }
+
+    /// Finds the first position where two values differ.
+    ///
+    /// A length difference counts as a difference at index `min(self.len(), other.len())`,
+    /// where the shorter side reports `None` for its character.
+    ///
+    /// # Parameters
+    /// - `other`: The value to compare against.
+    ///
+    /// # Returns
+    /// `Some((index, self_char, other_char))` for the first difference, or `None` if the values are equal.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use dec_sixbit::DecSixbit;
+    ///
+    /// let a = DecSixbit::new("HELLO").unwrap();
+    /// let b = DecSixbit::new("HELP").unwrap();
+    /// assert_eq!(a.first_difference(&b), Some((3, Some('L'), Some('P'))));
+    /// assert_eq!(a.first_difference(&a), None);
+    /// ```
+    pub fn first_difference(&self, other: &DecSixbitStr) -> Option<(usize, Option<char>, Option<char>)> {
+        let index = self.common_prefix_len(other);
+        if index == self.len() && index == other.len() {
+            return None;
+        }
+        let char_at = |sixbit: &DecSixbitStr| {
+            (index < sixbit.len()).then(|| char::from(bits::value_at(&sixbit.bytes, index) + ASCII_OFFSET))
+        };
+        Some((index, char_at(self), char_at(other)))
+    }
+
+    /// Compares the decoded characters against a string, without decoding or allocating.
+    ///
+    /// Characters are compared by their `char` values, so the result is consistent with `Ord` for
+    /// `DecSixbit` and with `str` ordering. This makes `binary_search_by(|x| x.cmp_with_str(probe))`
+    /// correct on a sorted slice of values. Probes containing characters outside the SIXBIT range
+    /// still compare by their raw `char` values.
+    ///
+    /// # Parameters
+    /// - `probe`: The string to compare against.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use dec_sixbit::DecSixbit;
+    ///
+    /// let names: Vec<DecSixbit> = ["DSKA", "DSKB", "MTA0"].iter().map(|s| DecSixbit::new(s).unwrap()).collect();
+    /// assert_eq!(names.binary_search_by(|x| x.cmp_with_str("DSKB")), Ok(1));
+    /// assert_eq!(names.binary_search_by(|x| x.cmp_with_str("LPT")), Err(2));
+    /// ```
+    pub fn cmp_with_str(&self, probe: &str) -> Ordering {
+        self.chars().cmp(probe.chars())
+    }
+
+    /// Feeds the decoded characters to `state` exactly as `str`'s `Hash` implementation does.
+    ///
+    /// This is what `Hash` uses, and it is guaranteed to stay so: a value and its decoded `&str` hash
+    /// equally under any hasher that doesn't override the unstable `Hasher::write_str`, which includes
+    /// the standard library's `DefaultHasher`. A map keyed by `DecSixbit` can therefore be probed with a
+    /// `&str` through a raw-entry API, hashing the probe as a `str` and comparing it with `PartialEq<str>`,
+    /// without building a `DecSixbit` per lookup.
+    ///
+    /// The characters are decoded into a stack buffer, or a heap buffer for values of more than 64
+    /// characters, and written in a single call followed by the `0xFF` terminator.
+    ///
+    /// # Parameters
+    /// - `state`: The hasher to write to.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use dec_sixbit::DecSixbit;
+    /// use std::hash::{BuildHasher, RandomState};
+    ///
+    /// let state = RandomState::new();
+    /// let sixbit = DecSixbit::new("DSKB").unwrap();
+    /// assert_eq!(state.hash_one(&sixbit), state.hash_one("DSKB"));
+    /// ```
+    ///
+    /// Looking up a key by `&str` with [hashbrown](https://docs.rs/hashbrown)'s raw-entry API:
+    ///
+    /// ```rust
+    /// use dec_sixbit::DecSixbit;
+    /// use hashbrown::HashMap;
+    /// use std::hash::BuildHasher;
+    ///
+    /// let map: HashMap<DecSixbit, u32> = [(DecSixbit::new("DSKB").unwrap(), 2)].into_iter().collect();
+    /// let probe = "DSKB";
+    /// let hash = map.hasher().hash_one(probe);
+    /// assert_eq!(map.raw_entry().from_hash(hash, |key| key == probe), Some((&DecSixbit::new("DSKB").unwrap(), &2)));
+    /// ```
+    pub fn hash_as_str<H: Hasher>(&self, state: &mut H) {
+        const STACK_BUFFER_LEN: usize = 64;
+
+        let len = self.len();
+        // Hashers may not be streaming, so the characters must go in one `write` call like `str`'s
+        if len <= STACK_BUFFER_LEN {
+            let mut buf = [0u8; STACK_BUFFER_LEN];
+            decode_into(&self.bytes, len, &mut buf);
+            state.write(&buf[..len]);
+        } else {
+            let mut buf = vec![0u8; len];
+            decode_into(&self.bytes, len, &mut buf);
+            state.write(&buf);
+        }
+        state.write_u8(0xFF);
+    }
+
+    /// Returns a 64-bit fingerprint of the characters that is stable across runs, platforms, and versions.
+    ///
+    /// Unlike `Hash`, whose output depends on the hasher, the fingerprint is suitable for on-disk indexes.
+    /// It is the 64-bit FNV-1a hash of the decoded ASCII characters, so it equals the FNV-1a hash of the
+    /// equivalent `&str` and is unaffected by the trailing-space marker. The algorithm will not change
+    /// without a major version bump.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use dec_sixbit::DecSixbit;
+    ///
+    /// assert_eq!(DecSixbit::new("").unwrap().fingerprint(), 0xcbf2_9ce4_8422_2325);
+    /// assert_eq!(DecSixbit::new("HELLO").unwrap().fingerprint(), 0xa0b4_00b9_8ea8_182b);
+    /// ```
+    pub fn fingerprint(&self) -> u64 {
+        const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+        const PRIME: u64 = 0x0000_0100_0000_01b3;
+        self.chars().fold(OFFSET_BASIS, |hash, c| (hash ^ c as u64).wrapping_mul(PRIME))
+    }
+
+    /// Returns the packed bytes that hold characters, excluding the trailing-space marker.
+    #[inline(always)]
+    pub(crate) fn payload(&self) -> &[u8] {
+        &self.bytes[..bits::bytes_for_chars(self.len())]
+    }
+
+    /// Returns an iterator over all contiguous windows of `size` characters.
+    ///
+    /// The windows overlap and are yielded in order, exactly like [`slice::windows`].
+    /// If the value is shorter than `size`, the iterator yields nothing.
+    ///
+    /// # Parameters
+    /// - `size`: The number of characters in each window.
+    ///
+    /// # Panics
+    /// Panics if `size` is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use dec_sixbit::DecSixbit;
+    ///
+    /// let sixbit = DecSixbit::new("HELLO").unwrap();
+    /// let windows: Vec<String> = sixbit.windows(3).map(|w| w.to_string()).collect();
+    /// assert_eq!(windows, ["HEL", "ELL", "LLO"]);
+    /// ```
+    pub fn windows(&self, size: usize) -> Windows<'_> {
+        assert!(size != 0, "window size must be non-zero");
+        let count = (self.len() + 1).saturating_sub(size);
+        Windows {
+            sixbit: self,
+            size,
+            front: 0,
+            back: count,
+        }
+    }
+
+    /// Copies the characters in `start..end` into a new value, re-aligning the 6-bit fields
+    /// to the start of the packed bytes.
+    ///
+    /// The caller must ensure that `start <= end <= self.len()`.
+    pub(crate) fn slice_unchecked(&self, start: usize, end: usize) -> DecSixbit {
+        let len = end - start;
+        let mut bytes = vec![0u8; bits::bytes_for_chars(len)];
+        for i in 0..len {
+            bits::set_value(&mut bytes, i, bits::value_at(&self.bytes, start + i));
+        }
+        DecSixbit::from_encoded(bytes, len)
+    }
+}
+
+/// An iterator over the characters of a [`DecSixbitStr`].
+///
+/// This struct is created by [`DecSixbitStr::chars`].
+#[derive(Debug, Clone)]
+pub struct Chars<'a> {
+    bytes: &'a [u8],
+    /// Index of the next character yielded from the front
+    front: usize,
+    /// One past the index of the next character yielded from the back
+    back: usize,
+}
+
+impl Iterator for Chars<'_> {
+    type Item = char;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.front >= self.back {
+            return None;
+        }
+        let value = bits::value_at(self.bytes, self.front);
+        self.front += 1;
+        Some(char::from(value + ASCII_OFFSET))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.back - self.front;
+        (remaining, Some(remaining))
+    }
+}
+
+impl DoubleEndedIterator for Chars<'_> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.front >= self.back {
+            return None;
+        }
+        self.back -= 1;
+        Some(char::from(bits::value_at(self.bytes, self.back) + ASCII_OFFSET))
+    }
+}
+
+impl ExactSizeIterator for Chars<'_> {}
+
+impl FusedIterator for Chars<'_> {}
+
+/// An iterator over the characters of a [`DecSixbitStr`] and their positions.
+///
+/// This struct is created by [`DecSixbitStr::char_indices`].
+#[derive(Debug, Clone)]
+pub struct CharIndices<'a> {
+    chars: Chars<'a>,
+}
+
+impl Iterator for CharIndices<'_> {
+    type Item = (usize, char);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let index = self.chars.front;
+        self.chars.next().map(|c| (index, c))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.chars.size_hint()
+    }
+}
+
+impl DoubleEndedIterator for CharIndices<'_> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.chars.next_back().map(|c| (self.chars.back, c))
+    }
+}
+
+impl ExactSizeIterator for CharIndices<'_> {}
+
+impl FusedIterator for CharIndices<'_> {}
+
+/// An iterator over overlapping windows of a [`DecSixbitStr`], each yielded as an owned [`DecSixbit`].
+///
+/// This struct is created by [`DecSixbitStr::windows`].
+#[derive(Debug, Clone)]
+pub struct Windows<'a> {
+    sixbit: &'a DecSixbitStr,
+    size: usize,
+    /// Start index of the next window yielded from the front
+    front: usize,
+    /// One past the start index of the next window yielded from the back
+    back: usize,
+}
+
+impl Iterator for Windows<'_> {
+    type Item = DecSixbit;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.front >= self.back {
+            return None;
+        }
+        let start = self.front;
+        self.front += 1;
+        Some(self.sixbit.slice_unchecked(start, start + self.size))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.back - self.front;
+        (remaining, Some(remaining))
+    }
+}
+
+impl DoubleEndedIterator for Windows<'_> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.front >= self.back {
+            return None;
+        }
+        self.back -= 1;
+        Some(self.sixbit.slice_unchecked(self.back, self.back + self.size))
+    }
+}
+
+impl ExactSizeIterator for Windows<'_> {}
+
+impl FusedIterator for Windows<'_> {}
+
+impl<'a> IntoIterator for &'a DecSixbitStr {
+    type Item = char;
+    type IntoIter = Chars<'a>;
+
+    /// Iterates over the characters, like [`chars`](DecSixbitStr::chars).
+    #[inline]
+    fn into_iter(self) -> Chars<'a> {
+        self.chars()
+    }
+}
+
+impl PartialEq for DecSixbitStr {
+    /// Compares the characters, via the payload and length, so the trailing-space marker never matters.
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        raw::eq_unchecked(self.payload(), self.len(), other.payload(), other.len())
+    }
+}
+
+impl Eq for DecSixbitStr {}
+
+impl PartialOrd for DecSixbitStr {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for DecSixbitStr {
+    /// Orders values lexicographically by their decoded characters, like `str`.
+    ///
+    /// See [`DecSixbit::lexicographic_cmp`] for why the packed bytes can be compared directly.
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        raw::cmp_unchecked(self.payload(), self.len(), other.payload(), other.len())
+    }
+}
+
+impl Hash for DecSixbitStr {
+    /// Hashes the decoded characters exactly like the equivalent `str`; see [`hash_as_str`](Self::hash_as_str).
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.hash_as_str(state);
+    }
+}
+
+impl fmt::Debug for DecSixbitStr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        display::debug_packed(f, "DecSixbitStr", &self.bytes, self.len())
+    }
+}
+
+impl fmt::Display for DecSixbitStr {
+    /// Writes the decoded characters without allocating, honoring width, fill, alignment, and precision like `str`.
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        display::pad_packed(f, self.payload(), self.len())
+    }
+}
+
+impl fmt::LowerHex for DecSixbitStr {
+    /// Writes the packed payload bytes, marker excluded, as two lowercase hex digits each.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        display::radix_packed(f, self.payload(), "0x", |f, byte| write!(f, "{:02x}", byte))
+    }
+}
+
+impl fmt::UpperHex for DecSixbitStr {
+    /// Writes the packed payload bytes, marker excluded, as two uppercase hex digits each.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        display::radix_packed(f, self.payload(), "0x", |f, byte| write!(f, "{:02X}", byte))
+    }
+}
+
+impl fmt::Octal for DecSixbitStr {
+    /// Writes the packed payload bytes, marker excluded, as three octal digits each.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        display::radix_packed(f, self.payload(), "0o", |f, byte| write!(f, "{:03o}", byte))
+    }
+}
+
+impl AsRef<[u8]> for DecSixbitStr {
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for DecSixbitStr {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use alloc::string::ToString;
+
+        if serializer.is_human_readable() {
+            self.to_string().serialize(serializer)
+        } else {
+            self.serialize_packed(serializer)
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl DecSixbitStr {
+    /// Serializes the length and packed bytes, the layout used by non-human-readable formats.
+    pub(crate) fn serialize_packed<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::Serialize;
+        // The length is always a u64 so the layout doesn't depend on the target's pointer width, and
+        // the packed bytes are a byte string rather than a sequence of integers
+        (self.len() as u64, PackedBytes(self.as_bytes())).serialize(serializer)
+    }
+}
+
+/// Serializes packed bytes with `serialize_bytes`, so formats store them as one contiguous byte string.
+#[cfg(feature = "serde")]
+struct PackedBytes<'a>(&'a [u8]);
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for PackedBytes<'_> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_bytes(self.0)
+    }
+}
+
+impl ToOwned for DecSixbitStr {
+    type Owned = DecSixbit;
+
+    fn to_owned(&self) -> DecSixbit {
+        DecSixbit::from_buffer(&self.bytes, self.len())
+    }
+}
+
+impl Borrow<DecSixbitStr> for DecSixbit {
+    fn borrow(&self) -> &DecSixbitStr {
+        self
+    }
+}
+
+impl PartialEq<DecSixbit> for DecSixbitStr {
+    fn eq(&self, other: &DecSixbit) -> bool {
+        self == other.as_sixbit_str()
+    }
+}
+
+impl PartialEq<DecSixbitStr> for DecSixbit {
+    fn eq(&self, other: &DecSixbitStr) -> bool {
+        self.as_sixbit_str() == other
+    }
+}
+
+impl PartialEq<DecSixbit> for &DecSixbitStr {
+    fn eq(&self, other: &DecSixbit) -> bool {
+        *self == other.as_sixbit_str()
+    }
+}
+
+impl PartialEq<&DecSixbitStr> for DecSixbit {
+    fn eq(&self, other: &&DecSixbitStr) -> bool {
+        self.as_sixbit_str() == *other
+    }
+}
+
+impl PartialEq<str> for DecSixbitStr {
+    /// Compares the decoded characters with `other`, without decoding or allocating.
+    fn eq(&self, other: &str) -> bool {
+        raw::eq_str_unchecked(self.payload(), self.len(), other)
+    }
+}
+
+impl PartialEq<&str> for DecSixbitStr {
+    fn eq(&self, other: &&str) -> bool {
+        self == *other
+    }
+}
+
+impl PartialEq<DecSixbitStr> for str {
+    fn eq(&self, other: &DecSixbitStr) -> bool {
+        other == self
+    }
+}
+
+impl PartialEq<str> for DecSixbit {
+    fn eq(&self, other: &str) -> bool {
+        self.as_sixbit_str() == other
+    }
+}
+
+impl PartialEq<&str> for DecSixbit {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_sixbit_str() == *other
+    }
+}
+
+impl PartialEq<DecSixbit> for str {
+    fn eq(&self, other: &DecSixbit) -> bool {
+        other.as_sixbit_str() == self
+    }
+}
+
+impl PartialEq<DecSixbit> for &str {
+    fn eq(&self, other: &DecSixbit) -> bool {
+        other.as_sixbit_str() == *self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DecSixbitStr;
+    use crate::{DecSixbit, Error};
+    use std::collections::HashMap;
+    use std::hash::{BuildHasher, RandomState};
+
+    #[test]
+    fn test_to_trimmed_string() {
+        for input in ["", "    ", "PIP", "PIP ", " PIP  SAV", "DSKB        ", "A B C       D       "] {
+            let trimmed = DecSixbit::new(input).unwrap().to_trimmed_string();
+            assert_eq!(trimmed, input.trim_end(), "{input:?}");
+            assert_eq!(trimmed.capacity(), trimmed.len());
+        }
+    }
+
+    #[test]
+    fn test_verify() {
+        for input in ["", "A", "ABC ", "HELLO", "TEST    "] {
+            assert_eq!(DecSixbit::new(input).unwrap().verify(), Ok(()), "{input:?}");
+        }
+        // "HELLO" with a padding bit set
+        let corrupt = DecSixbitStr::from_bytes_unchecked(&[0xa2, 0x5b, 0x2c, 0xbd]);
+        assert_eq!(corrupt.verify(), Err(crate::IntegrityError::NonzeroPadding { offset: 3 }));
+    }
+
+    #[test]
+    fn test_to_lowercase_string() {
+        let all: String = crate::SIXBIT_CHARS.iter().collect();
+        for input in [all.as_str(), "", "TEST    ", "HELLO, WORLD!"] {
+            let sixbit = DecSixbit::new(input).unwrap();
+            let lower = sixbit.to_lowercase_string();
+            assert_eq!(lower, input.to_ascii_lowercase());
+            assert_eq!(lower.capacity(), input.len());
+            assert_eq!(DecSixbit::new(&lower.to_ascii_uppercase()), Ok(sixbit));
+        }
+    }
+
+    #[test]
+    fn test_field() {
+        let columns = [0..6, 6..9, 9..14, 14..16];
+        let text = format!("{:<6}{:<3}{:>5}{:2}", "BOLT", "EA", "12", "");
+        let record = DecSixbit::new(&text).unwrap();
+        for range in columns {
+            let expected = text[range.clone()].trim_matches(' ');
+            assert_eq!(record.field(range.clone()), Some(DecSixbit::new(expected).unwrap()), "{range:?}");
+            assert_eq!(record.field_str(range.clone()).as_deref(), Some(expected), "{range:?}");
+        }
+        assert_eq!(record.field(14..16).unwrap().len(), 0);
+        assert_eq!(record.field(3..3).unwrap().len(), 0);
+        let (start, end) = (5, 0);
+        assert_eq!(record.field(start..end), None);
+        assert_eq!(record.field(10..17), None);
+        assert_eq!(record.field_str(16..17), None);
+        assert_eq!(DecSixbit::new(" A B ").unwrap().field_str(0..5).as_deref(), Some("A B"));
+    }
+
+    #[test]
+    fn test_blocks() {
+        // (input, block size, number of blocks)
+        let cases = [("", 6, 0), ("HELLO", 6, 1), ("HELLO WO", 6, 1), ("HELLO WORLD", 6, 2), ("HELLO WORLD ", 3, 3), ("HELLO WORLD", 9, 1)];
+        for (input, block_size, count) in cases {
+            let sixbit = DecSixbit::new(input).unwrap();
+            let blocks = sixbit.to_blocks(block_size).unwrap();
+            assert_eq!(blocks.len(), count, "{input:?} in blocks of {block_size}");
+            assert!(blocks.iter().all(|block| block.len() == block_size));
+            for (index, block) in blocks.iter().enumerate() {
+                let chars = block_size / 3 * 4;
+                let expected = format!("{input:<width$}", width = (index + 1) * chars);
+                assert_eq!(crate::decode(block, chars).unwrap(), expected[index * chars..(index + 1) * chars]);
+            }
+            assert_eq!(DecSixbit::from_blocks(&blocks, input.len()), Ok(sixbit));
+        }
+
+        let sixbit = DecSixbit::new("HELLO").unwrap();
+        assert_eq!(sixbit.to_blocks(4), Err(Error::InvalidBlockSize));
+        assert_eq!(sixbit.to_blocks(0), Err(Error::InvalidBlockSize));
+        let blocks = sixbit.to_blocks(3).unwrap();
+        assert_eq!(DecSixbit::from_blocks(&blocks, 9), Err(Error::InvalidBytesLength));
+        assert_eq!(DecSixbit::from_blocks(&blocks, 4).unwrap().to_string(), "HELL");
+        // The bit count of this length wraps around to 2, which would otherwise fit in one block
+        assert_eq!(DecSixbit::from_blocks([[0x80, 0, 0]], usize::MAX / 6 + 1), Err(Error::InvalidBytesLength));
+        assert_eq!(DecSixbit::from_blocks([[0x80, 0, 0]], 1 << 40), Err(Error::InvalidBytesLength));
+    }
+
+    #[test]
+    fn test_framed_bytes() {
+        let mut buffer = Vec::new();
+        let inputs: Vec<String> = [0, 1, 3, 4, 5, 127, 128, 300].iter().map(|&len| "AB C".repeat(len).chars().take(len).collect()).collect();
+        for input in &inputs {
+            let sixbit = DecSixbit::new(input).unwrap();
+            let framed = sixbit.to_framed_bytes();
+            assert_eq!(DecSixbit::from_framed_bytes(&framed), Ok((sixbit.clone(), framed.len())), "{input:?}");
+            let header = if input.len() < 128 { 2 } else { 3 };
+            assert_eq!(framed.len(), header + (input.len() * 6).div_ceil(8));
+            sixbit.write_framed_bytes(&mut buffer);
+        }
+
+        // Frames parsed back to back, followed by unrelated bytes
+        buffer.extend_from_slice(b"TRAILER");
+        let mut rest = &buffer[..];
+        for input in &inputs {
+            let (sixbit, used) = DecSixbit::from_framed_bytes(rest).unwrap();
+            assert_eq!(sixbit.to_string(), *input);
+            rest = &rest[used..];
+        }
+        assert_eq!(rest, b"TRAILER");
+    }
+
+    #[test]
+    fn test_framed_bytes_errors() {
+        let framed = DecSixbit::new("HELLO").unwrap().to_framed_bytes();
+        for end in 0..framed.len() {
+            assert_eq!(DecSixbit::from_framed_bytes(&framed[..end]), Err(Error::InvalidBytesLength), "{end}");
+        }
+        assert_eq!(DecSixbit::from_framed_bytes(&[2, 0]), Err(Error::UnsupportedVersion(2)));
+        assert_eq!(DecSixbit::from_framed_bytes(&[1, 0x80, 0x80]), Err(Error::InvalidBytesLength));
+        assert_eq!(DecSixbit::from_framed_bytes(&[1, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x7F]), Err(Error::InvalidBytesLength));
+        assert_eq!(DecSixbit::from_framed_bytes(&[1, 1, 0x87]), Err(Error::NonzeroPadding));
+        assert_eq!(DecSixbit::from_framed_bytes(&[1, 0]), Ok((DecSixbit::default(), 2)));
+    }
+
+    #[test]
+    fn test_hex_round_trip() {
+        // One input per remainder of the length modulo 4, and one needing the marker
+        for input in ["", "A", "AB", "ABC", "ABCD", "HELLO", "TEST    "] {
+            let sixbit = DecSixbit::new(input).unwrap();
+            let hex = sixbit.to_hex();
+            assert_eq!(hex, format!("{sixbit:x}"));
+            assert_eq!(hex.len(), 2 * crate::encode(input).unwrap().0.len());
+            assert_eq!(DecSixbit::from_hex(&hex, input.len()).as_ref(), Ok(&sixbit), "{input:?}");
+            assert_eq!(DecSixbit::from_hex(&format!("{sixbit:#X}"), input.len()).as_ref(), Ok(&sixbit), "{input:?}");
+        }
+    }
+
+    #[test]
+    fn test_hex_errors() {
+        assert_eq!(DecSixbit::from_hex("8628e", 4), Err(Error::InvalidHex));
+        assert_eq!(DecSixbit::from_hex("8628g4", 4), Err(Error::InvalidHex));
+        assert_eq!(DecSixbit::from_hex("+1", 1), Err(Error::InvalidHex));
+        assert_eq!(DecSixbit::from_hex("86 28e4", 4), Err(Error::InvalidHex));
+        assert_eq!(DecSixbit::from_hex("0x", 0), Ok(DecSixbit::default()));
+        assert_eq!(DecSixbit::from_hex("8628e4", 3), Err(Error::InvalidBytesLength));
+        assert_eq!(DecSixbit::from_hex("8621", 2), Err(Error::NonzeroPadding));
+        // The bit count of this length wraps around to 2, which would otherwise fit in one byte
+        assert_eq!(DecSixbit::from_hex("80", usize::MAX / 6 + 1), Err(Error::InvalidBytesLength));
+        assert_eq!(DecSixbit::from_hex("80", usize::MAX), Err(Error::InvalidBytesLength));
+    }
+
+    #[test]
+    fn test_hash_matches_str() {
+        use std::collections::hash_map::DefaultHasher;
+        use std::hash::{BuildHasherDefault, Hash, Hasher};
+
+        /// A non-streaming hasher, for which `write(a); write(b)` differs from `write(ab)`.
+        #[derive(Default)]
+        struct CallHasher(Vec<Vec<u8>>);
+
+        impl Hasher for CallHasher {
+            fn finish(&self) -> u64 {
+                0
+            }
+
+            fn write(&mut self, bytes: &[u8]) {
+                self.0.push(bytes.to_vec());
+            }
+        }
+
+        let long = "THE QUICK BROWN FOX JUMPS OVER THE LAZY DOG 0123456789 ".repeat(3);
+        let random = RandomState::new();
+        for input in ["", " ", "ABC ", "DSKB", &long] {
+            let sixbit = DecSixbit::new(input).unwrap();
+            assert_eq!(random.hash_one(&sixbit), random.hash_one(input), "{input:?}");
+            assert_eq!(random.hash_one(sixbit.as_sixbit_str()), random.hash_one(input));
+            assert_eq!(BuildHasherDefault::<DefaultHasher>::default().hash_one(&sixbit), BuildHasherDefault::<DefaultHasher>::default().hash_one(input));
+
+            let mut calls = CallHasher::default();
+            sixbit.hash(&mut calls);
+            let mut expected = CallHasher::default();
+            input.hash(&mut expected);
+            assert_eq!(calls.0, expected.0, "{input:?}");
+        }
+    }
+
+    #[test]
+    fn test_raw_entry_lookup_by_str() {
+        let mut map: hashbrown::HashMap<DecSixbit, u32> = hashbrown::HashMap::new();
+        for (value, name) in ["DSKA", "DSKB", "MTA0"].into_iter().enumerate() {
+            map.insert(DecSixbit::new(name).unwrap(), value as u32);
+        }
+        let probe = "DSKB";
+        let hash = map.hasher().hash_one(probe);
+        assert_eq!(map.raw_entry().from_hash(hash, |key| key == probe).map(|(_, &value)| value), Some(1));
+        let probe = "LPT";
+        let hash = map.hasher().hash_one(probe);
+        assert!(map.raw_entry().from_hash(hash, |key| key == probe).is_none());
+    }
+
+    #[test]
+    fn test_eq_str() {
+        let sixbit = DecSixbit::new("ABC ").unwrap();
+        assert!(sixbit == "ABC ");
+        assert!("ABC " == sixbit);
+        assert!(*sixbit.as_sixbit_str() == *"ABC ");
+        assert!(sixbit != "ABC");
+        assert!(sixbit != "abc ");
+        assert!(sixbit != "ABC  ");
+        assert_eq!(DecSixbit::default(), "");
+    }
+
+    #[test]
+    fn test_starts_and_ends_with_sixbit() {
+        // Needles of every length and alignment, cut from the haystacks and from other strings
+        let haystacks = ["", "A", "AB", "ABC", "ABC ", "ABCDE", "SYS:LOGIN.SAV", "    ", "THE QUICK BROWN FOX"];
+        let others = ["", " ", "X", "AB ", "SYS:", ".SAV", "OX", "BROWN FOX", "LONGER THAN ANY HAYSTACK HERE"];
+        for haystack in haystacks {
+            let sixbit = DecSixbit::new(haystack).unwrap();
+            let needles = (0..=haystack.len()).flat_map(|i| [&haystack[..i], &haystack[i..]]).chain(others);
+            for needle in needles {
+                let encoded = DecSixbit::new(needle).unwrap();
+                assert_eq!(sixbit.starts_with_sixbit(&encoded), haystack.starts_with(needle), "{haystack:?} starts with {needle:?}");
+                assert_eq!(sixbit.ends_with_sixbit(&encoded), haystack.ends_with(needle), "{haystack:?} ends with {needle:?}");
+                assert_eq!(sixbit.starts_with_sixbit(&encoded), sixbit.starts_with(needle));
+                assert_eq!(sixbit.ends_with_sixbit(&encoded), sixbit.ends_with(needle));
+            }
+        }
+
+        let empty = DecSixbit::default();
+        assert!(empty.starts_with_sixbit(&empty) && empty.ends_with_sixbit(&empty));
+        let short = DecSixbit::new("AB").unwrap();
+        let long = DecSixbit::new("ABC").unwrap();
+        assert!(!short.starts_with_sixbit(&long) && !short.ends_with_sixbit(&long));
+    }
+
+    #[test]
+    fn test_groups() {
+        let sixbit = DecSixbit::new("ABCDEFGHI").unwrap();
+        assert_eq!(sixbit.group_count(), 3);
+        assert_eq!(sixbit.groups().map(|(_, count)| count).collect::<Vec<_>>(), [4, 4, 1]);
+        assert_eq!(DecSixbit::default().groups().count(), 0);
+
+        for input in ["", "A", "AB", "ABC", "ABCD", "ABC ", "ABCDEFGH    ", "THE QUICK BROWN FOX"] {
+            let sixbit = DecSixbit::new(input).unwrap();
+            assert_eq!(sixbit.groups().len(), sixbit.group_count());
+            let mut bytes: Vec<u8> = sixbit.groups().flat_map(|(group, _)| group).collect();
+            assert_eq!(sixbit.groups().map(|(_, count)| count).sum::<usize>(), input.len());
+            bytes.truncate((input.len() * 6).div_ceil(8));
+            assert_eq!(crate::decode(&bytes, input.len()).unwrap(), input);
+        }
+    }
+
+    #[test]
+    fn test_octal_words_published_constants() {
+        // SIXBIT /DSK/ and /SYS/ as they appear in TOPS-10 monitor listings
+        assert_eq!(DecSixbit::new("DSK").unwrap().to_octal_words(), ["446353000000"]);
+        assert_eq!(DecSixbit::new("SYS").unwrap().to_octal_words(), ["637163000000"]);
+        assert_eq!(DecSixbit::new("SYSTAT").unwrap().to_octal_words(), ["637163644164"]);
+        assert_eq!(DecSixbit::new("").unwrap().to_octal_words(), Vec::<String>::new());
+    }
+
+    #[test]
+    fn test_octal_words_round_trip() {
+        let sixbit = DecSixbit::new("DSKB:SYSTAT.LOW").unwrap();
+        let words = sixbit.to_octal_words();
+        assert_eq!(words.len(), 3);
+        let words: Vec<&str> = words.iter().map(String::as_str).collect();
+        let decoded = DecSixbit::from_octal_words(&words).unwrap();
+        assert_eq!(decoded.to_string(), "DSKB:SYSTAT.LOW   ");
+    }
+
+    #[test]
+    fn test_from_octal_words_invalid() {
+        assert_eq!(DecSixbit::from_octal_words(&["4463530000"]), Err(Error::LengthMismatch));
+        assert_eq!(DecSixbit::from_octal_words(&["446353000008"]), Err(Error::InvalidCharacter));
+        assert_eq!(DecSixbit::from_octal_words(&[]), Ok(DecSixbit::new("").unwrap()));
+    }
+
+    #[test]
+    fn test_dump_full_group() {
+        let dump = DecSixbit::new("ABCD").unwrap().dump();
+        let lines: Vec<&str> = dump.lines().collect();
+        assert_eq!(lines.len(), 3);
+        assert_eq!(lines[0].len(), lines[1].len());
+        assert_eq!(lines[0].matches('|').count(), 2);
+        assert_eq!(lines[1].matches('|').count(), 3);
+        for c in ['A', 'B', 'C', 'D'] {
+            assert!(lines[2].contains(c));
+        }
+    }
+
+    #[test]
+    fn test_dump_partial_group() {
+        let dump = DecSixbit::new("XYZ").unwrap().dump();
+        let lines: Vec<&str> = dump.lines().collect();
+        assert_eq!(lines.len(), 3);
+        assert!(lines[1].ends_with("|......"), "Padding bits should be marked: {}", lines[1]);
+        assert_eq!(lines[2].trim_start_matches("chars:").split_whitespace().collect::<Vec<_>>(), ["X", "Y", "Z"]);
+    }
+
+    #[test]
+    fn test_dump_marker() {
+        let dump = DecSixbit::new("ABC ").unwrap().dump();
+        let lines: Vec<&str> = dump.lines().collect();
+        assert_eq!(lines.len(), 4);
+        assert_eq!(lines[3], "marker: 00000011 (trailing-space marker)");
+    }
+
+    #[test]
+    fn test_new_over_sub_slices() {
+        let fields = ["DSKB", "SYS", "FOO BAR", "    ", ""];
+        let mut buffer = Vec::new();
+        let mut spans = Vec::new();
+        for field in fields {
+            let sixbit = DecSixbit::new(field).unwrap();
+            let start = buffer.len();
+            buffer.extend_from_slice(sixbit.as_bytes());
+            spans.push((start..buffer.len(), sixbit.len()));
+        }
+
+        for (field, (range, len)) in fields.iter().zip(spans) {
+            let view = DecSixbitStr::new(&buffer[range.clone()], len).unwrap();
+            assert_eq!(view.len(), field.len());
+            assert_eq!(view.to_string(), *field);
+            assert_eq!(view.as_bytes().as_ptr(), buffer[range].as_ptr(), "View should not copy");
+        }
+    }
+
+    #[test]
+    fn test_new_inconsistent_length() {
+        let sixbit = DecSixbit::new("HELLO").unwrap();
+        assert_eq!(DecSixbitStr::new(sixbit.as_bytes(), 4), Err(Error::InvalidBytesLength));
+        assert_eq!(DecSixbitStr::new(sixbit.as_bytes(), 6), Err(Error::InvalidBytesLength));
+
+        // Four characters ending in a space require the marker byte
+        let (bytes, len) = crate::encode("ABC ").unwrap();
+        assert_eq!(DecSixbitStr::new(&bytes, len), Err(Error::InvalidBytesLength));
+        let sixbit = DecSixbit::new("ABC ").unwrap();
+        assert!(DecSixbitStr::new(sixbit.as_bytes(), 4).is_ok());
+    }
+
+    #[test]
+    fn test_read_only_api() {
+        let sixbit = DecSixbit::new("HELLO WORLD").unwrap();
+        let view = DecSixbitStr::new(sixbit.as_bytes(), sixbit.len()).unwrap();
+        assert_eq!(view.len(), 11);
+        assert!(!view.is_empty());
+        assert_eq!(view.get(4), Some('O'));
+        assert_eq!(view.get(11), None);
+        assert!(view.starts_with("HELLO"));
+        assert!(view.ends_with("WORLD"));
+        assert!(view.contains("O W"));
+        assert!(view.chars().eq("HELLO WORLD".chars()));
+        assert_eq!(view.count_char('L'), 3);
+    }
+
+    #[test]
+    fn test_owned_and_borrowed_agree() {
+        let state = RandomState::new();
+        for input in ["", "A", "AB", "ABC", "ABCD", "ABC ", "TEST    ", "HELLO WORLD"] {
+            let owned = DecSixbit::new(input).unwrap();
+            let view: &DecSixbitStr = &owned;
+            assert_eq!(view, owned);
+            assert_eq!(owned, view);
+            assert_eq!(view.len(), owned.len());
+            assert_eq!(view.to_string(), owned.to_string());
+            assert_eq!(state.hash_one(view), state.hash_one(&owned), "Hash mismatch for '{}'", input);
+            assert_eq!(view.to_owned(), owned);
+        }
+    }
+
+    #[test]
+    fn test_ordering_matches_owned() {
+        let inputs = ["", "A", "AB", "AB ", "ABC", "ABC ", "ABC!", "B"];
+        for a in inputs {
+            for b in inputs {
+                let a_owned = DecSixbit::new(a).unwrap();
+                let b_owned = DecSixbit::new(b).unwrap();
+                assert_eq!(a_owned.as_sixbit_str().cmp(b_owned.as_sixbit_str()), a.cmp(b));
+                assert_eq!(a_owned.cmp(&b_owned), a.cmp(b));
+            }
+        }
+    }
+
+    #[test]
+    fn test_borrowed_map_lookup() {
+        let mut map = HashMap::new();
+        map.insert(DecSixbit::new("DSKB").unwrap(), 1);
+        map.insert(DecSixbit::new("SYS").unwrap(), 2);
+
+        let (bytes, len) = crate::encode("SYS").unwrap();
+        let probe = DecSixbitStr::new(&bytes, len).unwrap();
+        assert_eq!(map.get(probe), Some(&2));
+    }
+
+    #[test]
+    fn test_fingerprint_pinned_values() {
+        // 64-bit FNV-1a of the ASCII text; these values must never change
+        let cases = [
+            ("", 0xcbf2_9ce4_8422_2325),
+            ("A", 0xaf63_fc4c_8602_22ec),
+            ("HELLO", 0xa0b4_00b9_8ea8_182b),
+            ("FOUR", 0x1d5d_ff85_ab0e_55e5),
+            ("ABC ", 0x939f_fc8b_a58d_7ef1),
+            ("DSKB:README.TXT", 0x1b23_c7b6_f60a_26ad),
+        ];
+        for (input, fingerprint) in cases {
+            assert_eq!(DecSixbit::new(input).unwrap().fingerprint(), fingerprint, "{input:?}");
+        }
+    }
+
+    #[test]
+    fn test_fingerprint_ignores_marker() {
+        let sixbit = DecSixbit::new("FOUR").unwrap();
+        let mut bytes = sixbit.as_bytes().to_vec();
+        bytes.push(crate::bits::TRAILING_SPACE_MARKER);
+        assert_eq!(DecSixbitStr::from_bytes_unchecked(&bytes).fingerprint(), sixbit.fingerprint());
+        assert_eq!(DecSixbit::try_from_slice(&bytes).unwrap().fingerprint(), sixbit.fingerprint());
+
+        let spaced = DecSixbit::new("ABC ").unwrap();
+        assert_eq!(spaced.as_bytes().len(), 4);
+        assert_ne!(spaced.fingerprint(), DecSixbit::new("ABC").unwrap().fingerprint());
+    }
+
+    #[test]
+    fn test_sort_key_layout() {
+        assert_eq!(DecSixbit::new("").unwrap().to_sort_key(), [0x00]);
+        // ' ' is field 1 and 'A' is field 34, followed by the 7-bit terminator
+        assert_eq!(DecSixbit::new(" ").unwrap().to_sort_key(), [0b0000_0010, 0b0000_0000]);
+        assert_eq!(DecSixbit::new("A").unwrap().to_sort_key(), [0b0100_0100, 0b0000_0000]);
+
+        let mut out = vec![0xAA];
+        DecSixbit::new("AB").unwrap().write_sort_key(&mut out);
+        assert_eq!(out[0], 0xAA);
+        assert_eq!(&out[1..], DecSixbit::new("AB").unwrap().to_sort_key());
+    }
+
+    #[test]
+    fn test_from_sort_key_rejects_malformed_keys() {
+        let key = DecSixbit::new("HELLO").unwrap().to_sort_key();
+        assert_eq!(DecSixbit::from_sort_key(&[]), Err(Error::InvalidBytesLength));
+        assert_eq!(DecSixbit::from_sort_key(&key[..key.len() - 1]), Err(Error::InvalidBytesLength));
+        assert_eq!(DecSixbit::from_sort_key(&[key.as_slice(), &[0]].concat()), Err(Error::InvalidBytesLength));
+        assert_eq!(DecSixbit::from_sort_key(&[0x01]), Err(Error::InvalidBytesLength));
+        assert_eq!(DecSixbit::from_sort_key(&[0b1000_0010, 0]), Err(Error::InvalidCharacter));
+    }
+
+    mod sort_key {
+        use crate::proptest::sixbit_string;
+        use crate::DecSixbit;
+        use proptest::prelude::*;
+
+        fn assert_key_order(a: &str, b: &str) -> Result<(), TestCaseError> {
+            let (key_a, key_b) = (DecSixbit::new(a).unwrap().to_sort_key(), DecSixbit::new(b).unwrap().to_sort_key());
+            prop_assert_eq!(key_a.cmp(&key_b), a.cmp(b), "{:?} vs {:?}", a, b);
+            prop_assert_eq!(DecSixbit::from_sort_key(&key_a).unwrap().to_string(), a);
+            Ok(())
+        }
+
+        proptest! {
+            #[test]
+            fn prop_key_order_matches_str(a in sixbit_string(0..=20), b in sixbit_string(0..=20)) {
+                assert_key_order(&a, &b)?;
+            }
+
+            #[test]
+            fn prop_prefix_sorts_first(a in sixbit_string(0..=20), suffix in sixbit_string(1..=8)) {
+                assert_key_order(&a, &(a.clone() + &suffix))?;
+            }
+
+            #[test]
+            fn prop_trailing_spaces_sort_after(a in sixbit_string(0..=20), spaces in 1usize..=8) {
+                assert_key_order(&a, &(a.clone() + &" ".repeat(spaces)))?;
+            }
+        }
+    }
+}
@@ -0,0 +1,326 @@
+//! Encoding and decoding into caller-provided buffers, available without an allocator.
+//!
+//! These are the building blocks of the allocating functions, exposed for targets that have no heap.
+//! Every function here works with the `alloc` and `std` features disabled.
+
+use crate::decode::decode_into;
+use crate::encode::{encode_ascii_into, encode_into};
+use crate::{bits, Error, IntegrityError, ASCII_OFFSET, MASK_SIX_BITS};
+
+/// Returns the number of packed bytes needed to encode `len` characters.
+///
+/// # Examples
+///
+/// ```rust
+/// use dec_sixbit::encoded_len;
+///
+/// assert_eq!(encoded_len(0), 0);
+/// assert_eq!(encoded_len(3), 3);
+/// assert_eq!(encoded_len(5), 4);
+/// ```
+#[inline]
+pub const fn encoded_len(len: usize) -> usize {
+    bits::bytes_for_chars(len)
+}
+
+/// Checks that every character of `str` can be encoded, without encoding it.
+///
+/// # Errors
+/// Returns an [`Error::InvalidCharacter`] if the input contains characters outside the range 32-95.
+///
+/// # Examples
+///
+/// ```rust
+/// use dec_sixbit::{validate, Error};
+///
+/// assert_eq!(validate("SYS:LOGIN.SAV"), Ok(()));
+/// assert_eq!(validate("login"), Err(Error::InvalidCharacter));
+/// ```
+#[inline]
+pub fn validate(str: &str) -> Result<(), Error> {
+    match str.bytes().all(|c| crate::is_sixbit_char(c as char)) {
+        true => Ok(()),
+        false => Err(Error::InvalidCharacter),
+    }
+}
+
+/// Encodes `str` like [`encode`](crate::encode), into the start of `out`.
+///
+/// # Returns
+/// The number of bytes written, [`encoded_len`] of `str.len()`. Bytes of `out` past that are left as
+/// they were.
+///
+/// # Errors
+/// - Returns an [`Error::TooLong`] if `out` is shorter than the encoded bytes, with `max` set to the
+///   number of characters `out` can hold.
+/// - Returns an [`Error::InvalidCharacter`] if the input contains characters outside the range 32-95,
+///   in which case `out` may be partially written.
+///
+/// # Examples
+///
+/// ```rust
+/// use dec_sixbit::{encode_to_slice, Error};
+///
+/// let mut buf = [0u8; 8];
+/// let written = encode_to_slice("HELLO", &mut buf).unwrap();
+/// assert_eq!(written, 4);
+/// assert_eq!(encode_to_slice("HELLO WORLD", &mut buf), Err(Error::TooLong { len: 11, max: 10 }));
+/// ```
+pub fn encode_to_slice(str: &str, out: &mut [u8]) -> Result<usize, Error> {
+    let written = encoded_len(str.len());
+    if out.len() < written {
+        return Err(Error::TooLong { len: str.len(), max: out.len() * 4 / 3 });
+    }
+    encode_into(str, &mut out[..written])?;
+    Ok(written)
+}
+
+/// Decodes `len` characters like [`decode`](crate::decode), into the start of `out`.
+///
+/// # Returns
+/// The decoded characters, borrowed from `out`.
+///
+/// # Errors
+/// - Returns an [`Error::InvalidBytesLength`] if `bytes.len()` and `len` are inconsistent.
+/// - Returns an [`Error::TooLong`] if `out` is shorter than `len`.
+///
+/// # Examples
+///
+/// ```rust
+/// use dec_sixbit::{decode_to_slice, encode_to_slice};
+///
+/// let mut packed = [0u8; 4];
+/// encode_to_slice("HELLO", &mut packed).unwrap();
+/// let mut buf = [0u8; 16];
+/// assert_eq!(decode_to_slice(&packed, 5, &mut buf).unwrap(), "HELLO");
+/// ```
+pub fn decode_to_slice<'a>(bytes: &[u8], len: usize, out: &'a mut [u8]) -> Result<&'a str, Error> {
+    if bits::checked_bytes_for_chars(len) != Some(bytes.len()) {
+        return Err(Error::InvalidBytesLength);
+    }
+    if out.len() < len {
+        return Err(Error::TooLong { len, max: out.len() });
+    }
+    decode_into(bytes, len, out);
+    // SAFETY: decoded SIXBIT characters are always ASCII
+    Ok(unsafe { core::str::from_utf8_unchecked(&out[..len]) })
+}
+
+/// Returns the character at `index` of the `len` characters packed in `bytes`, without decoding the
+/// others.
+///
+/// # Errors
+/// - Returns an [`Error::InvalidBytesLength`] if `bytes.len()` and `len` are inconsistent.
+/// - Returns an [`Error::OutOfBounds`] if `index` is not less than `len`.
+///
+/// # Examples
+///
+/// ```rust
+/// use dec_sixbit::{char_at, encode_to_slice, Error};
+///
+/// let mut packed = [0u8; 4];
+/// encode_to_slice("HELLO", &mut packed).unwrap();
+/// assert_eq!(char_at(&packed, 5, 4), Ok('O'));
+/// assert_eq!(char_at(&packed, 5, 5), Err(Error::OutOfBounds));
+/// ```
+pub fn char_at(bytes: &[u8], len: usize, index: usize) -> Result<char, Error> {
+    if bits::checked_bytes_for_chars(len) != Some(bytes.len()) {
+        return Err(Error::InvalidBytesLength);
+    }
+    if index >= len {
+        return Err(Error::OutOfBounds);
+    }
+    Ok((bits::value_at(bytes, index) + ASCII_OFFSET) as char)
+}
+
+/// Checks that stored bytes are exactly the storage form of `len` characters, reporting which check
+/// failed and at what byte offset.
+///
+/// The bytes are expected as [`DecSixbit::as_bytes`](crate::DecSixbit::as_bytes) returns them: the packed
+/// payload, followed by the trailing-space marker byte when the length is a nonzero multiple of 4 and the
+/// last character is a space. The checks run in order: the byte count, the padding bits after the last
+/// character, and the marker. This only reads the last two bytes, so it is cheap enough to run over every
+/// value read from disk.
+///
+/// # Errors
+/// - Returns an [`IntegrityError::ByteCount`] if `bytes` is neither the payload size nor one byte longer,
+///   or is one byte longer without ending in a marker.
+/// - Returns an [`IntegrityError::NonzeroPadding`] if the unused bits after the last character are set.
+/// - Returns an [`IntegrityError::MissingMarker`] if the content needs a marker and the bytes end without one.
+/// - Returns an [`IntegrityError::UnexpectedMarker`] if the bytes end in a marker the content doesn't need.
+/// - Returns an [`IntegrityError::InvalidMarker`] if the content needs a marker and a different byte is in its place.
+///
+/// # Examples
+///
+/// ```rust
+/// use dec_sixbit::{integrity_check, IntegrityError};
+///
+/// // "ABC " needs the marker after its 3 payload bytes
+/// let stored = [0x86, 0x28, 0xc0, 0x03];
+/// assert_eq!(integrity_check(&stored, 4), Ok(()));
+/// assert_eq!(integrity_check(&stored[..3], 4), Err(IntegrityError::MissingMarker { offset: 3 }));
+/// assert_eq!(integrity_check(&[0x86, 0x28, 0xc0, 0x07], 4), Err(IntegrityError::InvalidMarker { offset: 3, found: 0x07 }));
+///
+/// let mut payload = [0; 3];
+/// dec_sixbit::encode_to_slice("ABC ", &mut payload).unwrap();
+/// assert_eq!(payload, stored[..3]);
+/// ```
+pub fn integrity_check(bytes: &[u8], len: usize) -> Result<(), IntegrityError> {
+    let found = bytes.len();
+    let payload = bits::checked_bytes_for_chars(len).ok_or(IntegrityError::ByteCount { expected: usize::MAX, found })?;
+    if found < payload {
+        return Err(IntegrityError::ByteCount { expected: payload, found });
+    }
+    let needs_marker = len % 4 == 0 && len != 0 && bytes[payload - 1] & MASK_SIX_BITS == 0;
+    let expected = payload + needs_marker as usize;
+    if found > payload + 1 || (found == payload + 1 && !needs_marker && bytes[payload] != bits::TRAILING_SPACE_MARKER) {
+        return Err(IntegrityError::ByteCount { expected, found });
+    }
+    let used_bits = len * 6 % 8;
+    if used_bits != 0 && bytes[payload - 1] & (0xFF >> used_bits) != 0 {
+        return Err(IntegrityError::NonzeroPadding { offset: payload - 1 });
+    }
+    match (needs_marker, bytes.get(payload)) {
+        (true, None) => Err(IntegrityError::MissingMarker { offset: payload }),
+        (true, Some(&byte)) if byte != bits::TRAILING_SPACE_MARKER => Err(IntegrityError::InvalidMarker { offset: payload, found: byte }),
+        (false, Some(_)) => Err(IntegrityError::UnexpectedMarker { offset: payload }),
+        _ => Ok(()),
+    }
+}
+
+/// Packs a group of 4 ASCII characters into 3 bytes.
+///
+/// # Errors
+/// Returns an [`Error::InvalidCharacter`] if any character is outside the range 32-95.
+///
+/// # Examples
+///
+/// ```rust
+/// use dec_sixbit::{decode_chunk, encode_chunk};
+///
+/// let packed = encode_chunk(*b"DSK:").unwrap();
+/// assert_eq!(decode_chunk(packed), *b"DSK:");
+/// ```
+#[inline]
+pub fn encode_chunk(chars: [u8; 4]) -> Result<[u8; 3], Error> {
+    let mut packed = [0; 3];
+    encode_ascii_into(&chars, &mut packed)?;
+    Ok(packed)
+}
+
+/// Unpacks a group of 3 bytes into 4 ASCII characters.
+///
+/// Every 6-bit value is a valid character, so this cannot fail.
+#[inline]
+pub fn decode_chunk(packed: [u8; 3]) -> [u8; 4] {
+    let mut chars = [0; 4];
+    decode_into(&packed, 4, &mut chars);
+    chars
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{char_at, decode_chunk, decode_to_slice, encode_chunk, encode_to_slice, encoded_len, integrity_check, validate};
+    use crate::{Error, IntegrityError};
+
+    const INPUT: &str = "THE QUICK BROWN FOX JUMPS OVER THE LAZY DOG ";
+
+    #[test]
+    fn test_round_trip() {
+        let mut packed = [0u8; 64];
+        let mut decoded = [0u8; 64];
+        for end in 0..=INPUT.len() {
+            let input = &INPUT[..end];
+            let written = encode_to_slice(input, &mut packed).unwrap();
+            assert_eq!(written, encoded_len(end));
+            assert_eq!(decode_to_slice(&packed[..written], end, &mut decoded), Ok(input));
+            for (index, c) in input.chars().enumerate() {
+                assert_eq!(char_at(&packed[..written], end, index), Ok(c));
+            }
+        }
+    }
+
+    #[test]
+    fn test_chunks_match_slices() {
+        let mut packed = [0u8; 33];
+        encode_to_slice(INPUT, &mut packed).unwrap();
+        for (chars, group) in INPUT.as_bytes().chunks_exact(4).zip(packed.chunks_exact(3)) {
+            let chars: [u8; 4] = chars.try_into().unwrap();
+            let group: [u8; 3] = group.try_into().unwrap();
+            assert_eq!(encode_chunk(chars), Ok(group));
+            assert_eq!(decode_chunk(group), chars);
+        }
+        assert_eq!(encode_chunk(*b"ABCd"), Err(Error::InvalidCharacter));
+    }
+
+    #[test]
+    fn test_exact_fit_and_capacity() {
+        let mut packed = [0u8; 4];
+        assert_eq!(encode_to_slice("ABCDE", &mut packed), Ok(4));
+        assert_eq!(encode_to_slice("ABCDEF", &mut packed[..4]), Err(Error::TooLong { len: 6, max: 5 }));
+        assert_eq!(encode_to_slice("", &mut []), Ok(0));
+        let mut decoded = [0u8; 5];
+        assert_eq!(decode_to_slice(&packed, 5, &mut decoded), Ok("ABCDE"));
+        assert_eq!(decode_to_slice(&packed, 5, &mut decoded[..4]), Err(Error::TooLong { len: 5, max: 4 }));
+    }
+
+    #[test]
+    fn test_errors() {
+        let mut packed = [0u8; 8];
+        assert_eq!(encode_to_slice("lower", &mut packed), Err(Error::InvalidCharacter));
+        assert_eq!(encode_to_slice("ÄB", &mut packed), Err(Error::InvalidCharacter));
+        assert_eq!(decode_to_slice(&packed[..2], 4, &mut [0; 8]), Err(Error::InvalidBytesLength));
+        assert_eq!(char_at(&packed[..2], 4, 0), Err(Error::InvalidBytesLength));
+        assert_eq!(char_at(&packed[..3], 4, 4), Err(Error::OutOfBounds));
+        // The bit count of this length wraps around to 2, which would otherwise fit in one byte
+        let huge = usize::MAX / 6 + 1;
+        assert_eq!(decode_to_slice(&packed[..1], huge, &mut [0; 8]), Err(Error::InvalidBytesLength));
+        assert_eq!(char_at(&packed[..1], huge, 0), Err(Error::InvalidBytesLength));
+        assert_eq!(validate(INPUT), Ok(()));
+        assert_eq!(validate("~"), Err(Error::InvalidCharacter));
+        assert_eq!(validate("É"), Err(Error::InvalidCharacter));
+    }
+
+    #[cfg(feature = "with-struct")]
+    #[test]
+    fn test_integrity_check_valid() {
+        for end in 0..=INPUT.len() {
+            let sixbit = crate::DecSixbit::new(&INPUT[..end]).unwrap();
+            assert_eq!(integrity_check(sixbit.as_bytes(), end), Ok(()), "{end}");
+        }
+    }
+
+    #[test]
+    fn test_integrity_check_reports_each_corruption() {
+        // "HELLO" leaves 6 padding bits in its last byte; "ABC " needs a marker
+        let hello = [0xa2, 0x5b, 0x2c, 0xbc];
+        let abc = [0x86, 0x28, 0xc0, 0x03];
+        let mut packed = [0u8; 4];
+        assert_eq!(encode_to_slice("HELLO", &mut packed), Ok(4));
+        assert_eq!(packed, hello);
+        assert_eq!(encode_to_slice("ABC ", &mut packed), Ok(3));
+        assert_eq!(packed[..3], abc[..3]);
+        assert_eq!(integrity_check(&hello, 5), Ok(()));
+        assert_eq!(integrity_check(&abc, 4), Ok(()));
+
+        let mut flipped = hello;
+        flipped[3] |= 0b1;
+        assert_eq!(integrity_check(&flipped, 5), Err(IntegrityError::NonzeroPadding { offset: 3 }));
+
+        let mut changed = abc;
+        changed[3] = 0xff;
+        assert_eq!(integrity_check(&changed, 4), Err(IntegrityError::InvalidMarker { offset: 3, found: 0xff }));
+        assert_eq!(integrity_check(&abc[..3], 4), Err(IntegrityError::MissingMarker { offset: 3 }));
+        // "ABCD" ends in a letter, so a marker after it is unexpected
+        assert_eq!(integrity_check(&[0x86, 0x28, 0xe4, 0x03], 4), Err(IntegrityError::UnexpectedMarker { offset: 3 }));
+
+        assert_eq!(integrity_check(&hello[..3], 5), Err(IntegrityError::ByteCount { expected: 4, found: 3 }));
+        assert_eq!(integrity_check(&abc[..2], 4), Err(IntegrityError::ByteCount { expected: 3, found: 2 }));
+        assert_eq!(integrity_check(&[0xa2, 0x5b, 0x2c, 0xbc, 0x00], 5), Err(IntegrityError::ByteCount { expected: 4, found: 5 }));
+        assert_eq!(integrity_check(&[0x86, 0x28, 0xc0, 0x03, 0x03], 4), Err(IntegrityError::ByteCount { expected: 4, found: 5 }));
+        // The bit count of this length wraps around to 2, which would otherwise fit in one byte
+        assert_eq!(integrity_check(&[0x80], usize::MAX / 6 + 1), Err(IntegrityError::ByteCount { expected: usize::MAX, found: 1 }));
+        assert_eq!(integrity_check(&[0x03], 0), Err(IntegrityError::UnexpectedMarker { offset: 0 }));
+        assert_eq!(Error::from(IntegrityError::MissingMarker { offset: 3 }), Error::InvalidBytesLength);
+    }
+}
@@ -0,0 +1,102 @@
+//! Conversions between `DecSixbit` and [smol_str](https://docs.rs/smol_str)'s `SmolStr`.
+//!
+//! Decoding a value short enough for the inline buffer writes the characters on the stack and never
+//! touches the heap; longer values are decoded into a `String` that becomes the shared buffer.
+
+use crate::decode::decode_into;
+use crate::{DecSixbit, DecSixbitStr, Error};
+use smol_str::SmolStr;
+use std::convert::TryFrom;
+
+/// The longest string `SmolStr` stores inline, one byte short of its size.
+const INLINE_CAPACITY: usize = std::mem::size_of::<SmolStr>() - 1;
+
+impl From<&DecSixbitStr> for SmolStr {
+    /// Decodes the characters, inline when they fit.
+    fn from(sixbit: &DecSixbitStr) -> Self {
+        let len = sixbit.len();
+        if len <= INLINE_CAPACITY {
+            let mut buf = [0u8; INLINE_CAPACITY];
+            decode_into(sixbit.as_bytes(), len, &mut buf);
+            // SAFETY: decoded SIXBIT characters are always ASCII
+            SmolStr::new(unsafe { std::str::from_utf8_unchecked(&buf[..len]) })
+        } else {
+            let mut buf = vec![0u8; len];
+            decode_into(sixbit.as_bytes(), len, &mut buf);
+            // SAFETY: decoded SIXBIT characters are always ASCII
+            SmolStr::from(unsafe { String::from_utf8_unchecked(buf) })
+        }
+    }
+}
+
+impl From<&DecSixbit> for SmolStr {
+    fn from(sixbit: &DecSixbit) -> Self {
+        Self::from(sixbit.as_sixbit_str())
+    }
+}
+
+impl TryFrom<&SmolStr> for DecSixbit {
+    type Error = Error;
+
+    /// Encodes the string, returning an [`Error::InvalidCharacter`] if it contains characters outside
+    /// the SIXBIT range.
+    fn try_from(str: &SmolStr) -> Result<Self, Error> {
+        Self::new(str)
+    }
+}
+
+impl PartialEq<SmolStr> for DecSixbitStr {
+    fn eq(&self, other: &SmolStr) -> bool {
+        self == other.as_str()
+    }
+}
+
+impl PartialEq<SmolStr> for DecSixbit {
+    fn eq(&self, other: &SmolStr) -> bool {
+        self == other.as_str()
+    }
+}
+
+impl PartialEq<DecSixbitStr> for SmolStr {
+    fn eq(&self, other: &DecSixbitStr) -> bool {
+        other == self
+    }
+}
+
+impl PartialEq<DecSixbit> for SmolStr {
+    fn eq(&self, other: &DecSixbit) -> bool {
+        other == self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_alloc::count_allocations;
+    use crate::{DecSixbit, Error};
+    use smol_str::SmolStr;
+    use std::convert::TryFrom;
+
+    #[test]
+    fn test_round_trip() {
+        for input in ["", "A", "ABC ", "DSK:FOO.BAR", "ABCDEFGHIJKLMNOPQRSTUVW", "ABCDEFGHIJKLMNOPQRSTUVWX", "THE QUICK BROWN FOX JUMPS OVER THE LAZY DOG"] {
+            let sixbit = DecSixbit::new(input).unwrap();
+            let compact = SmolStr::from(&sixbit);
+            assert_eq!(compact, input);
+            assert_eq!(compact.is_heap_allocated(), input.len() > 23, "{input:?}");
+            assert_eq!(DecSixbit::try_from(&compact), Ok(sixbit.clone()));
+            assert_eq!(sixbit, compact);
+            assert_eq!(compact, sixbit);
+            assert_eq!(*sixbit.as_sixbit_str(), compact);
+            assert_eq!(compact, *sixbit.as_sixbit_str());
+        }
+        assert_eq!(DecSixbit::try_from(&SmolStr::new("lower")), Err(Error::InvalidCharacter));
+        assert!(DecSixbit::new("ABC").unwrap() != SmolStr::new("ABC "));
+    }
+
+    #[test]
+    fn test_inline_decode_does_not_allocate() {
+        let sixbit = DecSixbit::new("SYS:LOGIN ").unwrap();
+        let (compact, allocations) = count_allocations(|| SmolStr::from(&sixbit));
+        assert_eq!((compact.as_str(), allocations), ("SYS:LOGIN ", 0));
+    }
+}
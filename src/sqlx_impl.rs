@@ -0,0 +1,166 @@
+//! [sqlx](https://docs.rs/sqlx) Postgres support for `DecSixbit`.
+//!
+//! A `DecSixbit` is a TEXT value holding the decoded string, and is also accepted from VARCHAR,
+//! BPCHAR, NAME, and CITEXT columns. Wrap it in [`SixbitBytea`] to store the packed bytes as BYTEA
+//! instead. Decoding validates the value, so invalid characters surface as a decode error carrying
+//! [`Error`](crate::Error).
+//!
+//! With the `query!` family of macros, select a column with a type override such as
+//! `name AS "name: DecSixbit"`.
+
+use crate::{bits, DecSixbit};
+use sqlx::encode::IsNull;
+use sqlx::error::BoxDynError;
+use sqlx::postgres::{PgArgumentBuffer, PgHasArrayType, PgTypeInfo, PgValueRef, Postgres};
+use sqlx::{Decode, Encode, Type};
+
+/// A `DecSixbit` stored as BYTEA holding its packed bytes, about three quarters the size of TEXT.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct SixbitBytea(pub DecSixbit);
+
+impl Type<Postgres> for DecSixbit {
+    fn type_info() -> PgTypeInfo {
+        <str as Type<Postgres>>::type_info()
+    }
+
+    fn compatible(ty: &PgTypeInfo) -> bool {
+        <str as Type<Postgres>>::compatible(ty)
+    }
+}
+
+impl PgHasArrayType for DecSixbit {
+    fn array_type_info() -> PgTypeInfo {
+        <&str as PgHasArrayType>::array_type_info()
+    }
+
+    fn array_compatible(ty: &PgTypeInfo) -> bool {
+        <&str as PgHasArrayType>::array_compatible(ty)
+    }
+}
+
+impl Encode<'_, Postgres> for DecSixbit {
+    fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> Result<IsNull, BoxDynError> {
+        // Decodes straight into the argument buffer, without an intermediate `String`
+        buf.reserve(self.len());
+        buf.extend(self.chars().map(|c| c as u8));
+        Ok(IsNull::No)
+    }
+
+    fn size_hint(&self) -> usize {
+        self.len()
+    }
+}
+
+impl<'r> Decode<'r, Postgres> for DecSixbit {
+    fn decode(value: PgValueRef<'r>) -> Result<Self, BoxDynError> {
+        Ok(DecSixbit::new(value.as_str()?)?)
+    }
+}
+
+impl Type<Postgres> for SixbitBytea {
+    fn type_info() -> PgTypeInfo {
+        <[u8] as Type<Postgres>>::type_info()
+    }
+
+    fn compatible(ty: &PgTypeInfo) -> bool {
+        <[u8] as Type<Postgres>>::compatible(ty)
+    }
+}
+
+impl PgHasArrayType for SixbitBytea {
+    fn array_type_info() -> PgTypeInfo {
+        <&[u8] as PgHasArrayType>::array_type_info()
+    }
+}
+
+impl Encode<'_, Postgres> for SixbitBytea {
+    fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> Result<IsNull, BoxDynError> {
+        buf.extend_from_slice(self.0.as_bytes());
+        Ok(IsNull::No)
+    }
+
+    fn size_hint(&self) -> usize {
+        self.0.as_bytes().len()
+    }
+}
+
+impl<'r> Decode<'r, Postgres> for SixbitBytea {
+    fn decode(value: PgValueRef<'r>) -> Result<Self, BoxDynError> {
+        let bytes = <&[u8] as Decode<Postgres>>::decode(value)?;
+        Ok(SixbitBytea(DecSixbit::from_parts(bytes.to_vec(), bits::infer_len(bytes))?))
+    }
+}
+
+impl From<DecSixbit> for SixbitBytea {
+    fn from(sixbit: DecSixbit) -> Self {
+        SixbitBytea(sixbit)
+    }
+}
+
+impl From<SixbitBytea> for DecSixbit {
+    fn from(bytea: SixbitBytea) -> Self {
+        bytea.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SixbitBytea;
+    use crate::test_fixtures::INPUTS;
+    use crate::DecSixbit;
+    use sqlx::encode::IsNull;
+    use sqlx::postgres::{PgArgumentBuffer, PgTypeInfo, Postgres};
+    use sqlx::{Encode, Type};
+
+    fn encoded<'q, T: Encode<'q, Postgres>>(value: T) -> Vec<u8> {
+        let mut buf = PgArgumentBuffer::default();
+        assert!(matches!(value.encode_by_ref(&mut buf).unwrap(), IsNull::No));
+        buf.to_vec()
+    }
+
+    #[test]
+    fn test_type_info() {
+        assert_eq!(<DecSixbit as Type<Postgres>>::type_info(), PgTypeInfo::with_name("TEXT"));
+        assert!(<DecSixbit as Type<Postgres>>::compatible(&PgTypeInfo::with_name("VARCHAR")));
+        assert!(!<DecSixbit as Type<Postgres>>::compatible(&PgTypeInfo::with_name("BYTEA")));
+        assert_eq!(<SixbitBytea as Type<Postgres>>::type_info(), PgTypeInfo::with_name("BYTEA"));
+    }
+
+    #[test]
+    fn test_encode_matches_str() {
+        for input in INPUTS {
+            let sixbit = DecSixbit::new(input).unwrap();
+            assert_eq!(encoded(&sixbit), encoded(input));
+            assert_eq!(encoded(SixbitBytea(sixbit.clone())), encoded(sixbit.as_bytes()));
+        }
+    }
+
+    /// Runs against the database in `DEC_SIXBIT_TEST_POSTGRES_URL`, and is skipped when it isn't set.
+    #[tokio::test]
+    async fn test_live_round_trip() {
+        use sqlx::{Connection, PgConnection, Row};
+
+        let Ok(url) = std::env::var("DEC_SIXBIT_TEST_POSTGRES_URL") else {
+            return;
+        };
+        let mut conn = PgConnection::connect(&url).await.unwrap();
+        for input in INPUTS {
+            let sixbit = DecSixbit::new(input).unwrap();
+            let row = sqlx::query("SELECT $1::TEXT AS text, $2::BYTEA AS bytea, $1::VARCHAR AS varchar")
+                .bind(&sixbit)
+                .bind(SixbitBytea(sixbit.clone()))
+                .fetch_one(&mut conn)
+                .await
+                .unwrap();
+            assert_eq!(row.get::<DecSixbit, _>("text"), sixbit);
+            assert_eq!(row.get::<SixbitBytea, _>("bytea").0, sixbit);
+            assert_eq!(row.get::<DecSixbit, _>("varchar"), sixbit);
+            assert_eq!(row.get::<Option<DecSixbit>, _>("text"), Some(sixbit));
+        }
+
+        let row = sqlx::query("SELECT 'hello'::TEXT AS text, NULL::TEXT AS null").fetch_one(&mut conn).await.unwrap();
+        let error = row.try_get::<DecSixbit, _>("text").unwrap_err();
+        assert!(matches!(error, sqlx::Error::ColumnDecode { .. }), "{error}");
+        assert_eq!(row.get::<Option<DecSixbit>, _>("null"), None);
+    }
+}
@@ -0,0 +1,266 @@
+//! Incremental encoder and decoder state machines for streaming SIXBIT data.
+//!
+//! Four characters pack into a 3-byte group, so input that arrives in arbitrarily small pieces has to
+//! carry an incomplete group from one piece to the next. [`SixbitEncoder`] and [`SixbitDecoder`] hold
+//! that carry and hand back each group once it is complete, leaving the buffering and I/O to the caller.
+//! The I/O adapters, such as the `embedded-io` ones, are thin loops around them.
+
+use crate::decode::decode_into;
+use crate::encode::encode_ascii_into;
+use crate::{bits, is_sixbit_char, Error};
+
+/// Packs characters fed one at a time into 3-byte groups.
+///
+/// The bytes it produces, concatenated, are the same as [`encode_to_slice`](crate::encode_to_slice)
+/// writes for the whole input.
+///
+/// # Examples
+///
+/// ```rust
+/// use dec_sixbit::{encode_to_slice, SixbitEncoder};
+///
+/// let mut encoder = SixbitEncoder::new();
+/// let mut bytes = Vec::new();
+/// for &c in b"HELLO" {
+///     if let Some(group) = encoder.push(c).unwrap() {
+///         bytes.extend_from_slice(&group);
+///     }
+/// }
+/// let (tail, tail_len) = encoder.finish();
+/// bytes.extend_from_slice(&tail[..tail_len]);
+/// let mut expected = [0u8; 4];
+/// encode_to_slice("HELLO", &mut expected).unwrap();
+/// assert_eq!((&bytes[..], encoder.len()), (&expected[..], 5));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct SixbitEncoder {
+    /// The characters of the incomplete group, as ASCII
+    pending: [u8; 4],
+    /// The number of characters in `pending`
+    pending_len: u8,
+    /// The number of characters accepted so far
+    len: usize,
+}
+
+impl SixbitEncoder {
+    /// Creates an encoder with no characters.
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            pending: [0; 4],
+            pending_len: 0,
+            len: 0,
+        }
+    }
+
+    /// Adds the ASCII character `c`.
+    ///
+    /// # Returns
+    /// The packed group if `c` completed one, or `None` if it is still being filled.
+    ///
+    /// # Errors
+    /// Returns an [`Error::InvalidCharacter`] if `c` is outside the range 32-95, in which case the
+    /// encoder is left unchanged.
+    #[inline]
+    pub fn push(&mut self, c: u8) -> Result<Option<[u8; 3]>, Error> {
+        if !is_sixbit_char(c as char) {
+            return Err(Error::InvalidCharacter);
+        }
+        self.pending[self.pending_len as usize] = c;
+        self.pending_len += 1;
+        self.len += 1;
+        if self.pending_len < 4 {
+            return Ok(None);
+        }
+        self.pending_len = 0;
+        let mut group = [0; 3];
+        encode_ascii_into(&self.pending, &mut group)?;
+        Ok(Some(group))
+    }
+
+    /// Returns the number of characters accepted so far.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if no characters have been accepted.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Packs the characters of the incomplete group, if any.
+    ///
+    /// # Returns
+    /// A tuple of a buffer and the number of bytes at its start, 0 to 3, that end the encoded output.
+    #[inline]
+    pub fn finish(&self) -> ([u8; 3], usize) {
+        let chars = &self.pending[..self.pending_len as usize];
+        let mut tail = [0; 3];
+        // The characters were validated as they were pushed
+        let _ = encode_ascii_into(chars, &mut tail);
+        (tail, (chars.len() * 3).div_ceil(4))
+    }
+}
+
+/// Unpacks a known number of characters from packed bytes fed one at a time.
+///
+/// The length has to be known up front because 3 characters and 4 characters both take 3 bytes.
+///
+/// # Examples
+///
+/// ```rust
+/// use dec_sixbit::{encode_to_slice, SixbitDecoder};
+///
+/// let mut bytes = [0u8; 4];
+/// encode_to_slice("HELLO", &mut bytes).unwrap();
+/// let mut decoder = SixbitDecoder::new(5).unwrap();
+/// let mut decoded = String::new();
+/// for &byte in &bytes {
+///     if let Some((chars, count)) = decoder.push(byte) {
+///         decoded.push_str(std::str::from_utf8(&chars[..count]).unwrap());
+///     }
+/// }
+/// assert!(decoder.is_finished());
+/// assert_eq!(decoded, "HELLO");
+/// ```
+#[derive(Debug, Clone)]
+pub struct SixbitDecoder {
+    /// The bytes of the incomplete group
+    pending: [u8; 3],
+    /// The number of bytes in `pending`
+    pending_len: u8,
+    /// The number of characters not yet decoded
+    remaining: usize,
+}
+
+impl SixbitDecoder {
+    /// Creates a decoder for `len` characters.
+    ///
+    /// # Errors
+    /// Returns an [`Error::InvalidBytesLength`] if the number of bytes for `len` characters overflows `usize`.
+    #[inline]
+    pub const fn new(len: usize) -> Result<Self, Error> {
+        if bits::checked_bytes_for_chars(len).is_none() {
+            return Err(Error::InvalidBytesLength);
+        }
+        Ok(Self {
+            pending: [0; 3],
+            pending_len: 0,
+            remaining: len,
+        })
+    }
+
+    /// Adds the next packed byte.
+    ///
+    /// # Returns
+    /// A tuple of the decoded ASCII characters and how many of them, 1 to 4, are valid if `byte`
+    /// completed a group, or `None` if the group is still being filled or every character has already
+    /// been decoded.
+    #[inline]
+    pub fn push(&mut self, byte: u8) -> Option<([u8; 4], usize)> {
+        if self.remaining == 0 {
+            return None;
+        }
+        self.pending[self.pending_len as usize] = byte;
+        self.pending_len += 1;
+        let count = self.remaining.min(4);
+        if (self.pending_len as usize) < (count * 6).div_ceil(8) {
+            return None;
+        }
+        let mut chars = [0; 4];
+        decode_into(&self.pending[..self.pending_len as usize], count, &mut chars);
+        self.pending_len = 0;
+        self.remaining -= count;
+        Some((chars, count))
+    }
+
+    /// Returns the number of packed bytes still expected.
+    #[inline]
+    pub fn bytes_remaining(&self) -> usize {
+        (self.remaining * 6).div_ceil(8) - self.pending_len as usize
+    }
+
+    /// Returns `true` once every character has been decoded.
+    #[inline]
+    pub fn is_finished(&self) -> bool {
+        self.remaining == 0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{SixbitDecoder, SixbitEncoder};
+    use crate::{decode_to_slice, encode_to_slice, Error};
+
+    fn encode_streaming(input: &str) -> Result<(Vec<u8>, usize), Error> {
+        let mut encoder = SixbitEncoder::new();
+        let mut bytes = Vec::new();
+        for &c in input.as_bytes() {
+            if let Some(group) = encoder.push(c)? {
+                bytes.extend_from_slice(&group);
+            }
+        }
+        let (tail, tail_len) = encoder.finish();
+        bytes.extend_from_slice(&tail[..tail_len]);
+        Ok((bytes, encoder.len()))
+    }
+
+    fn decode_streaming(bytes: &[u8], len: usize) -> String {
+        let mut decoder = SixbitDecoder::new(len).unwrap();
+        let mut decoded = String::new();
+        for &byte in bytes {
+            assert!(decoder.bytes_remaining() > 0);
+            if let Some((chars, count)) = decoder.push(byte) {
+                decoded.push_str(std::str::from_utf8(&chars[..count]).unwrap());
+            }
+        }
+        assert!(decoder.is_finished());
+        assert_eq!(decoder.bytes_remaining(), 0);
+        decoded
+    }
+
+    #[test]
+    fn test_matches_one_shot() {
+        let input = "THE QUICK BROWN FOX JUMPS OVER THE LAZY DOG ";
+        for end in 0..=input.len() {
+            let input = &input[..end];
+            let mut bytes = [0u8; 33];
+            let written = encode_to_slice(input, &mut bytes).unwrap();
+            let bytes = &bytes[..written];
+            assert_eq!(encode_streaming(input), Ok((bytes.to_vec(), end)), "{input:?}");
+            assert_eq!(decode_streaming(bytes, end), decode_to_slice(bytes, end, &mut [0; 44]).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_invalid_character_leaves_encoder_unchanged() {
+        let mut encoder = SixbitEncoder::new();
+        assert_eq!(encoder.push(b'A'), Ok(None));
+        assert_eq!(encoder.push(b'a'), Err(Error::InvalidCharacter));
+        assert_eq!(encoder.push(0xC3), Err(Error::InvalidCharacter));
+        assert_eq!(encoder.len(), 1);
+        assert_eq!(encoder.push(b'B'), Ok(None));
+        let (tail, tail_len) = encoder.finish();
+        let mut expected = [0u8; 2];
+        encode_to_slice("AB", &mut expected).unwrap();
+        assert_eq!(&tail[..tail_len], &expected);
+    }
+
+    #[test]
+    fn test_decoder_ignores_extra_bytes() {
+        let mut decoder = SixbitDecoder::new(1).unwrap();
+        assert!(decoder.push(0b0000_0100).is_some());
+        assert_eq!(decoder.push(0xFF), None);
+        assert!(SixbitDecoder::new(0).unwrap().is_finished());
+    }
+
+    #[test]
+    fn test_decoder_length_overflow() {
+        // The bit count of this length wraps around to 2, which would otherwise need one byte
+        assert_eq!(SixbitDecoder::new(usize::MAX / 6 + 1).err(), Some(Error::InvalidBytesLength));
+        let decoder = SixbitDecoder::new(usize::MAX / 6).unwrap();
+        assert_eq!(decoder.bytes_remaining(), (usize::MAX / 6 * 6).div_ceil(8));
+    }
+}
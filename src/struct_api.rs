@@ -8,22 +8,116 @@
 //! - Implements common traits for ease of use.
 //! - Provides both encoding and decoding functionalities.
 
-use crate::{encode::encode, decode::decode_unchecked, Error};
-use std::fmt;
+use crate::{bits, display, encode::{encode, encode_into}, Chars, DecSixbitStr, Error, SixbitChar, ASCII_OFFSET};
+use alloc::borrow::Cow;
+use alloc::boxed::Box;
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::ops::Deref;
 
 /// The `DecSixbit` struct stores the encoded bytes and provides methods
 /// for accessing the encoded data and retrieving the original string.
-#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+///
+/// `DecSixbit` dereferences to [`DecSixbitStr`], which provides the read-only API.
+///
+/// # Storage format
+///
+/// [`as_bytes`](Self::as_bytes) returns the characters packed 6 bits each, most significant bit first,
+/// as SIXBIT values (ASCII code minus 32). Every 3 bytes hold 4 characters; a partial final group
+/// takes 1 or 2 bytes for 1 or 2 characters and 3 bytes for 3, with the unused low bits zero.
+///
+/// Since a space is value 0, the bytes of a value whose length is a multiple of 4 and whose last
+/// character is a space are indistinguishable from a 3-character tail. Such values are followed by one
+/// extra byte, [`TRAILING_SPACE_MARKER`](Self::TRAILING_SPACE_MARKER), and no other value has it. The
+/// length is therefore always recoverable from the bytes alone:
+///
+/// | Bytes | Characters |
+/// |---|---|
+/// | `3n`, last 6 bits nonzero | `4n` |
+/// | `3n`, last 6 bits zero | `4n - 1` |
+/// | `3n + 1`, last byte is the marker | `4n` |
+/// | `3n + 1`, otherwise | `4n + 1` |
+/// | `3n + 2` | `4n + 2` |
+///
+/// [`try_from_slice_strict`](Self::try_from_slice_strict) accepts exactly this format, while
+/// [`try_from_slice`](Self::try_from_slice) also accepts and normalizes an unneeded marker and nonzero
+/// padding bits.
+///
+/// # Memory layout
+///
+/// A `DecSixbit` is 24 bytes on 64-bit targets. Values of up to 19 characters are stored inline
+/// without allocating; longer values hold their bytes in a `Box<[u8]>` rather than a `Vec<u8>`,
+/// which saves the capacity word, and values created by [`new`](Self::new) or
+/// [`try_from_slice`](Self::try_from_slice) allocate exactly the encoded length. The representation
+/// is never observable: equality, hashing, ordering, and serialization only see [`as_bytes`](Self::as_bytes).
+///
+/// With the `cache` feature, each value also holds the decoded string once `as_decoded`
+/// has been called, which makes it 48 bytes. The cache is ignored by `Hash` and `Eq`, so values remain
+/// sound map keys; Clippy's `mutable_key_type` lint can't tell, and is silenced by listing
+/// `dec_sixbit::DecSixbit` under `ignore-interior-mutability` in `clippy.toml`.
+#[derive(Clone)]
 pub struct DecSixbit {
-    /// Original string length
-    pub(crate) len: usize,
-    /// Packed bytes where every 3 bytes contain 4 characters (6 bits each)
-    pub(crate) bytes: Vec<u8>,
+    repr: Repr,
+    /// The decoded string, filled on first use and cleared by every mutation
+    #[cfg(feature = "cache")]
+    cache: std::sync::OnceLock<Box<str>>,
+}
+
+/// Number of packed bytes stored inline, the most that keeps `DecSixbit` at 24 bytes.
+const INLINE_CAPACITY: usize = 15;
+
+/// Storage for the packed bytes: every 3 bytes contain 4 characters (6 bits each), followed by the
+/// marker and any reserved capacity. Bytes past [`DecSixbit::as_bytes`] are always zero.
+#[derive(Clone)]
+enum Repr {
+    Inline {
+        /// Original string length, at most 19
+        len: u8,
+        bytes: [u8; INLINE_CAPACITY],
+    },
+    Heap {
+        /// Original string length
+        len: usize,
+        bytes: Box<[u8]>,
+    },
+}
+
+#[cfg(all(target_pointer_width = "64", not(feature = "cache")))]
+const _: () = assert!(core::mem::size_of::<DecSixbit>() == 24);
+#[cfg(all(target_pointer_width = "64", feature = "cache"))]
+const _: () = assert!(core::mem::size_of::<DecSixbit>() == 48);
+
+impl Default for DecSixbit {
+    fn default() -> Self {
+        Self::from_repr(Repr::Inline {
+            len: 0,
+            bytes: [0; INLINE_CAPACITY],
+        })
+    }
 }
 
 impl DecSixbit {
-    /// The marker byte for trailing spaces in the last block is added when the length is a multiple of 4, and the last 6 bits are all zero.
-    const TRAILING_SPACE_MARKER: u8 = 0b11;
+    /// The byte appended to the packed bytes of a value whose length is a multiple of 4 and whose last
+    /// character is a space, so that its length can be told apart from a 3-character tail.
+    ///
+    /// See the [storage format](Self#storage-format).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use dec_sixbit::DecSixbit;
+    ///
+    /// let sixbit = DecSixbit::new("ABC ").unwrap();
+    /// assert_eq!(sixbit.as_bytes().last(), Some(&DecSixbit::TRAILING_SPACE_MARKER));
+    /// ```
+    pub const TRAILING_SPACE_MARKER: u8 = bits::TRAILING_SPACE_MARKER;
+
+    /// The version byte that starts the output of [`DecSixbitStr::to_framed_bytes`].
+    pub const FRAME_VERSION: u8 = 1;
 
     /// Creates a new DecSixbit instance by encoding the input string.
     /// Only accepts ASCII characters in the range 32-95 (space through underscore).
@@ -44,263 +138,1878 @@ impl DecSixbit {
     /// ```
     #[inline(always)]
     pub fn new(str: &str) -> Result<Self, Error> {
-        let (mut bytes, len) = encode(str)?;
+        if Self::bytes_with_marker_for_chars(str.len()) <= INLINE_CAPACITY {
+            // Pack short values straight into the inline buffer to avoid a temporary allocation
+            let mut bytes = [0; INLINE_CAPACITY];
+            encode_into(str, &mut bytes)?;
+            let mut sixbit = Self::from_repr(Repr::Inline {
+                len: str.len() as u8,
+                bytes,
+            });
+            sixbit.set_marker();
+            return Ok(sixbit);
+        }
+        let (bytes, len) = encode(str)?;
+        Ok(Self::from_encoded(bytes, len))
+    }
+
+    /// Wraps freshly packed bytes, appending the `TRAILING_SPACE_MARKER` when it is needed.
+    #[inline(always)]
+    pub(crate) fn from_encoded(mut bytes: Vec<u8>, len: usize) -> Self {
         // Check if TRAILING_SPACE_MARKER needs to be added
         if len % 4 == 0 && len != 0 && (bytes.last().unwrap() & 0b111111) == 0 {
             bytes.push(Self::TRAILING_SPACE_MARKER);
         }
-        Ok(Self { bytes, len })
+        Self::from_buffer(bytes, len)
     }
 
-    /// Returns a reference to the encoded SIXBIT bytes.
+    /// Wraps packed bytes without the marker, as returned by [`encode`], checking that they hold exactly
+    /// `len` characters with zeroed padding bits.
+    pub(crate) fn from_payload(bytes: Vec<u8>, len: usize) -> Result<Self, Error> {
+        if bits::checked_bytes_for_chars(len) != Some(bytes.len()) {
+            return Err(Error::InvalidBytesLength);
+        }
+        let sixbit = Self::from_encoded(bytes, len);
+        bits::check_canonical(sixbit.as_bytes(), len)?;
+        Ok(sixbit)
+    }
+
+    /// Wraps a buffer of packed bytes, marker, and zeroed capacity, storing it inline if it fits.
     ///
-    /// # Returns
-    /// A slice of bytes containing the SIXBIT-encoded data.
+    /// Accepts both `Vec<u8>`, which is moved to the heap without copying, and `&[u8]`.
+    pub(crate) fn from_buffer<B: AsRef<[u8]> + Into<Box<[u8]>>>(bytes: B, len: usize) -> Self {
+        let slice = bytes.as_ref();
+        // The inline buffer must also have room for a marker the content could need, so a length whose
+        // payload fills all of it goes to the heap even when the marker is absent
+        let marker = (len % 4 == 0 && len != 0) as usize;
+        let repr = if slice.len() <= INLINE_CAPACITY && bits::bytes_for_chars(len) + marker <= INLINE_CAPACITY {
+            // A length whose byte count wrapped around can pass the size check, so this must hold in release too
+            assert!(len <= u8::MAX as usize, "inline length {len} out of range");
+            let mut inline = [0; INLINE_CAPACITY];
+            inline[..slice.len()].copy_from_slice(slice);
+            Repr::Inline {
+                len: len as u8,
+                bytes: inline,
+            }
+        } else {
+            Repr::Heap {
+                len,
+                bytes: bytes.into(),
+            }
+        };
+        Self::from_repr(repr)
+    }
+
+    /// Wraps storage, with an empty cache when the `cache` feature is enabled.
+    #[inline(always)]
+    fn from_repr(repr: Repr) -> Self {
+        Self {
+            repr,
+            #[cfg(feature = "cache")]
+            cache: std::sync::OnceLock::new(),
+        }
+    }
+
+    /// Returns the whole buffer, including reserved capacity.
+    #[inline(always)]
+    fn buf(&self) -> &[u8] {
+        match &self.repr {
+            Repr::Inline { bytes, .. } => bytes,
+            Repr::Heap { bytes, .. } => bytes,
+        }
+    }
+
+    /// Returns the number of bytes the value owns on the heap: the buffer with its reserved capacity
+    /// when it isn't stored inline, plus the decoded string once cached.
+    #[cfg(any(feature = "allocative", feature = "deepsize"))]
+    pub(crate) fn heap_size(&self) -> usize {
+        let buf = match &self.repr {
+            Repr::Inline { .. } => 0,
+            Repr::Heap { bytes, .. } => bytes.len(),
+        };
+        #[cfg(feature = "cache")]
+        let buf = buf + self.cache.get().map_or(0, |decoded| decoded.len());
+        buf
+    }
+
+    /// Returns the whole buffer mutably, including reserved capacity.
+    ///
+    /// Every mutation goes through this or [`set_len`](Self::set_len), so both clear the cache.
+    #[inline(always)]
+    fn buf_mut(&mut self) -> &mut [u8] {
+        #[cfg(feature = "cache")]
+        self.cache.take();
+        match &mut self.repr {
+            Repr::Inline { bytes, .. } => bytes,
+            Repr::Heap { bytes, .. } => bytes,
+        }
+    }
+
+    /// Sets the number of characters. The caller must ensure the buffer is large enough.
+    #[inline(always)]
+    fn set_len(&mut self, new_len: usize) {
+        #[cfg(feature = "cache")]
+        self.cache.take();
+        match &mut self.repr {
+            Repr::Inline { len, .. } => {
+                assert!(new_len <= u8::MAX as usize, "inline length {new_len} out of range");
+                *len = new_len as u8;
+            }
+            Repr::Heap { len, .. } => *len = new_len,
+        }
+    }
+
+    /// Moves the contents into a buffer of exactly `size` bytes, which must not be less than the encoded length.
+    fn reallocate(&mut self, size: usize) {
+        let len = self.len();
+        let mut bytes = match &mut self.repr {
+            Repr::Inline { bytes, .. } => bytes.to_vec(),
+            Repr::Heap { bytes, .. } => core::mem::take(bytes).into_vec(),
+        };
+        bytes.resize(size, 0);
+        *self = Self::from_buffer(bytes, len);
+    }
+
+    /// Returns `true` if the packed bytes for the current length need the `TRAILING_SPACE_MARKER`.
+    #[inline(always)]
+    fn needs_marker(&self) -> bool {
+        let len = self.len();
+        len % 4 == 0 && len != 0 && (self.buf()[bits::bytes_for_chars(len) - 1] & 0b111111) == 0
+    }
+
+    /// Returns `true` if the `TRAILING_SPACE_MARKER` follows the packed bytes.
+    ///
+    /// Bytes past the payload are zero unless they hold the marker, so this only looks at the next byte.
+    #[inline(always)]
+    fn has_marker(&self) -> bool {
+        self.buf().get(bits::bytes_for_chars(self.len())) == Some(&Self::TRAILING_SPACE_MARKER)
+    }
+
+    /// Returns the number of meaningful bytes, including the `TRAILING_SPACE_MARKER` when present.
+    #[inline(always)]
+    fn encoded_len(&self) -> usize {
+        bits::bytes_for_chars(self.len()) + self.has_marker() as usize
+    }
+
+    /// Removes the `TRAILING_SPACE_MARKER` before the characters are modified in place.
+    fn clear_marker(&mut self) {
+        if self.has_marker() {
+            let index = bits::bytes_for_chars(self.len());
+            self.buf_mut()[index] = 0;
+        }
+    }
+
+    /// Writes the `TRAILING_SPACE_MARKER` after the characters have been modified in place, if needed.
+    fn set_marker(&mut self) {
+        if self.needs_marker() {
+            let index = bits::bytes_for_chars(self.len());
+            // An edit can make a value that was shrunk to its payload need a marker it has no room for
+            if index >= self.buf().len() {
+                self.reallocate(index + 1);
+            }
+            self.buf_mut()[index] = Self::TRAILING_SPACE_MARKER;
+        }
+    }
+
+    /// Grows the buffer to at least `required` bytes, zero-filling the new space.
+    fn grow_to(&mut self, required: usize) {
+        let size = self.buf().len();
+        if required > size {
+            // Grow geometrically so repeated pushes stay amortized O(1)
+            self.reallocate(required.max(size * 2));
+        }
+    }
+
+    /// Creates a new `DecSixbit` of exactly `width` characters, holding `str` padded with trailing spaces.
+    ///
+    /// # Parameters
+    /// - `str`: The field content. Must contain only ASCII characters in the range 32-95.
+    /// - `width`: The number of characters in the resulting field.
+    ///
+    /// # Errors
+    /// - Returns an [`Error::InvalidCharacter`] if `str` contains invalid characters.
+    /// - Returns an [`Error::TooLong`] if `str` has more than `width` characters.
     ///
     /// # Examples
     ///
     /// ```rust
-    /// use dec_sixbit::DecSixbit;
+    /// use dec_sixbit::{DecSixbit, Error};
     ///
-    /// let sixbit = DecSixbit::new("HELLO").unwrap();
-    /// let encoded = sixbit.as_bytes();
+    /// let field = DecSixbit::from_str_padded("DSK", 6).unwrap();
+    /// assert_eq!(field.to_string(), "DSK   ");
+    /// assert_eq!(DecSixbit::from_str_padded("SYSTEM", 3), Err(Error::TooLong { len: 6, max: 3 }));
     /// ```
-    #[inline(always)]
-    pub fn as_bytes(&self) -> &[u8] {
-        &self.bytes
+    pub fn from_str_padded(str: &str, width: usize) -> Result<Self, Error> {
+        Self::from_str_padded_at(str, width, 0)
     }
 
-    /// Returns the length of the original input string.
+    /// Creates a new `DecSixbit` of exactly `width` characters, holding `str` right-justified and padded
+    /// with leading spaces.
     ///
-    /// # Returns
-    /// The number of characters in the original string before encoding.
+    /// # Parameters
+    /// - `str`: The field content. Must contain only ASCII characters in the range 32-95.
+    /// - `width`: The number of characters in the resulting field.
+    ///
+    /// # Errors
+    /// - Returns an [`Error::InvalidCharacter`] if `str` contains invalid characters.
+    /// - Returns an [`Error::TooLong`] if `str` has more than `width` characters.
     ///
     /// # Examples
     ///
     /// ```rust
     /// use dec_sixbit::DecSixbit;
     ///
-    /// let sixbit = DecSixbit::new("HELLO").unwrap();
-    /// assert_eq!(sixbit.len(), 5);
+    /// let field = DecSixbit::from_str_padded_left("12.5", 6).unwrap();
+    /// assert_eq!(field.to_string(), "  12.5");
     /// ```
-    #[inline(always)]
-    pub fn len(&self) -> usize {
-        self.len
+    pub fn from_str_padded_left(str: &str, width: usize) -> Result<Self, Error> {
+        Self::from_str_padded_at(str, width, width.saturating_sub(str.len()))
     }
 
-    /// Checks if the encoded SIXBIT data is empty.
+    /// Packs `str` into a new value of `width` characters starting at `start`, with spaces elsewhere.
+    fn from_str_padded_at(str: &str, width: usize, start: usize) -> Result<Self, Error> {
+        if !str.bytes().all(|b| (ASCII_OFFSET..=95).contains(&b)) {
+            return Err(Error::InvalidCharacter);
+        }
+        if str.len() > width {
+            return Err(Error::TooLong { len: str.len(), max: width });
+        }
+        let mut bytes = vec![0; bits::bytes_for_chars(width)];
+        for (index, b) in (start..).zip(str.bytes()) {
+            bits::set_value(&mut bytes, index, b - ASCII_OFFSET);
+        }
+        Ok(Self::from_encoded(bytes, width))
+    }
+
+    /// Creates a new `DecSixbit` holding `value` in decimal, right-justified and space-padded to `width` characters.
     ///
-    /// # Returns
-    /// `true` if the original input string was empty, otherwise `false`.
+    /// # Parameters
+    /// - `value`: The number to render.
+    /// - `width`: The number of characters in the resulting field.
+    ///
+    /// # Errors
+    /// Returns an [`Error::FieldOverflow`] if `value` has more than `width` digits.
     ///
     /// # Examples
     ///
     /// ```rust
     /// use dec_sixbit::DecSixbit;
     ///
-    /// let sixbit = DecSixbit::new("").unwrap();
-    /// assert!(sixbit.is_empty());
+    /// let field = DecSixbit::from_uint_padded(42, 6).unwrap();
+    /// assert_eq!(field.to_string(), "    42");
     /// ```
-    #[inline(always)]
-    pub fn is_empty(&self) -> bool {
-        self.len == 0
+    pub fn from_uint_padded(value: u64, width: usize) -> Result<Self, Error> {
+        Self::from_uint_radix_padded(value, 10, width)
     }
 
-    /// Attempts to create a `DecSixbit` instance from a slice of encoded bytes.
+    /// Creates a new `DecSixbit` holding `value` in octal, right-justified and space-padded to `width` characters.
     ///
     /// # Parameters
-    /// - `bytes`: A slice of bytes containing SIXBIT-encoded data.
+    /// - `value`: The number to render.
+    /// - `width`: The number of characters in the resulting field.
     ///
-    /// # Returns
-    /// - `Ok(Self)` if the slice is successfully parsed.
-    /// - `Err(Error)` if the slice has an invalid format or contains invalid data.
-    #[inline(always)]
-    pub fn try_from_slice(bytes: &[u8]) -> Result<Self, Error> {
-        let num_full_blocks = bytes.len() / 3;
-        let num_remain_bytes = bytes.len() % 3;
-
-        let len = match num_remain_bytes {
-            0 => num_full_blocks * 4,
-            1 => {
-                if bytes.last().unwrap() == &Self::TRAILING_SPACE_MARKER {
-                    num_full_blocks * 4
-                } else {
-                    num_full_blocks * 4 + 1
-                }
-            },
-            2 => num_full_blocks * 4 + 2,
-            _ => unreachable!(),
-        };
-        Ok(Self {
-            len,
-            bytes: bytes.to_vec(),
-        })
-    }
-
-    /// Creates a `DecSixbit` instance from a slice of encoded bytes.
+    /// # Errors
+    /// Returns an [`Error::FieldOverflow`] if `value` has more than `width` octal digits.
     ///
-    /// # Parameters
-    /// - `bytes`: A slice of bytes containing SIXBIT-encoded data.
+    /// # Examples
     ///
-    /// # Panics
-    /// - Panics if the slice has an invalid format or contains invalid data.
-    #[inline(always)]
-    pub fn from_slice(bytes: &[u8]) -> Self {
-        Self::try_from_slice(bytes).unwrap()
+    /// ```rust
+    /// use dec_sixbit::DecSixbit;
+    ///
+    /// let field = DecSixbit::from_uint_octal_padded(0o777, 6).unwrap();
+    /// assert_eq!(field.to_string(), "   777");
+    /// ```
+    pub fn from_uint_octal_padded(value: u64, width: usize) -> Result<Self, Error> {
+        Self::from_uint_radix_padded(value, 8, width)
+    }
+
+    fn from_uint_radix_padded(value: u64, radix: u64, width: usize) -> Result<Self, Error> {
+        let mut buf = [0u8; UINT_DIGITS_MAX];
+        let digits = uint_digits(value, radix, &mut buf);
+        if digits.len() > width {
+            return Err(Error::FieldOverflow);
+        }
+        let mut bytes = vec![0u8; bits::bytes_for_chars(width)];
+        for (i, &digit) in digits.iter().enumerate() {
+            bits::set_value(&mut bytes, width - digits.len() + i, digit - ASCII_OFFSET);
+        }
+        Ok(Self::from_encoded(bytes, width))
     }
 
-    /// Gets the character at the specified position.
+    /// Overwrites the characters in `range` with `value` in decimal, right-justified and space-padded.
+    ///
+    /// Characters outside `range` are left untouched.
     ///
     /// # Parameters
-    /// - `index`: The position of the character to retrieve.
+    /// - `range`: The character positions of the field.
+    /// - `value`: The number to render.
     ///
-    /// # Returns
-    /// An `Option<char>` which is `Some(char)` if the index is valid, or `None` otherwise.
+    /// # Errors
+    /// - Returns an [`Error::OutOfBounds`] if `range` is not within the value.
+    /// - Returns an [`Error::FieldOverflow`] if `value` has more digits than `range` is wide.
+    ///
+    /// The value is left unchanged on error.
     ///
     /// # Examples
     ///
     /// ```rust
     /// use dec_sixbit::DecSixbit;
     ///
-    /// let sixbit = DecSixbit::new("HELLO").unwrap();
-    /// assert_eq!(sixbit.get(1), Some('E'));
-    /// assert_eq!(sixbit.get(5), None);
+    /// let mut record = DecSixbit::new("DSKB  0000").unwrap();
+    /// record.overwrite_field(6..10, 17).unwrap();
+    /// assert_eq!(record.to_string(), "DSKB    17");
     /// ```
-    pub fn get(&self, index: usize) -> Option<char> {
-        self.to_string().chars().nth(index)
+    pub fn overwrite_field(&mut self, range: core::ops::Range<usize>, value: u64) -> Result<(), Error> {
+        if range.start > range.end || range.end > self.len() {
+            return Err(Error::OutOfBounds);
+        }
+        let mut buf = [0u8; UINT_DIGITS_MAX];
+        let digits = uint_digits(value, 10, &mut buf);
+        let width = range.end - range.start;
+        if digits.len() > width {
+            return Err(Error::FieldOverflow);
+        }
+        let padding = width - digits.len();
+        self.clear_marker();
+        for (i, index) in range.enumerate() {
+            let value = if i < padding { 0 } else { digits[i - padding] - ASCII_OFFSET };
+            bits::set_value(self.buf_mut(), index, value);
+        }
+        self.set_marker();
+        Ok(())
     }
 
-    /// Checks if the string starts with the given prefix.
+    /// Replaces the character at `index` with `c`, rewriting only its 6-bit field.
     ///
     /// # Parameters
-    /// - `prefix`: The prefix string to check.
+    /// - `index`: The position of the character to replace.
+    /// - `c`: The new character. Must be an ASCII character in the range 32-95.
     ///
-    /// # Returns
-    /// `true` if the string starts with the given prefix, otherwise `false`.
+    /// # Errors
+    /// - Returns an [`Error::OutOfBounds`] if `index` is not less than the length.
+    /// - Returns an [`Error::InvalidCharacter`] if `c` is not a valid SIXBIT character.
+    ///
+    /// The value is left unchanged on error.
     ///
     /// # Examples
     ///
     /// ```rust
     /// use dec_sixbit::DecSixbit;
     ///
-    /// let sixbit = DecSixbit::new("HELLO").unwrap();
-    /// assert!(sixbit.starts_with("HE"));
-    /// assert!(!sixbit.starts_with("EL"));
+    /// let mut record = DecSixbit::new("JOB1 R").unwrap();
+    /// record.set_char(5, 'D').unwrap();
+    /// assert_eq!(record.to_string(), "JOB1 D");
     /// ```
-    pub fn starts_with<P: AsRef<str>>(&self, prefix: P) -> bool {
-        self.to_string().starts_with(prefix.as_ref())
+    pub fn set_char(&mut self, index: usize, c: char) -> Result<(), Error> {
+        if index >= self.len() {
+            return Err(Error::OutOfBounds);
+        }
+        let c = SixbitChar::try_from(c)?;
+        // Changing the last character can change whether the marker is needed
+        self.clear_marker();
+        bits::set_value(self.buf_mut(), index, c.as_value());
+        self.set_marker();
+        Ok(())
     }
 
-    /// Checks if the string ends with the given suffix.
+    /// Swaps the characters at `i` and `j`, rewriting only their 6-bit fields.
     ///
     /// # Parameters
-    /// - `suffix`: The suffix string to check.
+    /// - `i`: The position of the first character.
+    /// - `j`: The position of the second character.
     ///
-    /// # Returns
-    /// `true` if the string ends with the given suffix, otherwise `false`.
+    /// # Panics
+    /// Panics if `i` or `j` is not less than the length. Use [`try_swap`](Self::try_swap) to get an
+    /// error instead.
     ///
     /// # Examples
     ///
     /// ```rust
     /// use dec_sixbit::DecSixbit;
     ///
-    /// let sixbit = DecSixbit::new("HELLO").unwrap();
-    /// assert!(sixbit.ends_with("LO"));
-    /// assert!(!sixbit.ends_with("HE"));
+    /// let mut sixbit = DecSixbit::new("BACD").unwrap();
+    /// sixbit.swap(0, 1);
+    /// assert_eq!(sixbit.to_string(), "ABCD");
     /// ```
-    pub fn ends_with<P: AsRef<str>>(&self, suffix: P) -> bool {
-        self.to_string().ends_with(suffix.as_ref())
+    #[track_caller]
+    pub fn swap(&mut self, i: usize, j: usize) {
+        let len = self.len();
+        if let Err(error) = self.try_swap(i, j) {
+            panic!("cannot swap characters {i} and {j} of a value of {len} characters: {error}");
+        }
     }
 
-    /// Checks if the string contains the given substring.
+    /// Swaps the characters at `i` and `j`, rewriting only their 6-bit fields.
     ///
     /// # Parameters
-    /// - `substring`: The substring to search for.
+    /// - `i`: The position of the first character.
+    /// - `j`: The position of the second character.
     ///
-    /// # Returns
-    /// `true` if the string contains the given substring, otherwise `false`.
+    /// # Errors
+    /// Returns an [`Error::OutOfBounds`] if `i` or `j` is not less than the length, in which case the
+    /// value is left unchanged.
+    pub fn try_swap(&mut self, i: usize, j: usize) -> Result<(), Error> {
+        if i >= self.len() || j >= self.len() {
+            return Err(Error::OutOfBounds);
+        }
+        // Fields sharing a byte are each rewritten through a mask, so the order of the writes doesn't matter
+        let (a, b) = (bits::value_at(self.buf(), i), bits::value_at(self.buf(), j));
+        self.clear_marker();
+        bits::set_value(self.buf_mut(), i, b);
+        bits::set_value(self.buf_mut(), j, a);
+        self.set_marker();
+        Ok(())
+    }
+
+    /// Creates a new empty `DecSixbit` with room for at least `chars` characters.
+    ///
+    /// Appending up to `chars` characters with [`push`](Self::push) or [`push_str`](Self::push_str)
+    /// will not reallocate.
     ///
     /// # Examples
     ///
     /// ```rust
     /// use dec_sixbit::DecSixbit;
     ///
-    /// let sixbit = DecSixbit::new("HELLO").unwrap();
-    /// assert!(sixbit.contains("ELL"));
-    /// assert!(!sixbit.contains("XYZ"));
+    /// let sixbit = DecSixbit::with_capacity(10);
+    /// assert!(sixbit.is_empty());
+    /// assert!(sixbit.capacity() >= 10);
     /// ```
-    pub fn contains<P: AsRef<str>>(&self, substring: P) -> bool {
-        self.to_string().contains(substring.as_ref())
+    pub fn with_capacity(chars: usize) -> Self {
+        Self::from_buffer(vec![0; Self::bytes_with_marker_for_chars(chars)], 0)
     }
-}
 
-impl fmt::Display for DecSixbit {
-    #[inline(always)]
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        // Use decode_unchecked because the TRAILING_SPACE_MARKER byte might have been added at the end
-        let decoded = decode_unchecked(&self.bytes, self.len);
-        write!(f, "{}", decoded)
+    /// Returns the number of characters the value can hold without reallocating.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use dec_sixbit::DecSixbit;
+    ///
+    /// let sixbit = DecSixbit::with_capacity(8);
+    /// assert!(sixbit.capacity() >= 8);
+    /// ```
+    pub fn capacity(&self) -> usize {
+        let chars = self.buf().len() * 8 / 6;
+        // A multiple of 4 characters may need one more byte for the TRAILING_SPACE_MARKER
+        if chars % 4 == 0 && chars != 0 {
+            chars - 1
+        } else {
+            chars
+        }
     }
-}
-
-impl std::str::FromStr for DecSixbit {
-    type Err = Error;
 
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        Self::new(s)
+    /// Reserves capacity for at least `additional` more characters.
+    ///
+    /// # Panics
+    /// Panics if the new capacity overflows `usize`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use dec_sixbit::DecSixbit;
+    ///
+    /// let mut sixbit = DecSixbit::new("A").unwrap();
+    /// sixbit.reserve(10);
+    /// assert!(sixbit.capacity() >= 11);
+    /// ```
+    pub fn reserve(&mut self, additional: usize) {
+        let chars = self.len().checked_add(additional).expect("capacity overflow");
+        let required = Self::bytes_with_marker_for_chars(chars);
+        if required > self.buf().len() {
+            self.reallocate(required);
+        }
     }
-}
 
-impl TryFrom<&str> for DecSixbit {
-    type Error = Error;
-
-    fn try_from(s: &str) -> Result<Self, Self::Error> {
-        Self::new(s)
+    /// Shrinks the capacity to match the current length as closely as possible.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use dec_sixbit::DecSixbit;
+    ///
+    /// let mut sixbit = DecSixbit::with_capacity(100);
+    /// sixbit.push_str("HELLO").unwrap();
+    /// sixbit.shrink_to_fit();
+    /// assert!(sixbit.capacity() < 100);
+    /// ```
+    pub fn shrink_to_fit(&mut self) {
+        if let Repr::Heap { bytes, .. } = &self.repr {
+            let encoded_len = self.encoded_len();
+            if encoded_len < bytes.len() {
+                self.reallocate(encoded_len);
+            }
+        }
     }
-}
-
-impl TryFrom<&[u8]> for DecSixbit {
-    type Error = Error;
 
-    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
-        Self::try_from_slice(bytes)
+    /// Normalizes the packed bytes to the canonical form that [`new`](Self::new) produces.
+    ///
+    /// Every constructor already normalizes its input, so this is a no-op for values built by this crate.
+    /// It is meant for code that assembles values from stored bytes and wants to make the invariant
+    /// explicit; see [`DecSixbitStr::is_canonical`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use dec_sixbit::DecSixbit;
+    ///
+    /// let mut sixbit = DecSixbit::new("FOUR").unwrap();
+    /// let bytes = sixbit.as_bytes().to_vec();
+    /// sixbit.canonicalize();
+    /// assert!(sixbit.is_canonical());
+    /// assert_eq!(sixbit.as_bytes(), bytes);
+    /// ```
+    pub fn canonicalize(&mut self) {
+        if !self.is_canonical() {
+            *self = Self::try_from_slice(self.as_bytes()).expect("inferred length is always valid");
+        }
     }
-}
-
-impl TryFrom<Vec<u8>> for DecSixbit {
-    type Error = Error;
 
-    fn try_from(bytes: Vec<u8>) -> Result<Self, Self::Error> {
-        Self::try_from(bytes.as_slice())
+    /// Returns the value in the canonical form, like [`canonicalize`](Self::canonicalize).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use dec_sixbit::DecSixbit;
+    ///
+    /// let sixbit = DecSixbit::new("ABC ").unwrap().into_canonical();
+    /// assert!(sixbit.is_canonical());
+    /// ```
+    pub fn into_canonical(mut self) -> Self {
+        self.canonicalize();
+        self
     }
-}
 
-impl TryFrom<&Vec<u8>> for DecSixbit {
-    type Error = Error;
+    /// Appends a character to the end.
+    ///
+    /// # Parameters
+    /// - `c`: The character to append. Must be an ASCII character in the range 32-95.
+    ///
+    /// # Errors
+    /// Returns an [`Error::InvalidCharacter`] if `c` is not a valid SIXBIT character.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use dec_sixbit::DecSixbit;
+    ///
+    /// let mut sixbit = DecSixbit::new("HELL").unwrap();
+    /// sixbit.push('O').unwrap();
+    /// assert_eq!(sixbit.to_string(), "HELLO");
+    /// ```
+    pub fn push(&mut self, c: char) -> Result<(), Error> {
+        self.push_sixbit_char(SixbitChar::try_from(c)?);
+        Ok(())
+    }
 
-    fn try_from(bytes: &Vec<u8>) -> Result<Self, Self::Error> {
-        Self::try_from(bytes.as_slice())
+    /// Appends an already validated character to the end.
+    ///
+    /// # Parameters
+    /// - `c`: The character to append.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use dec_sixbit::{DecSixbit, SixbitChar};
+    ///
+    /// let mut sixbit = DecSixbit::new("DSK").unwrap();
+    /// sixbit.push_sixbit_char(SixbitChar::SPACE);
+    /// assert_eq!(sixbit.to_string(), "DSK ");
+    /// ```
+    pub fn push_sixbit_char(&mut self, c: SixbitChar) {
+        self.clear_marker();
+        let len = self.len();
+        self.grow_to(Self::bytes_with_marker_for_chars(len + 1));
+        bits::set_value(self.buf_mut(), len, c.as_value());
+        self.set_len(len + 1);
+        self.set_marker();
     }
-}
 
-impl AsRef<[u8]> for DecSixbit {
+    /// Appends a string to the end.
+    ///
+    /// # Parameters
+    /// - `str`: The string to append. Must contain only ASCII characters in the range 32-95.
+    ///
+    /// # Errors
+    /// Returns an [`Error::InvalidCharacter`] if `str` contains invalid characters, in which case
+    /// the value is left unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use dec_sixbit::DecSixbit;
+    ///
+    /// let mut sixbit = DecSixbit::new("HELLO").unwrap();
+    /// sixbit.push_str(" WORLD").unwrap();
+    /// assert_eq!(sixbit.to_string(), "HELLO WORLD");
+    /// ```
+    pub fn push_str(&mut self, str: &str) -> Result<(), Error> {
+        if !str.bytes().all(|b| (ASCII_OFFSET..=95).contains(&b)) {
+            return Err(Error::InvalidCharacter);
+        }
+        let len = self.len();
+        let new_len = len + str.len();
+        self.clear_marker();
+        self.grow_to(Self::bytes_with_marker_for_chars(new_len));
+        let buf = self.buf_mut();
+        for (index, b) in (len..new_len).zip(str.bytes()) {
+            bits::set_value(buf, index, b - ASCII_OFFSET);
+        }
+        self.set_len(new_len);
+        self.set_marker();
+        Ok(())
+    }
+
+    /// Shortens the value to `new_len` characters.
+    ///
+    /// Has no effect if `new_len` is greater than or equal to the current length. The capacity is not changed.
+    ///
+    /// # Parameters
+    /// - `new_len`: The number of characters to keep.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use dec_sixbit::DecSixbit;
+    ///
+    /// let mut sixbit = DecSixbit::new("HELLO").unwrap();
+    /// sixbit.truncate(4);
+    /// assert_eq!(sixbit.to_string(), "HELL");
+    /// ```
+    pub fn truncate(&mut self, new_len: usize) {
+        if new_len >= self.len() {
+            return;
+        }
+        self.clear_marker();
+        let old_bytes = bits::bytes_for_chars(self.len());
+        let new_bytes = bits::bytes_for_chars(new_len);
+        let buf = self.buf_mut();
+        buf[new_bytes..old_bytes].fill(0);
+        // Clear the bits of removed characters sharing the last byte
+        let used_bits = new_len * 6 % 8;
+        if used_bits != 0 {
+            buf[new_bytes - 1] &= !(0xFF >> used_bits);
+        }
+        self.set_len(new_len);
+        self.set_marker();
+    }
+
+    /// Keeps only the characters for which `f` returns `true`, in order.
+    ///
+    /// The kept characters are compacted in place, in a single pass over the packed fields. The capacity
+    /// is not changed.
+    ///
+    /// # Parameters
+    /// - `f`: The predicate, called once for each character in order.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use dec_sixbit::DecSixbit;
+    ///
+    /// let mut sixbit = DecSixbit::new("A-1, B.2").unwrap();
+    /// sixbit.retain(|c| c.is_ascii_alphanumeric() || c == ' ');
+    /// assert_eq!(sixbit.to_string(), "A1 B2");
+    /// ```
+    pub fn retain<F: FnMut(char) -> bool>(&mut self, mut f: F) {
+        let len = self.len();
+        let mut write = 0;
+        for read in 0..len {
+            let value = bits::value_at(self.buf(), read);
+            if f((value + ASCII_OFFSET) as char) {
+                if write != read {
+                    bits::set_value(self.buf_mut(), write, value);
+                }
+                write += 1;
+            }
+        }
+        // Only fields before `len` were rewritten, so the marker is still found after them
+        self.truncate(write);
+    }
+
+    /// Returns a copy with every character replaced by `f(c)`, without decoding to a string.
+    ///
+    /// # Parameters
+    /// - `f`: The mapping, called once for each character in order.
+    ///
+    /// # Errors
+    /// Returns an [`Error::InvalidCharacter`] if `f` returns a character outside the range 32-95.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use dec_sixbit::DecSixbit;
+    ///
+    /// let path = DecSixbit::new("DSK/USR/README").unwrap();
+    /// let mapped = path.map_chars(|c| if c == '/' { '.' } else { c }).unwrap();
+    /// assert_eq!(mapped.to_string(), "DSK.USR.README");
+    /// ```
+    pub fn map_chars<F: FnMut(char) -> char>(&self, f: F) -> Result<DecSixbit, Error> {
+        let mut mapped = self.clone();
+        mapped.map_chars_in_place(f)?;
+        Ok(mapped)
+    }
+
+    /// Replaces every character with `f(c)` in place, rewriting each 6-bit field directly.
+    ///
+    /// # Parameters
+    /// - `f`: The mapping, called once for each character in order.
+    ///
+    /// # Errors
+    /// Returns an [`Error::InvalidCharacter`] if `f` returns a character outside the range 32-95. The
+    /// characters before the one that failed have already been replaced; use
+    /// [`map_chars`](Self::map_chars) to leave the original untouched.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use dec_sixbit::DecSixbit;
+    ///
+    /// let mut card = DecSixbit::new("4111 1111 1111 1234").unwrap();
+    /// let mut seen = 0;
+    /// card.map_chars_in_place(|c| {
+    ///     seen += c.is_ascii_digit() as usize;
+    ///     if c.is_ascii_digit() && seen <= 12 { '#' } else { c }
+    /// })
+    /// .unwrap();
+    /// assert_eq!(card.to_string(), "#### #### #### 1234");
+    /// ```
+    pub fn map_chars_in_place<F: FnMut(char) -> char>(&mut self, mut f: F) -> Result<(), Error> {
+        self.clear_marker();
+        let mut result = Ok(());
+        for index in 0..self.len() {
+            let value = bits::value_at(self.buf(), index);
+            match SixbitChar::try_from(f((value + ASCII_OFFSET) as char)) {
+                Ok(c) => bits::set_value(self.buf_mut(), index, c.as_value()),
+                Err(error) => {
+                    result = Err(error);
+                    break;
+                },
+            }
+        }
+        self.set_marker();
+        result
+    }
+
+    /// Resizes the value to `new_len` characters, truncating or appending copies of `pad` like `String::resize`.
+    ///
+    /// # Parameters
+    /// - `new_len`: The number of characters in the result.
+    /// - `pad`: The character appended when growing. Must be an ASCII character in the range 32-95.
+    ///
+    /// # Errors
+    /// Returns an [`Error::InvalidCharacter`] if `pad` is not a valid SIXBIT character, in which case the
+    /// value is left unchanged, even when shrinking.
+    ///
+    /// # Panics
+    /// Panics if the number of bytes for `new_len` characters overflows `usize`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use dec_sixbit::DecSixbit;
+    ///
+    /// let mut sixbit = DecSixbit::new("DSK").unwrap();
+    /// sixbit.resize(6, '.').unwrap();
+    /// assert_eq!(sixbit.to_string(), "DSK...");
+    /// sixbit.resize(2, ' ').unwrap();
+    /// assert_eq!(sixbit.to_string(), "DS");
+    /// ```
+    pub fn resize(&mut self, new_len: usize, pad: char) -> Result<(), Error> {
+        let pad = SixbitChar::try_from(pad)?;
+        let len = self.len();
+        if new_len <= len {
+            self.truncate(new_len);
+            return Ok(());
+        }
+        self.clear_marker();
+        self.grow_to(Self::bytes_with_marker_for_chars(new_len));
+        // The new fields are already zero, which is the space character
+        if pad != SixbitChar::SPACE {
+            let buf = self.buf_mut();
+            for index in len..new_len {
+                bits::set_value(buf, index, pad.as_value());
+            }
+        }
+        self.set_len(new_len);
+        self.set_marker();
+        Ok(())
+    }
+
+    /// Returns the number of bytes needed for `chars` characters including a possible marker byte.
+    ///
+    /// Panics with "capacity overflow", like `Vec`, if the bit count of `chars` overflows `usize`.
+    #[inline(always)]
+    #[track_caller]
+    fn bytes_with_marker_for_chars(chars: usize) -> usize {
+        bits::checked_bytes_for_chars(chars).expect("capacity overflow") + (chars >= 4) as usize
+    }
+
+    /// Returns a reference to the encoded SIXBIT bytes.
+    ///
+    /// # Returns
+    /// A slice of bytes containing the SIXBIT-encoded data.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use dec_sixbit::DecSixbit;
+    ///
+    /// let sixbit = DecSixbit::new("HELLO").unwrap();
+    /// let encoded = sixbit.as_bytes();
+    /// ```
+    #[inline(always)]
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.buf()[..self.encoded_len()]
+    }
+
+    /// Consumes the value and returns the encoded SIXBIT bytes without copying.
+    ///
+    /// Any reserved capacity is released first, so the result holds exactly [`as_bytes`](Self::as_bytes).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use dec_sixbit::DecSixbit;
+    ///
+    /// let sixbit = DecSixbit::new("HELLO").unwrap();
+    /// let expected = sixbit.as_bytes().to_vec();
+    /// assert_eq!(*sixbit.into_boxed_bytes(), *expected);
+    /// ```
+    pub fn into_boxed_bytes(self) -> Box<[u8]> {
+        let encoded_len = self.encoded_len();
+        match self.repr {
+            Repr::Heap { bytes, .. } if bytes.len() == encoded_len => bytes,
+            _ => self.as_bytes().into(),
+        }
+    }
+
+    /// Returns a pointer to the packed bytes, the number of packed bytes, and the number of characters,
+    /// for handing the value to foreign code without copying.
+    ///
+    /// The byte count excludes the [`TRAILING_SPACE_MARKER`](Self::TRAILING_SPACE_MARKER), so it is always
+    /// [`encoded_len`](crate::encoded_len) of the character count, and the parts can be passed straight to
+    /// [`decode`](crate::decode) or [`from_raw_parts`](Self::from_raw_parts).
+    ///
+    /// # Pointer validity
+    ///
+    /// The pointer is valid for reads of the reported number of bytes for as long as the value is neither
+    /// moved nor mutated, exactly as if it were borrowed through [`as_bytes`](Self::as_bytes). Values of
+    /// up to 19 characters are [stored inline](Self#memory-layout), so their bytes move with the
+    /// `DecSixbit` itself: keep the value in place, for example behind a `Box` or a pinned reference,
+    /// while foreign code holds the pointer. Longer values keep their bytes at a fixed heap address until
+    /// they are mutated or dropped, but code should not rely on the difference, since it follows from the
+    /// inline capacity and may change with it.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use dec_sixbit::DecSixbit;
+    ///
+    /// let sixbit = DecSixbit::new("ABC ").unwrap();
+    /// let (ptr, byte_len, char_len) = sixbit.as_raw_parts();
+    /// assert_eq!((byte_len, char_len), (3, 4));
+    /// let copy = unsafe { DecSixbit::from_raw_parts(ptr, byte_len, char_len) }.unwrap();
+    /// assert_eq!(copy, sixbit);
+    /// ```
+    #[inline]
+    pub fn as_raw_parts(&self) -> (*const u8, usize, usize) {
+        let len = self.len();
+        (self.buf().as_ptr(), bits::bytes_for_chars(len), len)
+    }
+
+    /// Creates a `DecSixbit` by copying `byte_len` packed bytes from `ptr`, as returned by
+    /// [`as_raw_parts`](Self::as_raw_parts).
+    ///
+    /// The bytes must not include the [`TRAILING_SPACE_MARKER`](Self::TRAILING_SPACE_MARKER); it is added
+    /// when needed. `ptr` may be null if `byte_len` is zero.
+    ///
+    /// # Errors
+    /// - Returns an [`Error::InvalidBytesLength`] if `byte_len` is not the encoded length of `char_len`
+    ///   characters.
+    /// - Returns an [`Error::NonzeroPadding`] if the unused bits of the last byte are not zero.
+    ///
+    /// # Safety
+    /// If `byte_len` is nonzero, `ptr` must be valid for reads of `byte_len` bytes, which must not be
+    /// mutated for the duration of the call.
+    pub unsafe fn from_raw_parts(ptr: *const u8, byte_len: usize, char_len: usize) -> Result<Self, Error> {
+        let bytes = match byte_len {
+            0 => &[],
+            _ => core::slice::from_raw_parts(ptr, byte_len),
+        };
+        Self::from_payload(bytes.to_vec(), char_len)
+    }
+
+    /// Returns the length of the original input string.
+    ///
+    /// # Returns
+    /// The number of characters in the original string before encoding.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use dec_sixbit::DecSixbit;
+    ///
+    /// let sixbit = DecSixbit::new("HELLO").unwrap();
+    /// assert_eq!(sixbit.len(), 5);
+    /// ```
+    #[inline(always)]
+    pub fn len(&self) -> usize {
+        match self.repr {
+            Repr::Inline { len, .. } => len as usize,
+            Repr::Heap { len, .. } => len,
+        }
+    }
+
+    /// Checks if the encoded SIXBIT data is empty.
+    ///
+    /// # Returns
+    /// `true` if the original input string was empty, otherwise `false`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use dec_sixbit::DecSixbit;
+    ///
+    /// let sixbit = DecSixbit::new("").unwrap();
+    /// assert!(sixbit.is_empty());
+    /// ```
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Attempts to create a `DecSixbit` instance from a slice of encoded bytes.
+    ///
+    /// The bytes are normalized to the canonical form that [`new`](Self::new) produces: a trailing-space
+    /// marker that isn't needed is dropped and nonzero padding bits are cleared, so values read from
+    /// differently encoded bytes are equal whenever they hold the same characters.
+    ///
+    /// # Parameters
+    /// - `bytes`: A slice of bytes containing SIXBIT-encoded data.
+    ///
+    /// # Returns
+    /// - `Ok(Self)` if the slice is successfully parsed.
+    /// - `Err(Error)` if the slice has an invalid format or contains invalid data.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use dec_sixbit::DecSixbit;
+    ///
+    /// let sixbit = DecSixbit::new("FOUR").unwrap();
+    /// let mut bytes = sixbit.as_bytes().to_vec();
+    /// bytes.push(0b11);
+    ///
+    /// let read = DecSixbit::try_from_slice(&bytes).unwrap();
+    /// assert_eq!(read, sixbit);
+    /// assert_eq!(read.as_bytes(), sixbit.as_bytes());
+    /// ```
+    #[inline]
+    pub fn try_from_slice(bytes: &[u8]) -> Result<Self, Error> {
+        let len = bits::infer_len(bytes);
+        let mut payload = bytes[..bits::bytes_for_chars(len)].to_vec();
+        bits::clear_padding(&mut payload, len);
+        Ok(Self::from_encoded(payload, len))
+    }
+
+    /// Creates a `DecSixbit` from a slice of encoded bytes that must be exactly in the
+    /// [storage format](Self#storage-format), as returned by [`as_bytes`](Self::as_bytes).
+    ///
+    /// Unlike [`try_from_slice`](Self::try_from_slice), this rejects a trailing-space marker where the
+    /// content doesn't need one and nonzero padding bits. A needed marker that is missing can't be
+    /// detected: without it, the bytes are the exact encoding of the value one character shorter.
+    ///
+    /// # Parameters
+    /// - `bytes`: A slice of bytes containing SIXBIT-encoded data.
+    ///
+    /// # Errors
+    /// - Returns an [`Error::UnexpectedMarker`] if the bytes end in a marker the content doesn't need.
+    /// - Returns an [`Error::NonzeroPadding`] if the unused bits after the last character are set.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use dec_sixbit::{DecSixbit, Error};
+    ///
+    /// let sixbit = DecSixbit::new("FOUR").unwrap();
+    /// assert_eq!(DecSixbit::try_from_slice_strict(sixbit.as_bytes()), Ok(sixbit.clone()));
+    ///
+    /// let mut bytes = sixbit.as_bytes().to_vec();
+    /// bytes.push(DecSixbit::TRAILING_SPACE_MARKER);
+    /// assert_eq!(DecSixbit::try_from_slice_strict(&bytes), Err(Error::UnexpectedMarker));
+    /// assert_eq!(DecSixbit::try_from_slice(&bytes), Ok(sixbit));
+    /// ```
+    pub fn try_from_slice_strict(bytes: &[u8]) -> Result<Self, Error> {
+        let len = bits::infer_len(bytes);
+        bits::check_canonical(bytes, len)?;
+        Ok(Self::from_buffer(bytes, len))
+    }
+
+    /// Creates a `DecSixbit` from its packed bytes and length, as returned by [`as_bytes`](Self::as_bytes)
+    /// and [`len`](Self::len), taking ownership of the bytes.
+    ///
+    /// Unlike [`try_from_slice`](Self::try_from_slice), the length is checked against the bytes.
+    ///
+    /// # Parameters
+    /// - `bytes`: The packed bytes, including the trailing-space marker when present.
+    /// - `len`: The number of characters encoded in `bytes`.
+    ///
+    /// # Errors
+    /// - Returns an [`Error::InvalidBytesLength`] if `bytes` doesn't encode `len` characters.
+    /// - Returns an [`Error::NonzeroPadding`] if the unused bits after the last character are set.
+    /// - Returns an [`Error::UnexpectedMarker`] if the bytes end in a marker the content doesn't need.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use dec_sixbit::{DecSixbit, Error};
+    ///
+    /// let sixbit = DecSixbit::new("HELLO").unwrap();
+    /// let bytes = sixbit.as_bytes().to_vec();
+    /// assert_eq!(DecSixbit::from_parts(bytes.clone(), 5), Ok(sixbit));
+    /// assert_eq!(DecSixbit::from_parts(bytes, 500), Err(Error::InvalidBytesLength));
+    /// ```
+    pub fn from_parts(bytes: Vec<u8>, len: usize) -> Result<Self, Error> {
+        bits::check_canonical(&bytes, len)?;
+        Ok(Self::from_buffer(bytes, len))
+    }
+
+    /// Creates a `DecSixbit` from packed bytes and a character count, normalizing them to the canonical form.
+    ///
+    /// Unlike [`from_parts`](Self::from_parts), this accepts bytes written by older versions or other
+    /// encoders: nonzero padding bits are cleared, and the trailing-space marker is added or dropped as
+    /// the content requires. Since the length is given, a missing marker is not ambiguous here.
+    ///
+    /// # Parameters
+    /// - `bytes`: The packed bytes, optionally followed by the trailing-space marker.
+    /// - `len`: The number of characters encoded in `bytes`.
+    ///
+    /// # Errors
+    /// Returns an [`Error::InvalidBytesLength`] if `bytes` is too short for `len` characters, or has
+    /// anything but the marker after them.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use dec_sixbit::{DecSixbit, Error};
+    ///
+    /// // "ABC " without its marker, as `encode` returns it
+    /// let (bytes, len) = dec_sixbit::encode("ABC ").unwrap();
+    /// assert_eq!(DecSixbit::from_parts(bytes.clone(), len), Err(Error::InvalidBytesLength));
+    /// assert_eq!(DecSixbit::from_parts_lenient(bytes, len).unwrap().to_string(), "ABC ");
+    /// ```
+    pub fn from_parts_lenient(mut bytes: Vec<u8>, len: usize) -> Result<Self, Error> {
+        let payload = bits::checked_bytes_for_chars(len).ok_or(Error::InvalidBytesLength)?;
+        match bytes.len().checked_sub(payload) {
+            Some(0) => {},
+            Some(1) if bytes[payload] == Self::TRAILING_SPACE_MARKER => bytes.truncate(payload),
+            _ => return Err(Error::InvalidBytesLength),
+        }
+        bits::clear_padding(&mut bytes, len);
+        Ok(Self::from_encoded(bytes, len))
+    }
+
+    /// Creates a `DecSixbit` instance from a slice of encoded bytes.
+    ///
+    /// This is [`try_from_slice_strict`](Self::try_from_slice_strict) for bytes that are known to be
+    /// valid, such as ones written by [`as_bytes`](Self::as_bytes).
+    ///
+    /// # Parameters
+    /// - `bytes`: A slice of bytes containing SIXBIT-encoded data.
+    ///
+    /// # Panics
+    /// Panics if the slice is not in the [storage format](Self#storage-format). The message includes the
+    /// byte length and the error, and points at the caller.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use dec_sixbit::DecSixbit;
+    ///
+    /// let sixbit = DecSixbit::new("HELLO").unwrap();
+    /// assert_eq!(DecSixbit::from_slice(sixbit.as_bytes()), sixbit);
+    /// ```
+    #[track_caller]
+    pub fn from_slice(bytes: &[u8]) -> Self {
+        match Self::try_from_slice_strict(bytes) {
+            Ok(sixbit) => sixbit,
+            Err(error) => panic!("invalid SIXBIT bytes ({} bytes): {}", bytes.len(), error),
+        }
+    }
+
+    /// Concatenates values without decoding them, allocating the result once.
+    ///
+    /// # Parameters
+    /// - `parts`: The values to concatenate, in order.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use dec_sixbit::DecSixbit;
+    ///
+    /// let parts = ["DSKB", ":", "README", ".TXT"].map(|part| DecSixbit::new(part).unwrap());
+    /// assert_eq!(DecSixbit::concat_all(&parts).to_string(), "DSKB:README.TXT");
+    /// ```
+    pub fn concat_all<'a, I: IntoIterator<Item = &'a DecSixbit>>(parts: I) -> Self {
+        let parts: Vec<&DecSixbitStr> = parts.into_iter().map(DecSixbit::as_sixbit_str).collect();
+        Self::splice_all(&parts)
+    }
+
+    /// Concatenates values with `sep` between each pair, without decoding them.
+    ///
+    /// # Parameters
+    /// - `parts`: The values to join, in order.
+    /// - `sep`: The separator. Must contain only ASCII characters in the range 32-95.
+    ///
+    /// # Errors
+    /// Returns an [`Error::InvalidCharacter`] if `sep` contains invalid characters.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use dec_sixbit::DecSixbit;
+    ///
+    /// let parts = ["SYS", "LOGIN", "SAV"].map(|part| DecSixbit::new(part).unwrap());
+    /// assert_eq!(DecSixbit::join(&parts, ", ").unwrap().to_string(), "SYS, LOGIN, SAV");
+    /// assert!(DecSixbit::join(&parts, "|").is_err());
+    /// ```
+    pub fn join<'a, I: IntoIterator<Item = &'a DecSixbit>>(parts: I, sep: &str) -> Result<Self, Error> {
+        let sep = DecSixbit::new(sep)?;
+        let mut spliced = Vec::new();
+        for (index, part) in parts.into_iter().enumerate() {
+            if index > 0 {
+                spliced.push(sep.as_sixbit_str());
+            }
+            spliced.push(part.as_sixbit_str());
+        }
+        Ok(Self::splice_all(&spliced))
+    }
+
+    /// Packs `parts` one after another into a buffer sized for their total length.
+    fn splice_all(parts: &[&DecSixbitStr]) -> Self {
+        let len = parts.iter().map(|part| part.len()).sum();
+        let mut bytes = vec![0; bits::bytes_for_chars(len)];
+        let mut start = 0;
+        for part in parts {
+            bits::splice(&mut bytes, start, part.payload());
+            start += part.len();
+        }
+        Self::from_encoded(bytes, len)
+    }
+
+    /// Returns the value padded with trailing spaces to at least `width` characters, like `format!("{:<width$}")`.
+    ///
+    /// A value already `width` characters or longer is returned unchanged.
+    ///
+    /// # Parameters
+    /// - `width`: The minimum number of characters in the result.
+    ///
+    /// # Panics
+    /// Panics if the number of bytes for `width` characters overflows `usize`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use dec_sixbit::DecSixbit;
+    ///
+    /// let sixbit = DecSixbit::new("DSK").unwrap();
+    /// assert_eq!(sixbit.pad_right(6).to_string(), "DSK   ");
+    /// ```
+    #[track_caller]
+    pub fn pad_right(&self, width: usize) -> DecSixbit {
+        self.pad_to(width, 0)
+    }
+
+    /// Returns the value padded with leading spaces to at least `width` characters, like `format!("{:>width$}")`.
+    ///
+    /// A value already `width` characters or longer is returned unchanged.
+    ///
+    /// # Parameters
+    /// - `width`: The minimum number of characters in the result.
+    ///
+    /// # Panics
+    /// Panics if the number of bytes for `width` characters overflows `usize`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use dec_sixbit::DecSixbit;
+    ///
+    /// let sixbit = DecSixbit::new("42").unwrap();
+    /// assert_eq!(sixbit.pad_left(6).to_string(), "    42");
+    /// ```
+    #[track_caller]
+    pub fn pad_left(&self, width: usize) -> DecSixbit {
+        self.pad_to(width, width.saturating_sub(self.len()))
+    }
+
+    /// Copies the characters into a new value of `width` characters starting at `start`, with spaces elsewhere.
+    #[track_caller]
+    fn pad_to(&self, width: usize, start: usize) -> DecSixbit {
+        if width <= self.len() {
+            return self.clone();
+        }
+        // Space is the zero value, so only the characters themselves need copying
+        let mut bytes = vec![0; bits::checked_bytes_for_chars(width).expect("capacity overflow")];
+        bits::splice(&mut bytes, start, self.payload());
+        Self::from_encoded(bytes, width)
+    }
+
+    /// Creates a `DecSixbit` from raw 6-bit character values, `0..=63`, without the ASCII offset.
+    ///
+    /// This is the inverse of [`DecSixbitStr::sixbit_values`].
+    ///
+    /// # Parameters
+    /// - `values`: The 6-bit values of the characters, in order.
+    ///
+    /// # Errors
+    /// Returns an [`Error::InvalidCharacter`] if any value is 64 or greater.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use dec_sixbit::DecSixbit;
+    ///
+    /// let sixbit = DecSixbit::from_sixbit_values([36, 51, 43]).unwrap();
+    /// assert_eq!(sixbit.to_string(), "DSK");
+    /// assert!(DecSixbit::from_sixbit_values([64]).is_err());
+    /// ```
+    pub fn from_sixbit_values<I: IntoIterator<Item = u8>>(values: I) -> Result<Self, Error> {
+        let values = values.into_iter();
+        let mut bytes = Vec::with_capacity(bits::bytes_for_chars(values.size_hint().0));
+        let mut len = 0;
+        for value in values {
+            if value >= 64 {
+                return Err(Error::InvalidCharacter);
+            }
+            bytes.resize(bits::bytes_for_chars(len + 1), 0);
+            bits::set_value(&mut bytes, len, value);
+            len += 1;
+        }
+        Ok(Self::from_encoded(bytes, len))
+    }
+
+    /// Creates a `DecSixbit` from 36-bit words written as 12-digit octal numbers, as printed in
+    /// TOPS-10 listings and crash dumps.
+    ///
+    /// Each word holds 6 characters, the first in the most significant bits. This is the inverse of
+    /// [`DecSixbitStr::to_octal_words`]; the result is always 6 characters per word, including any
+    /// space padding of the final word.
+    ///
+    /// # Parameters
+    /// - `words`: The words, each exactly 12 octal digits.
+    ///
+    /// # Errors
+    /// - Returns an [`Error::LengthMismatch`] if a word is not exactly 12 characters long.
+    /// - Returns an [`Error::InvalidCharacter`] if a word contains a character other than `0`-`7`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use dec_sixbit::DecSixbit;
+    ///
+    /// let sixbit = DecSixbit::from_octal_words(&["637163644164"]).unwrap();
+    /// assert_eq!(sixbit.to_string(), "SYSTAT");
+    /// ```
+    pub fn from_octal_words(words: &[&str]) -> Result<Self, Error> {
+        let len = words.len() * 6;
+        let mut bytes = vec![0u8; bits::bytes_for_chars(len)];
+        for (word_idx, word) in words.iter().enumerate() {
+            if word.len() != 12 {
+                return Err(Error::LengthMismatch);
+            }
+            // Every pair of octal digits is one 6-bit character
+            for (char_idx, pair) in word.as_bytes().chunks_exact(2).enumerate() {
+                let value = pair.iter().try_fold(0u8, |acc, &digit| match digit {
+                    b'0'..=b'7' => Ok((acc << 3) | (digit - b'0')),
+                    _ => Err(Error::InvalidCharacter),
+                })?;
+                bits::set_value(&mut bytes, word_idx * 6 + char_idx, value);
+            }
+        }
+        Ok(Self::from_encoded(bytes, len))
+    }
+
+    /// Reassembles a value of `len` characters from blocks written by [`DecSixbitStr::to_blocks`].
+    ///
+    /// The blocks are concatenated in order and everything after the first `len` characters, such as
+    /// the space padding of the last block, is dropped.
+    ///
+    /// # Parameters
+    /// - `blocks`: The blocks, in order.
+    /// - `len`: The number of characters in the value.
+    ///
+    /// # Errors
+    /// Returns an [`Error::InvalidBytesLength`] if the blocks hold fewer than `len` characters.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use dec_sixbit::DecSixbit;
+    ///
+    /// let blocks = DecSixbit::new("DSKB").unwrap().to_blocks(3).unwrap();
+    /// assert_eq!(DecSixbit::from_blocks(&blocks, 4).unwrap().to_string(), "DSKB");
+    /// ```
+    pub fn from_blocks<I, B>(blocks: I, len: usize) -> Result<Self, Error>
+    where
+        I: IntoIterator<Item = B>,
+        B: AsRef<[u8]>,
+    {
+        let payload_len = bits::checked_bytes_for_chars(len).ok_or(Error::InvalidBytesLength)?;
+        // The buffer grows with the blocks, so a length they can't back doesn't allocate up front
+        let mut bytes = Vec::new();
+        for block in blocks {
+            let block = block.as_ref();
+            let take = block.len().min(payload_len - bytes.len());
+            bytes.extend_from_slice(&block[..take]);
+        }
+        if bytes.len() < payload_len {
+            return Err(Error::InvalidBytesLength);
+        }
+        bits::clear_padding(&mut bytes, len);
+        Ok(Self::from_encoded(bytes, len))
+    }
+
+    /// Creates a `DecSixbit` of `len` characters from a `u64` in the layout of [`encode_u64`](crate::encode_u64).
+    ///
+    /// # Parameters
+    /// - `value`: The packed value, with the first character in the 6 most significant bits.
+    /// - `len`: The number of characters packed in `value`, at most 10.
+    ///
+    /// # Errors
+    /// - Returns an [`Error::FieldOverflow`] if `len` is greater than 10.
+    /// - Returns an [`Error::InvalidBytesLength`] if any bit below the last character is set.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use dec_sixbit::DecSixbit;
+    ///
+    /// let sixbit = DecSixbit::new("SYSTAT").unwrap();
+    /// assert_eq!(DecSixbit::from_u64(sixbit.to_u64().unwrap(), 6), Ok(sixbit));
+    /// ```
+    pub fn from_u64(value: u64, len: usize) -> Result<Self, Error> {
+        if len > crate::U64_CHARS {
+            return Err(Error::FieldOverflow);
+        }
+        if value.checked_shl((6 * len) as u32).unwrap_or(0) != 0 {
+            return Err(Error::InvalidBytesLength);
+        }
+        let mut bytes = vec![0; bits::bytes_for_chars(len)];
+        for i in 0..len {
+            bits::set_value(&mut bytes, i, (value >> (58 - 6 * i)) as u8 & 0b111111);
+        }
+        Ok(Self::from_encoded(bytes, len))
+    }
+
+    /// Creates a `DecSixbit` of `len` characters from a `u128` in the layout of [`encode_u128`](crate::encode_u128).
+    ///
+    /// # Parameters
+    /// - `value`: The packed value, with the first character in the 6 most significant bits.
+    /// - `len`: The number of characters packed in `value`, at most 21.
+    ///
+    /// # Errors
+    /// - Returns an [`Error::FieldOverflow`] if `len` is greater than 21.
+    /// - Returns an [`Error::InvalidBytesLength`] if any bit below the last character is set.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use dec_sixbit::DecSixbit;
+    ///
+    /// let sixbit = DecSixbit::new("DSKB:README.TXT").unwrap();
+    /// assert_eq!(DecSixbit::from_u128(sixbit.to_u128().unwrap(), 15), Ok(sixbit));
+    /// ```
+    pub fn from_u128(value: u128, len: usize) -> Result<Self, Error> {
+        if len > crate::U128_CHARS {
+            return Err(Error::FieldOverflow);
+        }
+        if value.checked_shl((6 * len) as u32).unwrap_or(0) != 0 {
+            return Err(Error::InvalidBytesLength);
+        }
+        let mut bytes = vec![0; bits::bytes_for_chars(len)];
+        for i in 0..len {
+            bits::set_value(&mut bytes, i, (value >> (122 - 6 * i)) as u8 & 0b111111);
+        }
+        Ok(Self::from_encoded(bytes, len))
+    }
+
+    /// Reads a value written by [`DecSixbitStr::to_framed_bytes`] from the start of `bytes`.
+    ///
+    /// Bytes after the frame are ignored, so frames can be read back to back from one buffer.
+    ///
+    /// # Parameters
+    /// - `bytes`: The buffer, starting with a frame.
+    ///
+    /// # Returns
+    /// The value and the number of bytes of the frame.
+    ///
+    /// # Errors
+    /// - Returns an [`Error::UnsupportedVersion`] if the version byte is not [`FRAME_VERSION`](Self::FRAME_VERSION).
+    /// - Returns an [`Error::InvalidBytesLength`] if the buffer ends before the frame does or the
+    ///   character count is malformed.
+    /// - Returns an [`Error::NonzeroPadding`] if the padding bits after the last character are set.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use dec_sixbit::DecSixbit;
+    ///
+    /// let mut buffer = Vec::new();
+    /// DecSixbit::new("DSK").unwrap().write_framed_bytes(&mut buffer);
+    /// DecSixbit::new("SYS").unwrap().write_framed_bytes(&mut buffer);
+    ///
+    /// let (first, used) = DecSixbit::from_framed_bytes(&buffer).unwrap();
+    /// let (second, _) = DecSixbit::from_framed_bytes(&buffer[used..]).unwrap();
+    /// assert_eq!((first.to_string(), second.to_string()), ("DSK".to_string(), "SYS".to_string()));
+    /// ```
+    pub fn from_framed_bytes(bytes: &[u8]) -> Result<(Self, usize), Error> {
+        let (&version, rest) = bytes.split_first().ok_or(Error::InvalidBytesLength)?;
+        if version != Self::FRAME_VERSION {
+            return Err(Error::UnsupportedVersion(version));
+        }
+        // The character count is an unsigned LEB128 varint
+        let mut len = 0usize;
+        let mut header = 1;
+        for (i, &byte) in rest.iter().enumerate() {
+            let shift = 7 * i as u32;
+            let bits = (byte & 0x7F) as usize;
+            if shift >= usize::BITS || bits.checked_shl(shift).map_or(true, |value| value >> shift != bits) {
+                return Err(Error::InvalidBytesLength);
+            }
+            len |= bits << shift;
+            if byte & 0x80 == 0 {
+                header += i + 1;
+                break;
+            }
+        }
+        if header == 1 || len > usize::MAX / 6 {
+            return Err(Error::InvalidBytesLength);
+        }
+        let end = header + bits::bytes_for_chars(len);
+        let payload = bytes.get(header..end).ok_or(Error::InvalidBytesLength)?;
+        Ok((Self::from_payload(payload.to_vec(), len)?, end))
+    }
+
+    /// Creates a `DecSixbit` from the hex text written by [`DecSixbitStr::to_hex`].
+    ///
+    /// Digits may be in either case, and the text may start with `0x` or `0X`, as the `#` flag of the
+    /// [`LowerHex`](fmt::LowerHex) and [`UpperHex`](fmt::UpperHex) formatting writes it.
+    ///
+    /// # Parameters
+    /// - `hex`: The packed payload bytes, two hex digits each, without the trailing-space marker.
+    /// - `len`: The number of characters encoded in the payload.
+    ///
+    /// # Errors
+    /// - Returns an [`Error::InvalidHex`] if `hex` has an odd number of digits or a character that isn't one.
+    /// - Returns an [`Error::InvalidBytesLength`] if the number of bytes and `len` are inconsistent.
+    /// - Returns an [`Error::NonzeroPadding`] if the unused bits after the last character are set.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use dec_sixbit::{DecSixbit, Error};
+    ///
+    /// assert_eq!(DecSixbit::from_hex("0x8628E4", 4).unwrap().to_string(), "ABCD");
+    /// assert_eq!(DecSixbit::from_hex("8628e", 4), Err(Error::InvalidHex));
+    /// assert_eq!(DecSixbit::from_hex("8628e4", 5), Err(Error::InvalidBytesLength));
+    /// ```
+    pub fn from_hex(hex: &str, len: usize) -> Result<Self, Error> {
+        let digits = hex.strip_prefix("0x").or_else(|| hex.strip_prefix("0X")).unwrap_or(hex).as_bytes();
+        if digits.len() % 2 != 0 {
+            return Err(Error::InvalidHex);
+        }
+        let digit = |c: u8| (c as char).to_digit(16).ok_or(Error::InvalidHex);
+        let bytes = digits
+            .chunks_exact(2)
+            .map(|pair| Ok((digit(pair[0])? << 4 | digit(pair[1])?) as u8))
+            .collect::<Result<Vec<u8>, Error>>()?;
+        Self::from_payload(bytes, len)
+    }
+
+    /// Creates a `DecSixbit` from a key written by [`DecSixbitStr::to_sort_key`].
+    ///
+    /// # Parameters
+    /// - `key`: The key, which must end right after the terminator and its zero padding.
+    ///
+    /// # Errors
+    /// - Returns an [`Error::InvalidCharacter`] if a field holds a value that no character encodes to.
+    /// - Returns an [`Error::InvalidBytesLength`] if the terminator is missing, or is followed by nonzero
+    ///   padding bits or further bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use dec_sixbit::{DecSixbit, Error};
+    ///
+    /// let sixbit = DecSixbit::new("DSK:").unwrap();
+    /// assert_eq!(DecSixbit::from_sort_key(&sixbit.to_sort_key()), Ok(sixbit));
+    /// assert_eq!(DecSixbit::from_sort_key(&[0xFF]), Err(Error::InvalidCharacter));
+    /// ```
+    pub fn from_sort_key(key: &[u8]) -> Result<Self, Error> {
+        let mut values = Vec::with_capacity(key.len() * 8 / 7);
+        let mut acc = 0u16;
+        let mut pending = 0;
+        for (index, &byte) in key.iter().enumerate() {
+            acc = (acc << 8) | byte as u16;
+            pending += 8;
+            while pending >= 7 {
+                pending -= 7;
+                let field = (acc >> pending) as u8 & 0x7F;
+                acc &= (1 << pending) - 1;
+                match field {
+                    0 if index + 1 == key.len() && acc == 0 => {
+                        let mut bytes = vec![0; bits::bytes_for_chars(values.len())];
+                        for (i, &value) in values.iter().enumerate() {
+                            bits::set_value(&mut bytes, i, value);
+                        }
+                        return Ok(Self::from_encoded(bytes, values.len()));
+                    },
+                    0 => return Err(Error::InvalidBytesLength),
+                    1..=64 => values.push(field - 1),
+                    _ => return Err(Error::InvalidCharacter),
+                }
+            }
+        }
+        Err(Error::InvalidBytesLength)
+    }
+
+    /// Returns the decoded string, decoding it on the first call and caching it for later ones.
+    ///
+    /// The cache is cleared whenever the value is modified, and is ignored by equality, hashing, and
+    /// serialization.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use dec_sixbit::DecSixbit;
+    ///
+    /// let sixbit = DecSixbit::new("HELLO").unwrap();
+    /// assert_eq!(sixbit.as_decoded(), "HELLO");
+    /// assert!(std::ptr::eq(sixbit.as_decoded(), sixbit.as_decoded()));
+    /// ```
+    #[cfg(feature = "cache")]
+    pub fn as_decoded(&self) -> &str {
+        self.cache.get_or_init(|| self.to_string().into_boxed_str())
+    }
+
+    /// Borrows the value as a [`DecSixbitStr`] view.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use dec_sixbit::{DecSixbit, DecSixbitStr};
+    ///
+    /// let sixbit = DecSixbit::new("HELLO").unwrap();
+    /// let view: &DecSixbitStr = sixbit.as_sixbit_str();
+    /// assert_eq!(view.len(), 5);
+    /// ```
+    #[inline(always)]
+    pub fn as_sixbit_str(&self) -> &DecSixbitStr {
+        DecSixbitStr::from_bytes_unchecked(self.as_bytes())
+    }
+
+    /// Compares two values lexicographically by their decoded characters, without decoding.
+    ///
+    /// Characters are packed most significant bit first and SIXBIT values increase with their ASCII codes,
+    /// so comparing the packed payload bytes yields the same order as comparing the characters. The unused
+    /// padding bits of a partial final group are zero, which makes a shorter value's payload compare like
+    /// a prefix padded with spaces; ties are therefore broken by length, so that `"AB"` sorts before `"AB "`.
+    /// The trailing-space marker byte is not part of the payload and is never compared.
+    ///
+    /// This is the ordering used by the `Ord` implementation, and is suitable for `sort_unstable_by`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use dec_sixbit::DecSixbit;
+    /// use std::cmp::Ordering;
+    ///
+    /// let a = DecSixbit::new("B").unwrap();
+    /// let b = DecSixbit::new("AAAA").unwrap();
+    /// assert_eq!(DecSixbit::lexicographic_cmp(&a, &b), Ordering::Greater);
+    /// ```
+    #[inline]
+    pub fn lexicographic_cmp(a: &DecSixbit, b: &DecSixbit) -> Ordering {
+        a.as_sixbit_str().cmp(b.as_sixbit_str())
+    }
+
+    /// Sorts a slice of values lexicographically by their decoded characters, without decoding.
+    ///
+    /// This compares whole packed payloads byte by byte as described in [`DecSixbit::lexicographic_cmp`].
+    /// The sort is unstable, which is unobservable since equal values are indistinguishable.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use dec_sixbit::DecSixbit;
+    ///
+    /// let mut items: Vec<DecSixbit> = ["PIP", "DSK", "DSKB"].iter().map(|s| DecSixbit::new(s).unwrap()).collect();
+    /// DecSixbit::sort_slice(&mut items);
+    /// let sorted: Vec<String> = items.iter().map(|s| s.to_string()).collect();
+    /// assert_eq!(sorted, ["DSK", "DSKB", "PIP"]);
+    /// ```
+    pub fn sort_slice(items: &mut [DecSixbit]) {
+        items.sort_unstable_by(Self::lexicographic_cmp);
+    }
+}
+
+/// Enough room for the octal digits of `u64::MAX`.
+const UINT_DIGITS_MAX: usize = 22;
+
+/// Renders `value` in `radix` as ASCII digits at the end of `buf`, returning the digits.
+fn uint_digits(mut value: u64, radix: u64, buf: &mut [u8; UINT_DIGITS_MAX]) -> &[u8] {
+    let mut start = buf.len();
+    loop {
+        start -= 1;
+        buf[start] = b'0' + (value % radix) as u8;
+        value /= radix;
+        if value == 0 {
+            break;
+        }
+    }
+    &buf[start..]
+}
+
+impl PartialOrd for DecSixbit {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for DecSixbit {
+    /// Orders values lexicographically by their decoded characters, like `str`.
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        Self::lexicographic_cmp(self, other)
+    }
+}
+
+impl PartialEq for DecSixbit {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.as_sixbit_str() == other.as_sixbit_str()
+    }
+}
+
+impl Eq for DecSixbit {}
+
+impl fmt::Debug for DecSixbit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        display::debug_packed(f, "DecSixbit", self.as_bytes(), self.len())
+    }
+}
+
+impl Hash for DecSixbit {
+    /// Hashes the same way as the [`DecSixbitStr`] view, so either can be used to look up the other.
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_sixbit_str().hash(state);
+    }
+}
+
+impl Deref for DecSixbit {
+    type Target = DecSixbitStr;
+
+    #[inline(always)]
+    fn deref(&self) -> &DecSixbitStr {
+        self.as_sixbit_str()
+    }
+}
+
+impl AsRef<DecSixbitStr> for DecSixbit {
+    fn as_ref(&self) -> &DecSixbitStr {
+        self.as_sixbit_str()
+    }
+}
+
+impl fmt::Display for DecSixbit {
+    #[inline(always)]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self.as_sixbit_str(), f)
+    }
+}
+
+impl fmt::LowerHex for DecSixbit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::LowerHex::fmt(self.as_sixbit_str(), f)
+    }
+}
+
+impl fmt::UpperHex for DecSixbit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::UpperHex::fmt(self.as_sixbit_str(), f)
+    }
+}
+
+impl fmt::Octal for DecSixbit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Octal::fmt(self.as_sixbit_str(), f)
+    }
+}
+
+impl core::str::FromStr for DecSixbit {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::new(s)
+    }
+}
+
+impl TryFrom<&str> for DecSixbit {
+    type Error = Error;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        Self::new(s)
+    }
+}
+
+impl TryFrom<String> for DecSixbit {
+    type Error = Error;
+
+    /// Encodes the string, like [`new`](Self::new).
+    ///
+    /// The string's allocation is not reused: SIXBIT packs into a new, smaller buffer, and `s` is
+    /// dropped.
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        Self::new(&s)
+    }
+}
+
+impl TryFrom<&String> for DecSixbit {
+    type Error = Error;
+
+    fn try_from(s: &String) -> Result<Self, Self::Error> {
+        Self::new(s)
+    }
+}
+
+impl TryFrom<Cow<'_, str>> for DecSixbit {
+    type Error = Error;
+
+    fn try_from(s: Cow<'_, str>) -> Result<Self, Self::Error> {
+        Self::new(&s)
+    }
+}
+
+impl TryFrom<Box<str>> for DecSixbit {
+    type Error = Error;
+
+    /// Encodes the string into a new buffer, like `TryFrom<String>`.
+    fn try_from(s: Box<str>) -> Result<Self, Self::Error> {
+        Self::new(&s)
+    }
+}
+
+impl TryFrom<&[u8]> for DecSixbit {
+    type Error = Error;
+
+    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+        Self::try_from_slice(bytes)
+    }
+}
+
+impl TryFrom<Vec<u8>> for DecSixbit {
+    type Error = Error;
+
+    fn try_from(bytes: Vec<u8>) -> Result<Self, Self::Error> {
+        Self::try_from(bytes.as_slice())
+    }
+}
+
+impl TryFrom<&Vec<u8>> for DecSixbit {
+    type Error = Error;
+
+    fn try_from(bytes: &Vec<u8>) -> Result<Self, Self::Error> {
+        Self::try_from(bytes.as_slice())
+    }
+}
+
+impl AsRef<[u8]> for DecSixbit {
     fn as_ref(&self) -> &[u8] {
         self.as_bytes()
     }
 }
 
+impl<'a> IntoIterator for &'a DecSixbit {
+    type Item = char;
+    type IntoIter = Chars<'a>;
+
+    /// Iterates over the characters, like [`chars`](DecSixbitStr::chars).
+    #[inline]
+    fn into_iter(self) -> Chars<'a> {
+        self.chars()
+    }
+}
+
+impl FromIterator<SixbitChar> for DecSixbit {
+    /// Packs already validated characters, which can't fail.
+    fn from_iter<I: IntoIterator<Item = SixbitChar>>(iter: I) -> Self {
+        let mut sixbit = DecSixbit::default();
+        sixbit.extend(iter);
+        sixbit
+    }
+}
+
+impl Extend<SixbitChar> for DecSixbit {
+    fn extend<I: IntoIterator<Item = SixbitChar>>(&mut self, iter: I) {
+        let iter = iter.into_iter();
+        self.reserve(iter.size_hint().0);
+        iter.for_each(|c| self.push_sixbit_char(c));
+    }
+}
+
+#[cfg(feature = "serde")]
 impl serde::Serialize for DecSixbit {
     fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
-        if serializer.is_human_readable() {
-            self.to_string().serialize(serializer)
-        } else {
-            (&self.len, &self.bytes).serialize(serializer)
-        }
+        self.as_sixbit_str().serialize(serializer)
     }
 }
 
+#[cfg(feature = "serde")]
 mod deserialize {
     use super::DecSixbit;
+    use alloc::string::String;
+    use alloc::vec::Vec;
+    use core::fmt;
+
+    /// Packed bytes in the binary layout, accepting both a byte string and, for data written by
+    /// earlier versions, a sequence of integers.
+    pub(super) struct PackedByteBuf(pub(super) Vec<u8>);
+
+    impl<'de> serde::Deserialize<'de> for PackedByteBuf {
+        fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            deserializer.deserialize_byte_buf(PackedByteBufVisitor).map(PackedByteBuf)
+        }
+    }
+
+    struct PackedByteBufVisitor;
+
+    impl<'de> serde::de::Visitor<'de> for PackedByteBufVisitor {
+        type Value = Vec<u8>;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+            formatter.write_str("packed bytes")
+        }
+
+        fn visit_bytes<E: serde::de::Error>(self, v: &[u8]) -> Result<Self::Value, E> {
+            Ok(v.to_vec())
+        }
+
+        fn visit_byte_buf<E: serde::de::Error>(self, v: Vec<u8>) -> Result<Self::Value, E> {
+            Ok(v)
+        }
+
+        fn visit_seq<A: serde::de::SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+            let mut bytes = Vec::with_capacity(seq.size_hint().unwrap_or(0).min(4096));
+            while let Some(byte) = seq.next_element()? {
+                bytes.push(byte);
+            }
+            Ok(bytes)
+        }
+    }
 
     pub(super) struct DecSixbitVisitor;
 
@@ -308,7 +2017,7 @@ mod deserialize {
     impl<'de> serde::de::Visitor<'de> for DecSixbitVisitor {
         type Value = DecSixbit;
 
-        fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        fn expecting(&self, formatter: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
             formatter.write_str("bytes or string")
         }
 
@@ -318,27 +2027,76 @@ mod deserialize {
         {
             DecSixbit::new(v).map_err(E::custom)
         }
+
+        fn visit_borrowed_str<E>(self, v: &'de str) -> Result<Self::Value, E>
+        where
+            E: serde::de::Error,
+        {
+            self.visit_str(v)
+        }
+
+        fn visit_string<E>(self, v: String) -> Result<Self::Value, E>
+        where
+            E: serde::de::Error,
+        {
+            self.visit_str(&v)
+        }
+
+        // Bytes and sequences are the packed payload, with the length inferred like `try_from_slice`
+        fn visit_bytes<E>(self, v: &[u8]) -> Result<Self::Value, E>
+        where
+            E: serde::de::Error,
+        {
+            DecSixbit::try_from_slice(v).map_err(E::custom)
+        }
+
+        fn visit_seq<A: serde::de::SeqAccess<'de>>(self, seq: A) -> Result<Self::Value, A::Error> {
+            let bytes = serde::de::Visitor::visit_seq(PackedByteBufVisitor, seq)?;
+            DecSixbit::try_from_slice(&bytes).map_err(serde::de::Error::custom)
+        }
     }
 }
 
+#[cfg(feature = "serde")]
 impl<'de> serde::Deserialize<'de> for DecSixbit {
     fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<DecSixbit, D::Error> {
-        use serde::de::Error;
         if deserializer.is_human_readable() {
-            deserializer
-                .deserialize_str(deserialize::DecSixbitVisitor)
-                .map_err(D::Error::custom)
+            // Self-describing formats may hold the value as a string or as packed bytes
+            deserializer.deserialize_any(deserialize::DecSixbitVisitor)
         } else {
-            let (len, bytes) = <(usize, Vec<u8>)>::deserialize(deserializer)?;
-            Ok(DecSixbit { len, bytes })
+            DecSixbit::deserialize_packed(deserializer)
         }
     }
 }
 
+#[cfg(feature = "serde")]
+impl DecSixbit {
+    /// Deserializes the length and packed bytes written by [`DecSixbitStr::serialize_packed`].
+    pub(crate) fn deserialize_packed<'de, D: serde::Deserializer<'de>>(deserializer: D) -> Result<DecSixbit, D::Error> {
+        Self::deserialize_packed_with(deserializer, DecSixbit::from_parts)
+    }
+
+    /// Deserializes the length and packed bytes, building the value with `from_parts`.
+    pub(crate) fn deserialize_packed_with<'de, D: serde::Deserializer<'de>>(
+        deserializer: D,
+        from_parts: fn(Vec<u8>, usize) -> Result<DecSixbit, crate::Error>,
+    ) -> Result<DecSixbit, D::Error> {
+        use serde::de::Error;
+        use serde::Deserialize;
+        let (len, deserialize::PackedByteBuf(bytes)) = <(u64, deserialize::PackedByteBuf)>::deserialize(deserializer)?;
+        let len = usize::try_from(len).map_err(|_| {
+            D::Error::invalid_value(serde::de::Unexpected::Unsigned(len), &"a length that fits in usize")
+        })?;
+        from_parts(bytes, len).map_err(D::Error::custom)
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use super::DecSixbit;
-    use crate::Error;
+    use super::{DecSixbit, Repr};
+    use crate::{Error, ParseFieldError};
+    use std::borrow::Cow;
+    use std::cmp::Ordering;
     use std::convert::TryFrom;
 
     #[test]
@@ -359,6 +2117,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::unneeded_struct_pattern)]
     fn test_new_invalid_character() {
         let input = "HELLO😃";
         let result = DecSixbit::new(input);
@@ -394,11 +2153,480 @@ mod tests {
         let input = "FOUR";
         let sixbit = DecSixbit::new(input).unwrap();
         let mut bytes = sixbit.as_bytes().to_vec();
-        // Manually add TRAILING_SPACE_MARKER
+        // Manually add TRAILING_SPACE_MARKER, which "FOUR" doesn't need
         bytes.push(DecSixbit::TRAILING_SPACE_MARKER);
         let decoded = DecSixbit::try_from_slice(&bytes).unwrap();
-        assert_eq!(decoded.len, sixbit.len);
-        assert_eq!(decoded.bytes, bytes);
+        assert_eq!(decoded.len(), sixbit.len());
+        assert_eq!(decoded.as_bytes(), sixbit.as_bytes());
+        assert!(decoded.is_canonical());
+    }
+
+    #[test]
+    fn test_equal_strings_are_equal_values() {
+        use std::collections::hash_map::DefaultHasher;
+        use std::hash::{Hash, Hasher};
+
+        fn hash_of(sixbit: &DecSixbit) -> u64 {
+            let mut hasher = DefaultHasher::new();
+            sixbit.hash(&mut hasher);
+            hasher.finish()
+        }
+
+        for input in ["FOUR", "A", "ABCDEFGH", "ABC ", "AB"] {
+            let sixbit = DecSixbit::new(input).unwrap();
+            let payload = &sixbit.as_bytes()[..crate::bits::bytes_for_chars(input.len())];
+            let mut with_marker = payload.to_vec();
+            with_marker.push(DecSixbit::TRAILING_SPACE_MARKER);
+            let mut dirty_padding = sixbit.as_bytes().to_vec();
+            if input.len() % 4 != 0 {
+                *dirty_padding.last_mut().unwrap() |= 0xFF >> (input.len() * 6 % 8);
+            }
+
+            let mut variants = vec![sixbit.clone(), DecSixbit::try_from_slice(&dirty_padding).unwrap()];
+            if input.len() % 4 == 0 {
+                variants.push(DecSixbit::try_from_slice(&with_marker).unwrap());
+            }
+            if input.len() % 4 == 0 && !input.ends_with(' ') {
+                // Without a needed marker, the same payload would hold one character less
+                variants.push(DecSixbit::try_from_slice(payload).unwrap());
+            }
+            #[cfg(feature = "serde")]
+            variants.push(serde_json::from_str(&serde_json::to_string(input).unwrap()).unwrap());
+            #[cfg(feature = "serde")]
+            variants.push(bincode::deserialize(&bincode::serialize(&sixbit).unwrap()).unwrap());
+
+            for variant in &variants {
+                assert_eq!(variant, &sixbit, "{input:?}");
+                assert!(variant.is_canonical(), "{input:?}");
+                assert_eq!(variant.as_bytes(), sixbit.as_bytes(), "{input:?}");
+                assert_eq!(hash_of(variant), hash_of(&sixbit), "{input:?}");
+            }
+
+            let mut sorted: Vec<DecSixbit> = ["AAAA", "ZZZZ", "AB C"].iter().map(|s| DecSixbit::new(s).unwrap()).collect();
+            sorted.extend(variants.iter().cloned());
+            sorted.sort();
+            let first = sorted.iter().position(|value| *value == sixbit).unwrap();
+            assert!(sorted[first..first + variants.len()].iter().all(|value| *value == sixbit), "{input:?}");
+        }
+    }
+
+    #[test]
+    fn test_u64_round_trip_all_lengths() {
+        let alphabet = "ZY_XW 0A";
+        for len in 0..=10 {
+            let input: String = alphabet.chars().cycle().take(len).collect();
+            let sixbit = DecSixbit::new(&input).unwrap();
+            let packed = sixbit.to_u64().unwrap();
+            assert_eq!(packed, crate::encode_u64(&input).unwrap(), "{input:?}");
+            assert_eq!(DecSixbit::from_u64(packed, len).unwrap(), sixbit, "{input:?}");
+            assert_eq!(crate::decode_u64(packed, len).unwrap(), input);
+        }
+        assert_eq!(DecSixbit::new("").unwrap().to_u64(), Some(0));
+        assert_eq!(DecSixbit::new("___________").unwrap().to_u64(), None);
+    }
+
+    #[test]
+    fn test_u64_order_matches_str_for_equal_lengths() {
+        let mut inputs = ["ZZZ", "AAA", "A A", "AB_", "   ", "0AB", "AB "];
+        let mut packed: Vec<u64> = inputs.iter().map(|input| DecSixbit::new(input).unwrap().to_u64().unwrap()).collect();
+        inputs.sort();
+        packed.sort();
+        let decoded: Vec<String> = packed.iter().map(|&value| DecSixbit::from_u64(value, 3).unwrap().to_string()).collect();
+        assert_eq!(decoded, inputs);
+    }
+
+    #[test]
+    fn test_from_u64_rejects_invalid() {
+        assert_eq!(DecSixbit::from_u64(0, 11), Err(Error::FieldOverflow));
+        assert_eq!(crate::decode_u64(0, 11), Err(Error::FieldOverflow));
+        assert_eq!(crate::encode_u64("ELEVEN CHAR"), Err(Error::FieldOverflow));
+        assert_eq!(crate::encode_u64("a"), Err(Error::InvalidCharacter));
+
+        let packed = DecSixbit::new("AB").unwrap().to_u64().unwrap();
+        assert!(DecSixbit::from_u64(packed, 2).is_ok());
+        assert_eq!(DecSixbit::from_u64(packed | 1, 2), Err(Error::InvalidBytesLength));
+        assert_eq!(DecSixbit::from_u64(packed, 1), Err(Error::InvalidBytesLength));
+        assert_eq!(DecSixbit::from_u64(1, 0), Err(Error::InvalidBytesLength));
+        assert_eq!(DecSixbit::from_u64(0b1111, 10), Err(Error::InvalidBytesLength));
+        assert_eq!(crate::decode_u64(packed | 1, 2), Err(Error::InvalidBytesLength));
+    }
+
+    #[test]
+    fn test_u128_round_trip_all_lengths() {
+        let alphabet = "ZY_XW 0A";
+        for len in 0..=21 {
+            let input: String = alphabet.chars().cycle().take(len).collect();
+            let sixbit = DecSixbit::new(&input).unwrap();
+            let packed = sixbit.to_u128().unwrap();
+            assert_eq!(packed, crate::encode_u128(&input).unwrap(), "{input:?}");
+            assert_eq!(DecSixbit::from_u128(packed, len).unwrap(), sixbit, "{input:?}");
+            assert_eq!(crate::decode_u128(packed, len).unwrap(), input);
+
+            match sixbit.to_u64() {
+                Some(narrow) => {
+                    assert_eq!(crate::u64_to_u128(narrow), packed);
+                    assert_eq!(crate::u128_to_u64(packed, len), Some(narrow));
+                },
+                None => assert_eq!(crate::u128_to_u64(packed, len), None),
+            }
+        }
+    }
+
+    #[test]
+    fn test_u128_boundary() {
+        let at_limit = DecSixbit::new(&"_".repeat(21)).unwrap();
+        assert_eq!(at_limit.to_u128(), Some(u128::MAX << 2));
+        assert_eq!(DecSixbit::from_u128(u128::MAX << 2, 21), Ok(at_limit));
+        assert_eq!(DecSixbit::from_u128(u128::MAX, 21), Err(Error::InvalidBytesLength));
+
+        assert_eq!(DecSixbit::new(&"_".repeat(22)).unwrap().to_u128(), None);
+        assert_eq!(DecSixbit::from_u128(0, 22), Err(Error::FieldOverflow));
+        assert_eq!(crate::decode_u128(0, 22), Err(Error::FieldOverflow));
+        assert_eq!(crate::encode_u128(&"_".repeat(22)), Err(Error::FieldOverflow));
+    }
+
+    #[test]
+    fn test_u128_order_matches_str_for_equal_lengths() {
+        let mut inputs = ["DSKB:README.TXT", "DSKB:README TXT", "SYS:LOGIN.SAV  ", "               ", "DSKB:README.TX_"];
+        let mut packed: Vec<u128> = inputs.iter().map(|input| crate::encode_u128(input).unwrap()).collect();
+        inputs.sort();
+        packed.sort();
+        let decoded: Vec<String> = packed.iter().map(|&value| crate::decode_u128(value, 15).unwrap()).collect();
+        assert_eq!(decoded, inputs);
+    }
+
+    #[test]
+    fn test_from_u128_rejects_invalid() {
+        let packed = DecSixbit::new("AB").unwrap().to_u128().unwrap();
+        assert_eq!(DecSixbit::from_u128(packed | 1, 2), Err(Error::InvalidBytesLength));
+        assert_eq!(DecSixbit::from_u128(packed, 1), Err(Error::InvalidBytesLength));
+        assert_eq!(crate::decode_u128(packed | 1 << 100, 2), Err(Error::InvalidBytesLength));
+        assert_eq!(crate::u128_to_u64(packed | 1, 2), None);
+        assert_eq!(crate::encode_u128("a"), Err(Error::InvalidCharacter));
+    }
+
+    #[test]
+    fn test_sixbit_values_round_trip() {
+        for input in ["", " ", "A", "ABC ", "HELLO WORLD", "_^]\\[ZYX 0123456789"] {
+            let sixbit = DecSixbit::new(input).unwrap();
+            let values: Vec<u8> = sixbit.sixbit_values().collect();
+            assert_eq!(values, input.bytes().map(|c| c - 32).collect::<Vec<_>>());
+            assert!(sixbit.sixbit_values().rev().eq(values.iter().rev().copied()));
+            let (bytes, len) = crate::encode(input).unwrap();
+            assert_eq!(crate::decode_values(&bytes, len).unwrap(), values);
+
+            let rebuilt = DecSixbit::from_sixbit_values(sixbit.sixbit_values()).unwrap();
+            assert_eq!(rebuilt, sixbit);
+            assert_eq!(rebuilt.as_bytes(), sixbit.as_bytes());
+        }
+    }
+
+    #[test]
+    fn test_from_sixbit_values_rejects_out_of_range() {
+        assert!(DecSixbit::from_sixbit_values([63]).is_ok());
+        assert_eq!(DecSixbit::from_sixbit_values([1, 2, 64]), Err(Error::InvalidCharacter));
+        assert_eq!(DecSixbit::from_sixbit_values([255]), Err(Error::InvalidCharacter));
+    }
+
+    #[test]
+    fn test_sixbit_chars() {
+        use crate::SixbitChar;
+
+        let input = "A _?0 ";
+        let sixbit = DecSixbit::new(input).unwrap();
+        let chars: Vec<SixbitChar> = sixbit.sixbit_chars().collect();
+        assert!(chars.iter().map(|c| c.as_char()).eq(input.chars()));
+        assert!(sixbit.sixbit_chars().rev().map(char::from).eq(input.chars().rev()));
+
+        // No `Result` to unwrap: the characters are validated already
+        let collected: DecSixbit = chars.iter().copied().collect();
+        assert_eq!(collected, sixbit);
+        assert_eq!(collected.as_bytes(), sixbit.as_bytes());
+
+        let mut extended = DecSixbit::new("DSK").unwrap();
+        extended.extend([SixbitChar::try_from(':').unwrap(), SixbitChar::SPACE]);
+        assert_eq!(extended.to_string(), "DSK: ");
+        assert_eq!(DecSixbit::from_iter(std::iter::empty::<SixbitChar>()), DecSixbit::default());
+    }
+
+    #[cfg(feature = "cache")]
+    #[test]
+    fn test_cache_reuses_decoded_string() {
+        let sixbit = DecSixbit::new("HELLO WORLD").unwrap();
+        let first = sixbit.as_decoded();
+        assert_eq!(first, "HELLO WORLD");
+        assert!(std::ptr::eq(first, sixbit.as_decoded()));
+
+        let clone = sixbit.clone();
+        assert_eq!(clone.as_decoded(), "HELLO WORLD");
+    }
+
+    #[cfg(feature = "cache")]
+    #[test]
+    fn test_cache_is_invalidated_by_mutation() {
+        let mut sixbit = DecSixbit::new("ABC").unwrap();
+        assert_eq!(sixbit.as_decoded(), "ABC");
+        sixbit.push(' ').unwrap();
+        assert_eq!(sixbit.as_decoded(), "ABC ");
+        sixbit.push_str("DEFGHIJKLMNOPQRSTUVWXYZ").unwrap();
+        assert_eq!(sixbit.as_decoded(), "ABC DEFGHIJKLMNOPQRSTUVWXYZ");
+        sixbit.truncate(2);
+        assert_eq!(sixbit.as_decoded(), "AB");
+        sixbit.shrink_to_fit();
+        assert_eq!(sixbit.as_decoded(), "AB");
+    }
+
+    #[cfg(feature = "cache")]
+    #[test]
+    fn test_cache_is_ignored_by_eq_and_hash() {
+        use std::collections::HashSet;
+
+        let cached = DecSixbit::new("DSKB").unwrap();
+        let uncached = DecSixbit::new("DSKB").unwrap();
+        let _ = cached.as_decoded();
+        assert_eq!(cached, uncached);
+        assert_eq!(cached.cmp(&uncached), Ordering::Equal);
+        let set: HashSet<DecSixbit> = [cached, uncached].into_iter().collect();
+        assert_eq!(set.len(), 1);
+    }
+
+    #[test]
+    fn test_concat_all_phase_combinations() {
+        let alphabet = "ABCDEFGHIJ KLMNOPQRSTUVWXYZ_0123456789";
+        let fragments: Vec<&str> = (0..=9).map(|len| &alphabet[len..len * 2]).collect();
+        for a in &fragments {
+            for b in &fragments {
+                for c in ["", " ", "XYZ"] {
+                    let parts = [*a, *b, c].map(|part| DecSixbit::new(part).unwrap());
+                    let concatenated = DecSixbit::concat_all(&parts);
+                    let expected = DecSixbit::new(&[*a, *b, c].concat()).unwrap();
+                    assert_eq!(concatenated, expected, "{a:?} {b:?} {c:?}");
+                    assert_eq!(concatenated.as_bytes(), expected.as_bytes(), "{a:?} {b:?} {c:?}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_concat_and_join_edge_cases() {
+        let none: [DecSixbit; 0] = [];
+        assert_eq!(DecSixbit::concat_all(&none), DecSixbit::default());
+        assert_eq!(DecSixbit::join(&none, ", ").unwrap(), DecSixbit::default());
+
+        let one = [DecSixbit::new("ABC ").unwrap()];
+        assert_eq!(DecSixbit::concat_all(&one), one[0]);
+        assert_eq!(DecSixbit::join(&one, "--").unwrap(), one[0]);
+
+        // Ends in spaces at a multiple of 4, so the marker is needed
+        let parts = [DecSixbit::new("AB").unwrap(), DecSixbit::new("").unwrap(), DecSixbit::new("C").unwrap()];
+        let joined = DecSixbit::join(&parts, " ").unwrap();
+        assert_eq!(joined.to_string(), "AB  C");
+        assert_eq!(DecSixbit::join(&parts[..2], "  ").unwrap().as_bytes(), DecSixbit::new("AB  ").unwrap().as_bytes());
+        assert_eq!(DecSixbit::join(&parts, "a"), Err(Error::InvalidCharacter));
+    }
+
+    mod splice {
+        use crate::proptest::{sixbit_char, sixbit_string};
+        use crate::DecSixbit;
+        use proptest::prelude::*;
+
+        proptest! {
+            #[test]
+            fn prop_join_matches_str(parts in proptest::collection::vec(sixbit_string(0..=9), 0..6), sep in sixbit_string(0..=3)) {
+                let values: Vec<DecSixbit> = parts.iter().map(|part| DecSixbit::new(part).unwrap()).collect();
+                let joined = DecSixbit::join(&values, &sep).unwrap();
+                prop_assert_eq!(joined.to_string(), parts.join(&sep));
+                prop_assert!(joined.is_canonical());
+                prop_assert_eq!(DecSixbit::concat_all(&values).to_string(), parts.concat());
+            }
+
+            #[test]
+            fn prop_map_chars_matches_str(
+                body in prop_oneof![sixbit_string(0..=24), sixbit_string(20..=24)],
+                padding in 0usize..=4,
+                from in prop::option::of(any::<prop::sample::Index>()),
+                to in prop_oneof![Just(' '), sixbit_char()],
+            ) {
+                // Trailing spaces, and a character taken from the input or else its last one, so the mapping
+                // often adds or removes the need for the marker, including on values that fill their buffer
+                let input = format!("{body}{}", " ".repeat(padding));
+                let from = match from {
+                    Some(index) => input.chars().nth(index.index(input.len().max(1))),
+                    None => input.chars().last(),
+                }
+                .unwrap_or('A');
+                let map = |c: char| if c == from { to } else { c };
+                let expected = DecSixbit::new(&input.chars().map(map).collect::<String>()).unwrap();
+                for sixbit in super::stored_layouts(&input) {
+                    let mapped = sixbit.map_chars(map).unwrap();
+                    prop_assert_eq!(mapped.as_bytes(), expected.as_bytes());
+                    let mut in_place = sixbit;
+                    in_place.map_chars_in_place(map).unwrap();
+                    prop_assert_eq!(in_place.as_bytes(), expected.as_bytes());
+                }
+            }
+
+            #[test]
+            fn prop_retain_matches_str(input in sixbit_string(0..=40), removed in sixbit_string(0..=4)) {
+                let mut sixbit = DecSixbit::new(&input).unwrap();
+                sixbit.retain(|c| !removed.contains(c));
+                let mut expected = input.clone();
+                expected.retain(|c| !removed.contains(c));
+                let expected = DecSixbit::new(&expected).unwrap();
+                prop_assert_eq!(sixbit.as_bytes(), expected.as_bytes());
+                prop_assert_eq!(sixbit, expected);
+            }
+        }
+    }
+
+    #[test]
+    fn test_into_iterator() {
+        fn count_spaces<I: IntoIterator<Item = char>>(chars: I) -> usize {
+            chars.into_iter().filter(|&c| c == ' ').count()
+        }
+
+        let sixbit = DecSixbit::new("DSKB: README .TXT").unwrap();
+        let mut looped = String::new();
+        for c in &sixbit {
+            looped.push(c);
+        }
+        assert_eq!(looped, "DSKB: README .TXT");
+        assert!((&sixbit).into_iter().eq(sixbit.to_string().chars()));
+        assert!(sixbit.as_sixbit_str().into_iter().eq(sixbit.chars()));
+        assert_eq!(count_spaces(&sixbit), 2);
+        assert_eq!((&sixbit).into_iter().rev().collect::<String>(), "TXT. EMDAER :BKSD");
+    }
+
+    #[test]
+    fn test_canonicalize() {
+        let mut sixbit = DecSixbit::new("ABC ").unwrap();
+        assert!(sixbit.is_canonical());
+        sixbit.canonicalize();
+        assert_eq!(sixbit.to_string(), "ABC ");
+        assert_eq!(sixbit.as_bytes().len(), 4);
+    }
+
+    #[test]
+    fn test_canonicalize_non_canonical_values() {
+        use std::hash::{BuildHasher, RandomState};
+
+        let state = RandomState::new();
+        // (packed bytes, length, content): a spurious marker and nonzero padding
+        let spurious_marker = [DecSixbit::new("FOUR").unwrap().as_bytes(), &[DecSixbit::TRAILING_SPACE_MARKER]].concat();
+        let dirty_padding = {
+            let mut bytes = DecSixbit::new("AB").unwrap().as_bytes().to_vec();
+            bytes[1] |= 0b1111;
+            bytes
+        };
+        for (bytes, len, content) in [(spurious_marker, 4, "FOUR"), (dirty_padding, 2, "AB")] {
+            let fresh = DecSixbit::new(content).unwrap();
+            let mut sixbit = DecSixbit::from_buffer(bytes.clone(), len);
+            assert!(!sixbit.is_canonical(), "{content:?}");
+            assert!(DecSixbit::from_parts(bytes.clone(), len).is_err());
+            assert_eq!(DecSixbit::from_parts_lenient(bytes, len).unwrap().as_bytes(), fresh.as_bytes());
+
+            sixbit.canonicalize();
+            assert!(sixbit.is_canonical());
+            assert_eq!(sixbit.as_bytes(), fresh.as_bytes());
+            assert_eq!(sixbit, fresh);
+            assert_eq!(state.hash_one(&sixbit), state.hash_one(&fresh));
+            assert_eq!(DecSixbit::from_buffer(sixbit.as_bytes().to_vec(), len).into_canonical(), fresh);
+        }
+
+        // A missing marker reads as a shorter value unless the length is given
+        let (missing_marker, len) = crate::encode("ABC ").unwrap();
+        assert_eq!(DecSixbit::try_from_slice(&missing_marker).unwrap(), "ABC");
+        assert_eq!(DecSixbit::from_parts(missing_marker.clone(), len), Err(Error::InvalidBytesLength));
+        let lenient = DecSixbit::from_parts_lenient(missing_marker, len).unwrap();
+        assert!(lenient.is_canonical());
+        assert_eq!(lenient, DecSixbit::new("ABC ").unwrap());
+        assert_eq!(state.hash_one(&lenient), state.hash_one("ABC "));
+
+        assert_eq!(DecSixbit::from_parts_lenient(vec![0x86], 2), Err(Error::InvalidBytesLength));
+        assert_eq!(DecSixbit::from_parts_lenient(vec![0x86, 0x20, 0x00], 2), Err(Error::InvalidBytesLength));
+        assert_eq!(DecSixbit::from_parts_lenient(vec![0x86, 0x20, 0x03, 0x03], 2), Err(Error::InvalidBytesLength));
+        // The bit count of this length wraps around to 2 in release builds
+        assert_eq!(DecSixbit::from_parts_lenient(vec![0x80], usize::MAX / 6 + 1), Err(Error::InvalidBytesLength));
+        assert_eq!(DecSixbit::from_parts_lenient(vec![0x80], usize::MAX), Err(Error::InvalidBytesLength));
+    }
+
+    #[test]
+    fn test_marker_matrix() {
+        // (input, marker needed)
+        for (input, needed) in [("ABC ", true), ("    ", true), ("FOUR", false), ("HELLO", false)] {
+            let sixbit = DecSixbit::new(input).unwrap();
+            let payload = &sixbit.as_bytes()[..crate::bits::bytes_for_chars(input.len())];
+            let with_marker = [payload, &[DecSixbit::TRAILING_SPACE_MARKER]].concat();
+
+            match needed {
+                true => {
+                    // Present: the only correct encoding
+                    assert_eq!(DecSixbit::try_from_slice_strict(&with_marker), Ok(sixbit.clone()));
+                    assert_eq!(DecSixbit::try_from_slice(&with_marker), Ok(sixbit.clone()));
+                    // Absent: indistinguishable from the correct encoding of one character less
+                    let shorter = DecSixbit::new(&input[..input.len() - 1]).unwrap();
+                    assert_eq!(DecSixbit::try_from_slice_strict(payload), Ok(shorter.clone()));
+                    assert_eq!(DecSixbit::try_from_slice(payload), Ok(shorter));
+                },
+                false if input.len() % 4 == 0 => {
+                    // Present: strict rejects it, lenient drops it
+                    assert_eq!(DecSixbit::try_from_slice_strict(&with_marker), Err(Error::UnexpectedMarker));
+                    assert_eq!(DecSixbit::try_from_slice(&with_marker), Ok(sixbit.clone()));
+                    // Absent: the only correct encoding
+                    assert_eq!(DecSixbit::try_from_slice_strict(payload), Ok(sixbit.clone()));
+                    assert_eq!(DecSixbit::try_from_slice(payload), Ok(sixbit));
+                },
+                false => {
+                    // The extra byte reads as a 1-character tail, so there is no marker to speak of
+                    assert_eq!(DecSixbit::try_from_slice_strict(payload), Ok(sixbit.clone()));
+                    assert_eq!(DecSixbit::try_from_slice(payload), Ok(sixbit));
+                    assert_eq!(DecSixbit::try_from_slice_strict(&with_marker), Err(Error::NonzeroPadding));
+                    assert_eq!(DecSixbit::try_from_slice(&with_marker).unwrap().to_string(), format!("{input} "));
+                },
+            }
+        }
+    }
+
+    #[test]
+    fn test_try_from_slice_strict_rejects_padding() {
+        let bytes = [0b1000_0101];
+        assert_eq!(DecSixbit::try_from_slice_strict(&bytes), Err(Error::NonzeroPadding));
+        assert_eq!(DecSixbit::try_from_slice(&bytes).unwrap().to_string(), "A");
+        assert_eq!(DecSixbit::try_from_slice_strict(&[]), Ok(DecSixbit::default()));
+    }
+
+    #[test]
+    fn test_slice_error_classes() {
+        let four = DecSixbit::new("FOUR").unwrap();
+        let spurious_marker = [four.as_bytes(), &[DecSixbit::TRAILING_SPACE_MARKER]].concat();
+        assert_eq!(DecSixbit::try_from_slice_strict(&spurious_marker), Err(Error::UnexpectedMarker));
+        assert_eq!(DecSixbit::try_from_slice_strict(&[0x86, 0x21]), Err(Error::NonzeroPadding));
+        assert_eq!(DecSixbit::from_parts(four.as_bytes().to_vec(), 5), Err(Error::InvalidBytesLength));
+        assert_eq!(DecSixbit::from_parts(vec![0x86, 0x21], 2), Err(Error::NonzeroPadding));
+        assert_eq!(DecSixbit::from_parts(spurious_marker, 4), Err(Error::UnexpectedMarker));
+    }
+
+    #[test]
+    fn test_from_slice_panic_message() {
+        let four = DecSixbit::new("FOUR").unwrap();
+        let spurious_marker = [four.as_bytes(), &[DecSixbit::TRAILING_SPACE_MARKER]].concat();
+        let payload = std::panic::catch_unwind(|| DecSixbit::from_slice(&spurious_marker)).unwrap_err();
+        let message = payload.downcast_ref::<String>().unwrap();
+        assert_eq!(message, "invalid SIXBIT bytes (4 bytes): unexpected trailing-space marker");
+
+        let payload = std::panic::catch_unwind(|| DecSixbit::from_slice(&[0xFF])).unwrap_err();
+        assert!(payload.downcast_ref::<String>().unwrap().ends_with("(1 bytes): nonzero padding bits after the last character"));
+        assert_eq!(DecSixbit::from_slice(four.as_bytes()), four);
+    }
+
+    #[test]
+    fn test_try_from_slice_three_char_tail() {
+        for input in ["ABC", "ABCDEFG", "AB "] {
+            let sixbit = DecSixbit::new(input).unwrap();
+            let decoded = DecSixbit::try_from_slice(sixbit.as_bytes()).unwrap();
+            assert_eq!(decoded.len(), input.len());
+            assert_eq!(decoded.to_string(), input);
+        }
     }
 
     #[test]
@@ -431,39 +2659,274 @@ mod tests {
     }
 
     #[test]
-    fn test_contains() {
-        let sixbit = DecSixbit::new("CONTAINS").unwrap();
-        assert!(sixbit.contains("TAI"));
-        assert!(!sixbit.contains("XYZ"));
+    fn test_contains() {
+        let sixbit = DecSixbit::new("CONTAINS").unwrap();
+        assert!(sixbit.contains("TAI"));
+        assert!(!sixbit.contains("XYZ"));
+    }
+
+    #[test]
+    fn test_display_trait() {
+        let input = "DISPLAY";
+        let sixbit = DecSixbit::new(input).unwrap();
+        let displayed = format!("{}", sixbit);
+        assert_eq!(displayed, input);
+    }
+
+    #[test]
+    fn test_display_formatting_matches_str() {
+        for input in ["", "A", "HEY", "DSKB", "HELLO", "TEST    ", "HELLO WORLD"] {
+            let sixbit = DecSixbit::new(input).unwrap();
+            assert_eq!(format!("{:>8}", sixbit), format!("{:>8}", input));
+            assert_eq!(format!("{:<8}", sixbit), format!("{:<8}", input));
+            assert_eq!(format!("{:^8}", sixbit), format!("{:^8}", input));
+            assert_eq!(format!("{:8}", sixbit), format!("{:8}", input));
+            assert_eq!(format!("{:.3}", sixbit), format!("{:.3}", input));
+            assert_eq!(format!("{:.0}", sixbit), format!("{:.0}", input));
+            assert_eq!(format!("{:*^9.4}", sixbit), format!("{:*^9.4}", input));
+            assert_eq!(format!("{:->7.2}", sixbit), format!("{:->7.2}", input));
+            assert_eq!(format!("{:_<2}", sixbit), format!("{:_<2}", input));
+        }
+    }
+
+    #[test]
+    fn test_from_str() {
+        let input = "FROM_STR";
+        let sixbit: DecSixbit = input.parse().unwrap();
+        assert_eq!(sixbit.to_string(), input);
+    }
+
+    #[test]
+    fn test_try_from_str_valid() {
+        let input = "TRY_FROM";
+        let sixbit = DecSixbit::try_from(input).unwrap();
+        assert_eq!(sixbit.to_string(), input);
+    }
+
+    #[test]
+    fn test_try_from_str_invalid() {
+        let input = "INVALID😤";
+        let result = DecSixbit::try_from(input);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_set_char() {
+        for input in ["ABCDEFGHI", "ABCDEFGH", "ABC     "] {
+            for index in 0..input.len() {
+                // Values at the ends of the range and ones that fill or clear each byte boundary
+                for c in [' ', '!', '/', '0', '?', '@', 'O', 'P', '^', '_'] {
+                    let mut sixbit = DecSixbit::new(input).unwrap();
+                    sixbit.set_char(index, c).unwrap();
+                    let mut expected = input.to_string();
+                    expected.replace_range(index..index + 1, &c.to_string());
+                    assert_eq!(sixbit.to_string(), expected, "{input:?}[{index}] = {c:?}");
+                    assert_eq!(sixbit.as_bytes(), DecSixbit::new(&expected).unwrap().as_bytes(), "{input:?}[{index}] = {c:?}");
+                }
+            }
+        }
+
+        let mut sixbit = DecSixbit::new("ABCDEFGHI").unwrap();
+        assert_eq!(sixbit.set_char(9, 'A'), Err(Error::OutOfBounds));
+        assert_eq!(sixbit.set_char(0, 'a'), Err(Error::InvalidCharacter));
+        assert_eq!(sixbit, DecSixbit::new("ABCDEFGHI").unwrap());
+        assert_eq!(DecSixbit::default().set_char(0, 'A'), Err(Error::OutOfBounds));
+    }
+
+    /// Returns `input` as built by `new` and as a heap value shrunk to fit, so edits are tested on both.
+    fn stored_layouts(input: &str) -> [DecSixbit; 2] {
+        let mut shrunk = DecSixbit::with_capacity(100);
+        shrunk.push_str(input).unwrap();
+        shrunk.shrink_to_fit();
+        [DecSixbit::new(input).unwrap(), shrunk]
+    }
+
+    #[test]
+    fn test_set_char_adds_marker_at_capacity() {
+        for len in [16, 19, 20, 21, 24] {
+            let input = "A".repeat(len);
+            let padded = format!("{} ", &input[1..]);
+            for mut sixbit in stored_layouts(&input) {
+                sixbit.set_char(len - 1, ' ').unwrap();
+                assert_eq!(sixbit.to_string(), padded);
+                assert_eq!(sixbit.as_bytes(), DecSixbit::new(&padded).unwrap().as_bytes(), "{len}");
+                sixbit.set_char(len - 1, 'A').unwrap();
+                assert_eq!(sixbit.as_bytes(), DecSixbit::new(&input).unwrap().as_bytes(), "{len}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_map_chars_invalid_output() {
+        let sixbit = DecSixbit::new("ABC DEF").unwrap();
+        assert_eq!(sixbit.map_chars(|c| c.to_ascii_lowercase()), Err(Error::InvalidCharacter));
+
+        let mut in_place = DecSixbit::new("ABCD").unwrap();
+        assert_eq!(in_place.map_chars_in_place(|c| if c == 'C' { '~' } else { ' ' }), Err(Error::InvalidCharacter));
+        assert_eq!(in_place.as_bytes(), DecSixbit::new("  CD").unwrap().as_bytes());
+        in_place.map_chars_in_place(|_| ' ').unwrap();
+        assert_eq!(in_place.as_bytes(), DecSixbit::new("    ").unwrap().as_bytes());
+    }
+
+    #[test]
+    fn test_retain() {
+        let mut sixbit = DecSixbit::new("A B C D E").unwrap();
+        sixbit.retain(|c| c != ' ');
+        assert_eq!(sixbit.to_string(), "ABCDE");
+        assert_eq!(sixbit.as_bytes(), DecSixbit::new("ABCDE").unwrap().as_bytes());
+
+        let mut sixbit = DecSixbit::new("KEEP ALL").unwrap();
+        sixbit.retain(|_| true);
+        assert_eq!(sixbit, DecSixbit::new("KEEP ALL").unwrap());
+
+        let mut sixbit = DecSixbit::new("A  B  ").unwrap();
+        sixbit.retain(|c| c != 'B');
+        assert_eq!(sixbit.as_bytes(), DecSixbit::new("A    ").unwrap().as_bytes());
+        sixbit.retain(|c| c != 'A');
+        assert_eq!(sixbit.as_bytes(), DecSixbit::new("    ").unwrap().as_bytes());
+        assert!(sixbit.is_canonical());
+
+        sixbit.retain(|_| false);
+        assert!(sixbit.is_empty());
+        assert_eq!(sixbit.as_bytes(), &[] as &[u8]);
+    }
+
+    #[test]
+    fn test_swap_all_pairs() {
+        // Every pair of phases, within a byte, straddling a byte boundary, and across groups
+        for input in ["AB_ 0?@Z", "ABC DEF "] {
+            let original = DecSixbit::new(input).unwrap();
+            for i in 0..input.len() {
+                for j in 0..input.len() {
+                    let mut sixbit = original.clone();
+                    sixbit.swap(i, j);
+                    let mut expected: Vec<u8> = input.bytes().collect();
+                    expected.swap(i, j);
+                    let expected = String::from_utf8(expected).unwrap();
+                    assert_eq!(sixbit.as_bytes(), DecSixbit::new(&expected).unwrap().as_bytes(), "{input:?}: swap({i}, {j})");
+                    for k in (0..input.len()).filter(|&k| k != i && k != j) {
+                        assert_eq!(crate::bits::value_at(sixbit.as_bytes(), k), crate::bits::value_at(original.as_bytes(), k));
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_swap_across_inline_threshold() {
+        // A leading space swapped to the end makes the value need the marker, and swapping it back drops it
+        for len in [19, 20, 21, 24] {
+            let input = format!(" {}", "A".repeat(len - 1));
+            let swapped = format!("{} ", "A".repeat(len - 1));
+            for mut sixbit in stored_layouts(&input) {
+                sixbit.swap(0, len - 1);
+                assert_eq!(sixbit.as_bytes(), DecSixbit::new(&swapped).unwrap().as_bytes(), "{len}");
+                assert_eq!(sixbit.try_swap(len - 1, 0), Ok(()));
+                assert_eq!(sixbit.as_bytes(), DecSixbit::new(&input).unwrap().as_bytes(), "{len}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_swap_out_of_bounds() {
+        let mut sixbit = DecSixbit::new("ABCD").unwrap();
+        assert_eq!(sixbit.try_swap(0, 4), Err(Error::OutOfBounds));
+        assert_eq!(sixbit.try_swap(4, 4), Err(Error::OutOfBounds));
+        assert_eq!(sixbit.to_string(), "ABCD");
+
+        let panic = std::panic::catch_unwind(move || sixbit.swap(1, 9)).unwrap_err();
+        assert_eq!(
+            panic.downcast_ref::<String>().map(String::as_str),
+            Some("cannot swap characters 1 and 9 of a value of 4 characters: index out of bounds")
+        );
+    }
+
+    #[test]
+    fn test_pad() {
+        for input in ["", "A", "AB", "ABC", "ABCD", "AB  ", "ABCDE"] {
+            let sixbit = DecSixbit::new(input).unwrap();
+            for width in 0..=13 {
+                let right = DecSixbit::new(&format!("{input:<width$}")).unwrap();
+                let left = DecSixbit::new(&format!("{input:>width$}")).unwrap();
+                assert_eq!(sixbit.pad_right(width).as_bytes(), right.as_bytes(), "{input:?} to {width}");
+                assert_eq!(sixbit.pad_left(width).as_bytes(), left.as_bytes(), "{input:?} to {width}");
+                assert_eq!(sixbit.pad_right(width), right);
+                assert_eq!(sixbit.pad_left(width), left);
+            }
+        }
+        let sixbit = DecSixbit::new("TOO WIDE").unwrap();
+        assert_eq!(sixbit.pad_left(4), sixbit);
+        assert_eq!(sixbit.pad_right(4), sixbit);
     }
 
     #[test]
-    fn test_display_trait() {
-        let input = "DISPLAY";
-        let sixbit = DecSixbit::new(input).unwrap();
-        let displayed = format!("{}", sixbit);
-        assert_eq!(displayed, input);
+    #[should_panic(expected = "capacity overflow")]
+    fn test_pad_overflow() {
+        // The bit count of this width wraps around to 2, which would otherwise fit in one byte
+        let _ = DecSixbit::new("ABCDEF").unwrap().pad_right(usize::MAX / 6 + 1);
     }
 
     #[test]
-    fn test_from_str() {
-        let input = "FROM_STR";
-        let sixbit: DecSixbit = input.parse().unwrap();
-        assert_eq!(sixbit.to_string(), input);
+    fn test_resize() {
+        for input in ["", "A", "ABC", "ABCD", "ABCDEFG"] {
+            for new_len in 0..=12 {
+                for pad in [' ', '_', '0'] {
+                    let mut sixbit = DecSixbit::new(input).unwrap();
+                    sixbit.resize(new_len, pad).unwrap();
+                    let mut expected = input.to_string();
+                    expected.truncate(new_len);
+                    expected.extend(std::iter::repeat(pad).take(new_len.saturating_sub(input.len())));
+                    assert_eq!(sixbit.as_bytes(), DecSixbit::new(&expected).unwrap().as_bytes(), "{input:?} to {new_len} with {pad:?}");
+                    assert_eq!(sixbit, DecSixbit::new(&expected).unwrap());
+                }
+            }
+        }
+
+        let mut sixbit = DecSixbit::new("ABC ").unwrap();
+        let before = sixbit.as_bytes().to_vec();
+        sixbit.resize(4, 'X').unwrap();
+        assert_eq!(sixbit.as_bytes(), before);
+        assert_eq!(sixbit.resize(8, 'x'), Err(Error::InvalidCharacter));
+        assert_eq!(sixbit.resize(1, 'x'), Err(Error::InvalidCharacter));
+        assert_eq!(sixbit.as_bytes(), before);
     }
 
     #[test]
-    fn test_try_from_str_valid() {
-        let input = "TRY_FROM";
-        let sixbit = DecSixbit::try_from(input).unwrap();
-        assert_eq!(sixbit.to_string(), input);
+    fn test_from_str_padded() {
+        for (input, width) in [("", 0), ("", 5), ("A", 1), ("ABC", 4), ("ABCD", 4), ("AB", 8), ("ABCDE", 12)] {
+            let right = DecSixbit::from_str_padded(input, width).unwrap();
+            let left = DecSixbit::from_str_padded_left(input, width).unwrap();
+            assert_eq!((right.len(), left.len()), (width, width));
+            assert_eq!(right.as_bytes(), DecSixbit::new(&format!("{input:<width$}")).unwrap().as_bytes(), "{input:?} in {width}");
+            assert_eq!(left.as_bytes(), DecSixbit::new(&format!("{input:>width$}")).unwrap().as_bytes(), "{input:?} in {width}");
+        }
+
+        assert_eq!(DecSixbit::from_str_padded("TOOLONG", 4), Err(Error::TooLong { len: 7, max: 4 }));
+        assert_eq!(DecSixbit::from_str_padded_left("TOOLONG", 4), Err(Error::TooLong { len: 7, max: 4 }));
+        assert_eq!(DecSixbit::from_str_padded("ok", 4), Err(Error::InvalidCharacter));
+        assert_eq!(Error::TooLong { len: 7, max: 4 }.to_string(), "input of 7 characters does not fit in 4");
     }
 
     #[test]
-    fn test_try_from_str_invalid() {
-        let input = "INVALID😤";
-        let result = DecSixbit::try_from(input);
-        assert!(result.is_err());
+    fn test_try_from_owned_strings() {
+        fn convert<T: TryInto<DecSixbit, Error = Error>>(value: T) -> Result<DecSixbit, Error> {
+            let sixbit: DecSixbit = value.try_into()?;
+            Ok(sixbit)
+        }
+
+        let expected = DecSixbit::new("OWNED ").unwrap();
+        let owned = String::from("OWNED ");
+        assert_eq!(convert(&owned), Ok(expected.clone()));
+        assert_eq!(convert(Cow::Borrowed("OWNED ")), Ok(expected.clone()));
+        assert_eq!(convert(Cow::<str>::Owned(owned.clone())), Ok(expected.clone()));
+        assert_eq!(convert(owned.clone().into_boxed_str()), Ok(expected.clone()));
+        assert_eq!(convert(owned), Ok(expected));
+
+        let invalid = String::from("owned");
+        assert_eq!(convert(&invalid), Err(Error::InvalidCharacter));
+        assert_eq!(convert(Cow::<str>::Owned(invalid.clone())), Err(Error::InvalidCharacter));
+        assert_eq!(convert(invalid.clone().into_boxed_str()), Err(Error::InvalidCharacter));
+        assert_eq!(convert(invalid), Err(Error::InvalidCharacter));
     }
 
     #[test]
@@ -484,6 +2947,7 @@ mod tests {
         assert_eq!(sixbit, decoded);
     }
 
+    #[cfg(feature = "serde")]
     #[test]
     fn test_serde_serialize_deserialize_human_readable() {
         use serde_json;
@@ -497,6 +2961,7 @@ mod tests {
         assert_eq!(sixbit, deserialized);
     }
 
+    #[cfg(feature = "serde")]
     #[test]
     fn test_serde_serialize_deserialize_binary() {
         use bincode;
@@ -540,6 +3005,783 @@ mod tests {
         assert!(sixbit_a < sixbit_b);
     }
 
+    #[test]
+    fn test_chars() {
+        for input in ["", "A", "AB", "ABC", "ABCD", "HELLO WORLD", "TEST    "] {
+            let sixbit = DecSixbit::new(input).unwrap();
+            assert!(sixbit.chars().eq(input.chars()), "Forward mismatch for '{}'", input);
+            assert!(sixbit.chars().rev().eq(input.chars().rev()), "Reverse mismatch for '{}'", input);
+            assert_eq!(sixbit.chars().len(), input.len());
+        }
+    }
+
+    #[test]
+    fn test_char_indices() {
+        for input in ["", "X", "AB", "ABC", "ABCD", "SPHINX OF BLACK QUARTZ", "PAD    "] {
+            let sixbit = DecSixbit::new(input).unwrap();
+            let decoded = sixbit.to_string();
+            assert!(sixbit.char_indices().eq(decoded.char_indices()), "Forward mismatch for '{}'", input);
+            assert!(sixbit.char_indices().rev().eq(decoded.char_indices().rev()), "Reverse mismatch for '{}'", input);
+            assert_eq!(sixbit.char_indices().len(), input.len());
+        }
+    }
+
+    #[test]
+    fn test_char_indices_mixed_ends() {
+        let sixbit = DecSixbit::new("ABCDE").unwrap();
+        let mut iter = sixbit.char_indices();
+        assert_eq!(iter.next(), Some((0, 'A')));
+        assert_eq!(iter.next_back(), Some((4, 'E')));
+        assert_eq!(iter.len(), 3);
+        assert_eq!(iter.next_back(), Some((3, 'D')));
+        assert_eq!(iter.next(), Some((1, 'B')));
+        assert_eq!(iter.next(), Some((2, 'C')));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next_back(), None);
+    }
+
+    #[test]
+    fn test_is_blank() {
+        assert!(DecSixbit::new("").unwrap().is_blank());
+        for len in 1..=8 {
+            let blank = " ".repeat(len);
+            assert!(DecSixbit::new(&blank).unwrap().is_blank(), "Expected blank for length {}", len);
+
+            let mut last_set = " ".repeat(len - 1);
+            last_set.push('X');
+            assert!(!DecSixbit::new(&last_set).unwrap().is_blank(), "Expected non-blank for '{}'", last_set);
+        }
+        assert!(!DecSixbit::new("TEST").unwrap().is_blank());
+    }
+
+    #[test]
+    fn test_is_blank_with_marker() {
+        let sixbit = DecSixbit::new("    ").unwrap();
+        assert_eq!(sixbit.as_bytes(), &[0, 0, 0, DecSixbit::TRAILING_SPACE_MARKER]);
+        assert!(sixbit.is_blank());
+    }
+
+    #[test]
+    fn test_content_classification() {
+        let cases = [
+            ("0123456789", true, false, true),
+            ("ABCDEFGHIJKLMNOPQRSTUVWXYZ", false, true, true),
+            ("RSX11M", false, false, true),
+            ("RSX-11M", false, false, false),
+            ("12 34", false, false, false),
+            ("A_B", false, false, false),
+            ("@", false, false, false),
+            ("[", false, false, false),
+            ("/", false, false, false),
+            (":", false, false, false),
+            ("", true, true, true),
+        ];
+        for (input, numeric, alphabetic, alphanumeric) in cases {
+            let sixbit = DecSixbit::new(input).unwrap();
+            assert_eq!(sixbit.is_numeric(), numeric, "is_numeric mismatch for '{}'", input);
+            assert_eq!(sixbit.is_alphabetic(), alphabetic, "is_alphabetic mismatch for '{}'", input);
+            assert_eq!(sixbit.is_alphanumeric(), alphanumeric, "is_alphanumeric mismatch for '{}'", input);
+        }
+    }
+
+    proptest::proptest! {
+        #[test]
+        fn prop_classification_matches_decoded(input in "[ -_]{0,24}") {
+            let sixbit = DecSixbit::new(&input).unwrap();
+            proptest::prop_assert_eq!(sixbit.is_numeric(), input.chars().all(|c| c.is_ascii_digit()));
+            proptest::prop_assert_eq!(sixbit.is_alphabetic(), input.chars().all(|c| c.is_ascii_uppercase()));
+            proptest::prop_assert_eq!(sixbit.is_alphanumeric(), input.chars().all(|c| c.is_ascii_alphanumeric()));
+        }
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(DecSixbit::new("   42 ").unwrap().parse::<u32>(), Ok(42));
+        assert_eq!(DecSixbit::new("  -17").unwrap().parse::<i32>(), Ok(-17));
+        assert_eq!(DecSixbit::new("7").unwrap().parse::<u8>(), Ok(7));
+        assert_eq!(DecSixbit::new("3.5   ").unwrap().parse::<f64>(), Ok(3.5));
+    }
+
+    #[test]
+    fn test_parse_blank() {
+        assert_eq!(DecSixbit::new("").unwrap().parse::<u32>(), Err(ParseFieldError::Blank));
+        assert_eq!(DecSixbit::new("    ").unwrap().parse::<u32>(), Err(ParseFieldError::Blank));
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        let expected = "12X4".parse::<u32>().unwrap_err();
+        assert_eq!(DecSixbit::new(" 12X4").unwrap().parse::<u32>(), Err(ParseFieldError::Invalid(expected)));
+        // Interior spaces are not trimmed
+        assert!(matches!(DecSixbit::new(" 1 2 ").unwrap().parse::<u32>(), Err(ParseFieldError::Invalid(_))));
+    }
+
+    #[test]
+    fn test_parse_long_field() {
+        let digits = "1".repeat(70);
+        let field = DecSixbit::new(&format!("  {}  ", digits)).unwrap();
+        assert_eq!(field.parse::<String>(), Ok(digits));
+    }
+
+    #[test]
+    fn test_from_uint_padded() {
+        assert_eq!(DecSixbit::from_uint_padded(1234, 4).unwrap().to_string(), "1234");
+        assert_eq!(DecSixbit::from_uint_padded(42, 6).unwrap().to_string(), "    42");
+        assert_eq!(DecSixbit::from_uint_padded(0, 3).unwrap().to_string(), "  0");
+        assert_eq!(DecSixbit::from_uint_padded(u64::MAX, 20).unwrap().to_string(), u64::MAX.to_string());
+        assert_eq!(DecSixbit::from_uint_padded(12345, 4), Err(Error::FieldOverflow));
+        assert_eq!(DecSixbit::from_uint_padded(0, 0), Err(Error::FieldOverflow));
+    }
+
+    #[test]
+    fn test_from_uint_padded_matches_new() {
+        for width in 1..=9 {
+            let field = DecSixbit::from_uint_padded(7, width).unwrap();
+            assert_eq!(field, DecSixbit::new(&format!("{:>width$}", 7, width = width)).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_from_uint_octal_padded() {
+        assert_eq!(DecSixbit::from_uint_octal_padded(0o777, 6).unwrap().to_string(), "   777");
+        assert_eq!(DecSixbit::from_uint_octal_padded(8, 2).unwrap().to_string(), "10");
+        assert_eq!(DecSixbit::from_uint_octal_padded(0, 1).unwrap().to_string(), "0");
+        assert_eq!(
+            DecSixbit::from_uint_octal_padded(u64::MAX, 22).unwrap().to_string(),
+            format!("{:o}", u64::MAX)
+        );
+        assert_eq!(DecSixbit::from_uint_octal_padded(0o100, 2), Err(Error::FieldOverflow));
+    }
+
+    #[test]
+    fn test_overwrite_field() {
+        let mut record = DecSixbit::new("NAME  XXXXEND").unwrap();
+        record.overwrite_field(6..10, 17).unwrap();
+        assert_eq!(record.to_string(), "NAME    17END");
+
+        record.overwrite_field(6..10, 9999).unwrap();
+        assert_eq!(record.to_string(), "NAME  9999END");
+
+        let before = record.clone();
+        assert_eq!(record.overwrite_field(6..10, 10000), Err(Error::FieldOverflow));
+        assert_eq!(record.overwrite_field(10..14, 1), Err(Error::OutOfBounds));
+        assert_eq!(record, before);
+    }
+
+    #[test]
+    fn test_overwrite_field_updates_marker() {
+        let mut record = DecSixbit::new("AB12").unwrap();
+        record.overwrite_field(2..4, 0).unwrap();
+        assert_eq!(record, DecSixbit::new("AB 0").unwrap());
+
+        let mut record = DecSixbit::new("AB  ").unwrap();
+        record.overwrite_field(2..4, 5).unwrap();
+        assert_eq!(record, DecSixbit::new("AB 5").unwrap());
+    }
+
+    #[test]
+    fn test_push() {
+        let input = "PUSHING CHARACTERS ONE BY ONE  ";
+        let mut sixbit = DecSixbit::default();
+        for end in 1..=input.len() {
+            sixbit.push(char::from(input.as_bytes()[end - 1])).unwrap();
+            assert_eq!(sixbit, DecSixbit::new(&input[..end]).unwrap(), "Mismatch after pushing '{}'", &input[..end]);
+        }
+        assert_eq!(sixbit.push('a'), Err(Error::InvalidCharacter));
+        assert_eq!(sixbit.to_string(), input);
+    }
+
+    #[test]
+    fn test_push_str() {
+        let fragments = ["A", "BC", "DEF", "    ", "G", "", "HIJKL "];
+        let mut sixbit = DecSixbit::default();
+        let mut expected = String::new();
+        for fragment in fragments {
+            sixbit.push_str(fragment).unwrap();
+            expected.push_str(fragment);
+            assert_eq!(sixbit, DecSixbit::new(&expected).unwrap(), "Mismatch after appending '{}'", fragment);
+        }
+
+        let before = sixbit.clone();
+        assert_eq!(sixbit.push_str("OK but lowercase"), Err(Error::InvalidCharacter));
+        assert_eq!(sixbit, before);
+    }
+
+    #[test]
+    fn test_truncate() {
+        let input = "TRUNCATE ME   ";
+        for new_len in 0..=input.len() + 1 {
+            let mut sixbit = DecSixbit::new(input).unwrap();
+            sixbit.truncate(new_len);
+            let expected = &input[..new_len.min(input.len())];
+            assert_eq!(sixbit, DecSixbit::new(expected).unwrap(), "Mismatch after truncating to {}", new_len);
+        }
+    }
+
+    #[test]
+    fn test_with_capacity() {
+        let mut sixbit = DecSixbit::with_capacity(100);
+        assert!(sixbit.capacity() >= 100);
+        let ptr = sixbit.as_bytes().as_ptr();
+        for _ in 0..25 {
+            sixbit.push_str("ABC ").unwrap();
+            assert_eq!(sixbit.as_bytes().as_ptr(), ptr, "Unexpected reallocation");
+        }
+        assert_eq!(sixbit.len(), 100);
+        assert!(sixbit.capacity() >= 100);
+    }
+
+    #[test]
+    fn test_reserve() {
+        let mut sixbit = DecSixbit::new("START").unwrap();
+        sixbit.reserve(40);
+        assert!(sixbit.capacity() >= 45);
+        let capacity = sixbit.capacity();
+        sixbit.push_str(&"X".repeat(40)).unwrap();
+        assert_eq!(sixbit.capacity(), capacity);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_from_buffer_rejects_wrapped_length() {
+        // The bit count of this length wraps around to 2, which would otherwise fit in one byte
+        let _ = DecSixbit::from_buffer(&[0x80][..], usize::MAX / 6 + 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "capacity overflow")]
+    fn test_reserve_overflow() {
+        DecSixbit::new("A").unwrap().reserve(usize::MAX);
+    }
+
+    #[test]
+    #[should_panic(expected = "capacity overflow")]
+    fn test_resize_overflow() {
+        // The bit count of this length wraps around to 2, which would otherwise fit in one byte
+        let _ = DecSixbit::new("A").unwrap().resize(usize::MAX / 6 + 1, ' ');
+    }
+
+    #[test]
+    fn test_capacity_accounts_for_marker() {
+        for chars in 0..=16 {
+            let mut sixbit = DecSixbit::with_capacity(chars);
+            assert!(sixbit.capacity() >= chars);
+            let ptr = sixbit.as_bytes().as_ptr();
+            sixbit.push_str(&" ".repeat(chars)).unwrap();
+            if chars != 0 {
+                assert_eq!(sixbit.as_bytes().as_ptr(), ptr, "Unexpected reallocation for {} spaces", chars);
+            }
+        }
+    }
+
+    #[test]
+    fn test_shrink_to_fit() {
+        let mut sixbit = DecSixbit::new(&"LONG VALUE ".repeat(10)).unwrap();
+        let capacity = sixbit.capacity();
+        sixbit.truncate(5);
+        assert_eq!(sixbit.capacity(), capacity);
+        sixbit.shrink_to_fit();
+        assert!(sixbit.capacity() < capacity);
+        assert!(sixbit.capacity() >= 5);
+        assert_eq!(sixbit.to_string(), "LONG ");
+    }
+
+    #[test]
+    fn test_reserved_capacity_is_unobservable() {
+        use std::hash::BuildHasher;
+        let state = std::hash::RandomState::new();
+        for input in ["", "A", "ABC", "ABC ", "TEST    ", "HELLO WORLD"] {
+            let exact = DecSixbit::new(input).unwrap();
+            let mut spare = DecSixbit::with_capacity(32);
+            spare.push_str(input).unwrap();
+            assert!(spare.buf().len() > exact.buf().len());
+            assert_eq!(spare, exact);
+            assert_eq!(spare.as_bytes(), exact.as_bytes());
+            assert_eq!(state.hash_one(&spare), state.hash_one(&exact));
+            assert_eq!(format!("{:?}", spare), format!("{:?}", exact));
+            #[cfg(feature = "serde")]
+            assert_eq!(bincode::serialize(&spare).unwrap(), bincode::serialize(&exact).unwrap());
+            assert_eq!(*spare.into_boxed_bytes(), *exact.as_bytes());
+        }
+    }
+
+    #[test]
+    fn test_new_allocates_exactly() {
+        for input in ["THE QUICK BROWN FOX JUMPS", "THE QUICK BROWN FOX JUMPS   ", "THE QUICK BROWN FOX JUMPS    "] {
+            let sixbit = DecSixbit::new(input).unwrap();
+            assert!(matches!(sixbit.repr, Repr::Heap { .. }));
+            assert_eq!(sixbit.buf().len(), sixbit.as_bytes().len());
+        }
+    }
+
+    #[test]
+    fn test_inline_threshold() {
+        for len in 0..=40 {
+            let input = "AB C".repeat(10)[..len].to_string();
+            let sixbit = DecSixbit::new(&input).unwrap();
+            assert_eq!(matches!(sixbit.repr, Repr::Inline { .. }), len <= 19, "Unexpected repr for {}", len);
+            assert_eq!(sixbit.len(), len);
+            assert_eq!(sixbit.to_string(), input);
+            assert_eq!(sixbit.as_bytes(), DecSixbit::try_from_slice(sixbit.as_bytes()).unwrap().as_bytes());
+        }
+    }
+
+    #[test]
+    fn test_constructors_agree_across_inline_threshold() {
+        for len in [18, 19, 20, 21, 23, 24, 25] {
+            for fill in ["AB C", "ABC ", "    "] {
+                let input = fill.repeat(7)[..len].to_string();
+                let expected = DecSixbit::new(&input).unwrap();
+                let (bytes, _) = crate::encode(&input).unwrap();
+                let mut pushed = DecSixbit::new("").unwrap();
+                pushed.push_str(&input).unwrap();
+                let mut shrunk = DecSixbit::with_capacity(100);
+                shrunk.push_str(&input).unwrap();
+                shrunk.shrink_to_fit();
+                let built = [
+                    DecSixbit::try_from_slice(expected.as_bytes()).unwrap(),
+                    DecSixbit::try_from_slice_strict(expected.as_bytes()).unwrap(),
+                    DecSixbit::from_parts(expected.as_bytes().to_vec(), len).unwrap(),
+                    DecSixbit::from_parts_lenient(bytes, len).unwrap(),
+                    pushed,
+                    shrunk,
+                ];
+                for sixbit in built {
+                    assert!(len <= 19 || matches!(sixbit.repr, Repr::Heap { .. }), "{input:?}");
+                    assert_eq!(sixbit.as_bytes(), expected.as_bytes(), "{input:?}");
+                    assert_eq!(sixbit.to_string(), input);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_inline_and_heap_are_indistinguishable() {
+        use std::hash::BuildHasher;
+        let state = std::hash::RandomState::new();
+        for input in ["", "A", "ABC ", "HELLO WORLD", "SEVENTEEN CHARS!!", "NINETEEN CHARACTERS"] {
+            let inline = DecSixbit::new(input).unwrap();
+            let mut heap = DecSixbit::with_capacity(100);
+            heap.push_str(input).unwrap();
+            assert!(matches!(inline.repr, Repr::Inline { .. }));
+            assert!(matches!(heap.repr, Repr::Heap { .. }));
+            assert_eq!(inline, heap);
+            assert_eq!(inline.cmp(&heap), Ordering::Equal);
+            assert_eq!(inline.as_bytes(), heap.as_bytes());
+            assert_eq!(state.hash_one(&inline), state.hash_one(&heap));
+            #[cfg(feature = "serde")]
+            {
+                assert_eq!(serde_json::to_string(&inline).unwrap(), serde_json::to_string(&heap).unwrap());
+                assert_eq!(bincode::serialize(&inline).unwrap(), bincode::serialize(&heap).unwrap());
+            }
+        }
+    }
+
+    #[test]
+    fn test_push_across_inline_threshold() {
+        let mut sixbit = DecSixbit::new("").unwrap();
+        let mut expected = String::new();
+        for c in "THE QUICK BROWN FOX JUMPS OVER    ".chars() {
+            sixbit.push(c).unwrap();
+            expected.push(c);
+            assert_eq!(sixbit, DecSixbit::new(&expected).unwrap());
+            assert_eq!(sixbit.as_bytes(), DecSixbit::new(&expected).unwrap().as_bytes());
+            assert_eq!(sixbit.to_string(), expected);
+        }
+        assert!(matches!(sixbit.repr, Repr::Heap { .. }));
+
+        sixbit.truncate(8);
+        sixbit.shrink_to_fit();
+        assert!(matches!(sixbit.repr, Repr::Inline { .. }));
+        assert_eq!(sixbit, DecSixbit::new("THE QUIC").unwrap());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_human_readable_value_forms() {
+        let sixbit = DecSixbit::new("HELLO").unwrap();
+
+        let value = serde_json::Value::String("HELLO".to_string());
+        assert_eq!(serde_json::from_value::<DecSixbit>(value).unwrap(), sixbit);
+
+        let packed = serde_json::to_string(sixbit.as_bytes()).unwrap();
+        assert_eq!(serde_json::from_str::<DecSixbit>(&packed).unwrap(), sixbit);
+
+        let value = serde_json::from_str::<serde_json::Value>(&packed).unwrap();
+        assert_eq!(serde_json::from_value::<DecSixbit>(value).unwrap(), sixbit);
+
+        assert!(serde_json::from_str::<DecSixbit>("[256]").is_err());
+        assert!(serde_json::from_str::<DecSixbit>("\"hello\"").is_err());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_untagged_enum() {
+        #[derive(Debug, PartialEq, serde::Deserialize)]
+        #[serde(untagged)]
+        enum Field {
+            Number(u32),
+            Name(DecSixbit),
+        }
+
+        assert_eq!(serde_json::from_str::<Field>("42").unwrap(), Field::Number(42));
+        assert_eq!(
+            serde_json::from_str::<Field>("\"FILE.DAT\"").unwrap(),
+            Field::Name(DecSixbit::new("FILE.DAT").unwrap())
+        );
+        assert!(serde_json::from_str::<Field>("\"file\"").is_err());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_binary_length_is_u64() {
+        use bincode::Options;
+        let sixbit = DecSixbit::new("HELLO WORLD").unwrap();
+        let fixint = bincode::DefaultOptions::new().with_fixint_encoding();
+
+        // The layout written by a 32-bit target is identical to that of a 64-bit one
+        let encoded = fixint.serialize(&sixbit).unwrap();
+        assert_eq!(encoded[..8], 11u64.to_le_bytes());
+        let written_by_32_bit = fixint.serialize(&(11u64, sixbit.as_bytes())).unwrap();
+        assert_eq!(encoded, written_by_32_bit);
+        assert_eq!(fixint.deserialize::<DecSixbit>(&written_by_32_bit).unwrap(), sixbit);
+
+        // So is the layout written by earlier versions, which serialized the length as `usize`
+        let legacy = fixint.serialize(&(sixbit.len(), sixbit.as_bytes().to_vec())).unwrap();
+        assert_eq!(fixint.deserialize::<DecSixbit>(&legacy).unwrap(), sixbit);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_binary_length_beyond_usize() {
+        let encoded = bincode::serialize(&(u64::MAX, vec![0u8; 3])).unwrap();
+        let error = bincode::deserialize::<DecSixbit>(&encoded).unwrap_err().to_string();
+        if usize::try_from(u64::MAX).is_err() {
+            assert!(error.contains("a length that fits in usize"), "{error}");
+        } else {
+            assert!(error.contains("input bytes and length are inconsistent"), "{error}");
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_binary_bincode_options() {
+        use bincode::Options;
+        let sixbit = DecSixbit::new(&"THE QUICK BROWN FOX JUMPS".repeat(4)).unwrap();
+        let fixint = bincode::DefaultOptions::new().with_fixint_encoding();
+        let varint = bincode::DefaultOptions::new().with_varint_encoding();
+
+        let encoded = fixint.serialize(&sixbit).unwrap();
+        assert_eq!(encoded.len(), 8 + 8 + 75);
+        assert_eq!(fixint.deserialize::<DecSixbit>(&encoded).unwrap(), sixbit);
+
+        let encoded = varint.serialize(&sixbit).unwrap();
+        assert_eq!(encoded.len(), 1 + 1 + 75);
+        assert_eq!(varint.deserialize::<DecSixbit>(&encoded).unwrap(), sixbit);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_binary_postcard() {
+        let sixbit = DecSixbit::new(&"THE QUICK BROWN FOX JUMPS".repeat(4)).unwrap();
+        let encoded = postcard::to_allocvec(&sixbit).unwrap();
+        assert_eq!(encoded.len(), 1 + 1 + 75);
+        assert_eq!(postcard::from_bytes::<DecSixbit>(&encoded).unwrap(), sixbit);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_binary_messagepack() {
+        let sixbit = DecSixbit::new(&"THE QUICK BROWN FOX JUMPS".repeat(4)).unwrap();
+        let encoded = rmp_serde::to_vec(&sixbit).unwrap();
+        // Array marker, length, and a bin8 header before the raw bytes
+        assert_eq!(encoded.len(), 1 + 1 + 2 + 75);
+        assert_eq!(rmp_serde::from_slice::<DecSixbit>(&encoded).unwrap(), sixbit);
+
+        // Earlier versions wrote the bytes as an array of integers
+        let legacy = rmp_serde::to_vec(&(sixbit.len(), sixbit.as_bytes().to_vec())).unwrap();
+        assert!(legacy.len() > encoded.len());
+        assert_eq!(rmp_serde::from_slice::<DecSixbit>(&legacy).unwrap(), sixbit);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_binary_rejects_inconsistent_length() {
+        let encoded = bincode::serialize(&(3usize, vec![0u8; 4])).unwrap();
+        assert!(bincode::deserialize::<DecSixbit>(&encoded).is_err());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_binary_rejects_malformed_payloads() {
+        let valid = DecSixbit::new("HELLO").unwrap();
+        let payloads: [(usize, Vec<u8>); 6] = [
+            // Length too long for the bytes
+            (6, valid.as_bytes().to_vec()),
+            // Length far beyond any allocation
+            (usize::MAX, valid.as_bytes().to_vec()),
+            (1 << 40, vec![]),
+            // Length too short for the bytes
+            (1, valid.as_bytes().to_vec()),
+            // Nonzero padding bits
+            (1, vec![0xFF]),
+            // Marker on a value that does not need it
+            (4, vec![0x86, 0x28, 0xe4, 0b11]),
+        ];
+        for (len, bytes) in payloads {
+            let encoded = bincode::serialize(&(len, &bytes)).unwrap();
+            let result = bincode::deserialize::<DecSixbit>(&encoded);
+            assert!(result.is_err(), "Accepted len={} bytes={:?}", len, bytes);
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_binary_malformed_never_reads_out_of_bounds() {
+        // Under Miri this checks that no malformed payload reaches the unchecked decoder. Before
+        // validation, (len=64, bytes=[]) deserialized and `to_string` read far past the buffer.
+        for len in [0, 1, 4, 5, 64, 1000] {
+            for bytes in [vec![], vec![0u8], vec![0u8; 3], vec![0u8; 4]] {
+                let encoded = bincode::serialize(&(len, &bytes)).unwrap();
+                if let Ok(sixbit) = bincode::deserialize::<DecSixbit>(&encoded) {
+                    assert_eq!(sixbit.to_string().len(), len);
+                    assert_eq!(sixbit.as_bytes(), bytes);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_count_char() {
+        let sixbit = DecSixbit::new("A,B,,C").unwrap();
+        assert_eq!(sixbit.count_char(','), 3);
+        assert_eq!(sixbit.count_char('A'), 1);
+        assert_eq!(sixbit.count_char('Z'), 0);
+        assert_eq!(sixbit.count_char('a'), 0);
+        assert_eq!(sixbit.count_char('😃'), 0);
+    }
+
+    #[test]
+    fn test_count_char_ignores_marker() {
+        let sixbit = DecSixbit::new("TEST    ").unwrap();
+        assert_eq!(sixbit.as_bytes().len(), 7);
+        assert_eq!(sixbit.count_char(' '), 4);
+        assert_eq!(sixbit.count_char('_'), 0);
+        assert_eq!(sixbit.char_frequencies().iter().sum::<usize>(), 8);
+    }
+
+    proptest::proptest! {
+        #[test]
+        fn prop_frequencies_match_decoded(input in "[ -_]{0,64}") {
+            let sixbit = DecSixbit::new(&input).unwrap();
+            let decoded = sixbit.to_string();
+            let frequencies = sixbit.char_frequencies();
+            for value in 0..64u8 {
+                let c = char::from(value + 32);
+                let expected = decoded.chars().filter(|&d| d == c).count();
+                proptest::prop_assert_eq!(frequencies[value as usize], expected);
+                proptest::prop_assert_eq!(sixbit.count_char(c), expected);
+            }
+        }
+    }
+
+    #[test]
+    fn test_common_prefix_len() {
+        let cases = [
+            ("", "", 0),
+            ("", "ABC", 0),
+            ("ABC", "ABC", 3),
+            ("ABCDEFGH", "ABCDEFGH", 8),
+            ("ABCD", "ABCDEFGH", 4),
+            ("ABCDE", "ABCDEFGH", 5),
+            ("XBCDEFGH", "ABCDEFGH", 0),
+            ("ABCDEFGX", "ABCDEFGH", 7),
+            ("ABC ", "ABC", 3),
+            ("ABC ", "ABC  ", 4),
+        ];
+        for (a, b, expected) in cases {
+            let a_sixbit = DecSixbit::new(a).unwrap();
+            let b_sixbit = DecSixbit::new(b).unwrap();
+            assert_eq!(a_sixbit.common_prefix_len(&b_sixbit), expected, "Mismatch for '{}' and '{}'", a, b);
+            assert_eq!(b_sixbit.common_prefix_len(&a_sixbit), expected, "Mismatch for '{}' and '{}'", b, a);
+        }
+    }
+
+    proptest::proptest! {
+        #[test]
+        fn prop_common_prefix_len(prefix in "[ -_]{0,12}", a in "[ -_]{0,12}", b in "[ -_]{0,12}") {
+            let a = format!("{}{}", prefix, a);
+            let b = format!("{}{}", prefix, b);
+            let expected = a.chars().zip(b.chars()).take_while(|(x, y)| x == y).count();
+            let a_sixbit = DecSixbit::new(&a).unwrap();
+            let b_sixbit = DecSixbit::new(&b).unwrap();
+            proptest::prop_assert_eq!(a_sixbit.common_prefix_len(&b_sixbit), expected);
+        }
+    }
+
+    #[test]
+    fn test_first_difference() {
+        let sixbit = |s: &str| DecSixbit::new(s).unwrap();
+        assert_eq!(sixbit("EQUAL").first_difference(&sixbit("EQUAL")), None);
+        assert_eq!(sixbit("").first_difference(&sixbit("")), None);
+        assert_eq!(sixbit("ABCDEFGH").first_difference(&sixbit("ABCDXFGH")), Some((4, Some('E'), Some('X'))));
+        assert_eq!(sixbit("ABC").first_difference(&sixbit("ABCDE")), Some((3, None, Some('D'))));
+        assert_eq!(sixbit("ABCDE").first_difference(&sixbit("ABC")), Some((3, Some('D'), None)));
+        assert_eq!(sixbit("").first_difference(&sixbit("A")), Some((0, None, Some('A'))));
+        assert_eq!(sixbit("ABC").first_difference(&sixbit("ABC ")), Some((3, None, Some(' '))));
+    }
+
+    #[test]
+    fn test_windows() {
+        let sixbit = DecSixbit::new("ABCDEFGHI").unwrap();
+        let windows: Vec<String> = sixbit.windows(4).map(|w| w.to_string()).collect();
+        assert_eq!(windows, ["ABCD", "BCDE", "CDEF", "DEFG", "EFGH", "FGHI"]);
+
+        let reversed: Vec<String> = sixbit.windows(4).rev().map(|w| w.to_string()).collect();
+        assert_eq!(reversed, ["FGHI", "EFGH", "DEFG", "CDEF", "BCDE", "ABCD"]);
+    }
+
+    #[test]
+    fn test_windows_larger_than_len() {
+        let sixbit = DecSixbit::new("ABC").unwrap();
+        assert_eq!(sixbit.windows(4).len(), 0);
+        assert_eq!(sixbit.windows(4).next(), None);
+        assert_eq!(sixbit.windows(3).len(), 1);
+    }
+
+    #[test]
+    fn test_windows_trailing_spaces() {
+        let sixbit = DecSixbit::new("AB      ").unwrap();
+        for window in sixbit.windows(4) {
+            assert_eq!(window, DecSixbit::new(&window.to_string()).unwrap());
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "window size must be non-zero")]
+    fn test_windows_zero_size() {
+        let sixbit = DecSixbit::new("ABC").unwrap();
+        let _ = sixbit.windows(0);
+    }
+
+    proptest::proptest! {
+        #[test]
+        fn prop_windows_match_decoded(input in "[ -_]{0,40}") {
+            let sixbit = DecSixbit::new(&input).unwrap();
+            let windows = sixbit.windows(3);
+            proptest::prop_assert_eq!(windows.len(), input.len().saturating_sub(2));
+
+            let actual: Vec<String> = windows.map(|w| w.to_string()).collect();
+            let expected: Vec<String> = input
+                .as_bytes()
+                .windows(3)
+                .map(|w| String::from_utf8(w.to_vec()).unwrap())
+                .collect();
+            proptest::prop_assert_eq!(actual, expected);
+        }
+    }
+
+    #[test]
+    fn test_ordering_is_lexicographic() {
+        let short = DecSixbit::new("B").unwrap();
+        let long = DecSixbit::new("AAAA").unwrap();
+        assert!(short > long);
+
+        let cases = [("AB", "AB "), ("ABC", "ABC "), ("ABC ", "ABC  "), ("ABC ", "ABC!"), ("", " ")];
+        for (smaller, larger) in cases {
+            let smaller_sixbit = DecSixbit::new(smaller).unwrap();
+            let larger_sixbit = DecSixbit::new(larger).unwrap();
+            assert!(smaller_sixbit < larger_sixbit, "Expected '{}' < '{}'", smaller, larger);
+        }
+    }
+
+    proptest::proptest! {
+        #[test]
+        fn prop_sort_slice_matches_decoded(inputs in proptest::collection::vec("[ -_]{0,10}", 0..32)) {
+            let mut items: Vec<DecSixbit> = inputs.iter().map(|s| DecSixbit::new(s).unwrap()).collect();
+            DecSixbit::sort_slice(&mut items);
+            let actual: Vec<String> = items.iter().map(|s| s.to_string()).collect();
+
+            let mut expected = inputs.clone();
+            expected.sort_unstable();
+            proptest::prop_assert_eq!(actual, expected);
+        }
+
+        #[test]
+        fn prop_lexicographic_cmp_matches_decoded(a in "[ -_]{0,10}", b in "[ -_]{0,10}") {
+            let a_sixbit = DecSixbit::new(&a).unwrap();
+            let b_sixbit = DecSixbit::new(&b).unwrap();
+            proptest::prop_assert_eq!(DecSixbit::lexicographic_cmp(&a_sixbit, &b_sixbit), a.cmp(&b));
+            proptest::prop_assert_eq!(a_sixbit.cmp(&b_sixbit), a.cmp(&b));
+        }
+    }
+
+    #[test]
+    fn test_cmp_with_str_binary_search() {
+        let inputs = ["AAA", "AAB", "B", "DSK", "DSKB", "DSKB ", "MTA0", "SYS", "_"];
+        let items: Vec<DecSixbit> = inputs.iter().map(|s| DecSixbit::new(s).unwrap()).collect();
+        for (i, input) in inputs.iter().enumerate() {
+            assert_eq!(items.binary_search_by(|x| x.cmp_with_str(input)), Ok(i), "Expected hit for '{}'", input);
+        }
+
+        let misses = [("", 0), ("AA", 0), ("AAC", 2), ("DSKA", 4), ("DSKB  ", 6), ("ZZZ", 8), ("a", 9), ("😃", 9)];
+        for (probe, index) in misses {
+            assert_eq!(items.binary_search_by(|x| x.cmp_with_str(probe)), Err(index), "Expected miss for '{}'", probe);
+        }
+    }
+
+    proptest::proptest! {
+        #[test]
+        fn prop_cmp_with_str_consistent_with_ord(a in "[ -_]{0,10}", b in "[ -_]{0,10}") {
+            let a_sixbit = DecSixbit::new(&a).unwrap();
+            let b_sixbit = DecSixbit::new(&b).unwrap();
+            proptest::prop_assert_eq!(a_sixbit.cmp_with_str(&b), a_sixbit.cmp(&b_sixbit));
+            proptest::prop_assert_eq!(a_sixbit.cmp_with_str(&a), Ordering::Equal);
+        }
+
+        #[test]
+        fn prop_cmp_with_str_arbitrary_probe(a in "[ -_]{0,10}", probe in ".{0,10}") {
+            let a_sixbit = DecSixbit::new(&a).unwrap();
+            proptest::prop_assert_eq!(a_sixbit.cmp_with_str(&probe), a.as_str().cmp(probe.as_str()));
+        }
+    }
+
+    #[test]
+    fn test_raw_parts_round_trip() {
+        // Under Miri this checks the reconstruction reads only the reported bytes, for inline and heap values
+        let inputs = ["", "A", "ABC ", "HELLO", "ABCDEFGHIJKLMNOPQRS", "ABCDEFGHIJKLMNOPQRST", "ABCDEFGHIJKLMNOPQRSTUVWXYZ01234 "];
+        for input in inputs {
+            let sixbit = DecSixbit::new(input).unwrap();
+            let (ptr, byte_len, char_len) = sixbit.as_raw_parts();
+            assert_eq!((byte_len, char_len), (crate::encoded_len(input.len()), input.len()));
+            assert_eq!(unsafe { std::slice::from_raw_parts(ptr, byte_len) }, &sixbit.as_bytes()[..byte_len]);
+            let copy = unsafe { DecSixbit::from_raw_parts(ptr, byte_len, char_len) }.unwrap();
+            assert_eq!(copy, sixbit);
+            assert_eq!(copy.as_bytes(), sixbit.as_bytes());
+        }
+    }
+
+    #[test]
+    fn test_raw_parts_exclude_marker() {
+        let sixbit = DecSixbit::new("ABC ").unwrap();
+        assert_eq!(sixbit.as_bytes().len(), 4);
+        let (ptr, byte_len, char_len) = sixbit.as_raw_parts();
+        assert_eq!((byte_len, char_len), (3, 4));
+        assert_eq!(crate::decode(unsafe { std::slice::from_raw_parts(ptr, byte_len) }, char_len).unwrap(), "ABC ");
+    }
+
+    #[test]
+    fn test_from_raw_parts_errors() {
+        let bytes = [0x86, 0x28, 0xe4, DecSixbit::TRAILING_SPACE_MARKER];
+        unsafe {
+            assert_eq!(DecSixbit::from_raw_parts(std::ptr::null(), 0, 0), Ok(DecSixbit::default()));
+            assert_eq!(DecSixbit::from_raw_parts(bytes.as_ptr(), 2, 4), Err(Error::InvalidBytesLength));
+            // The marker is not part of the payload
+            assert_eq!(DecSixbit::from_raw_parts(bytes.as_ptr(), 4, 4), Err(Error::InvalidBytesLength));
+            assert_eq!(DecSixbit::from_raw_parts([0xFF].as_ptr(), 1, 1), Err(Error::NonzeroPadding));
+        }
+    }
+
     #[test]
     fn test_hash() {
         use std::collections::HashSet;
@@ -0,0 +1,50 @@
+//! Inputs shared by the tests of the serialization formats and integrations, so each of them only has
+//! to cover what is specific to its own encoding.
+
+#[cfg(feature = "with-struct")]
+use crate::DecSixbit;
+
+/// Strings covering the empty value, partial and whole 4-character groups, trailing spaces that need
+/// the trailing-space marker, and lengths on both sides of the inline storage limit of 19 characters.
+pub(crate) const INPUTS: [&str; 16] = [
+    "",
+    " ",
+    "A",
+    "AB",
+    "ABC",
+    "ABC ",
+    "TEST    ",
+    "HELLO WORLD",
+    "NINETEEN CHARACTERS",
+    "NINETEEN CHARS     ",
+    "TWENTY CHARACTERS!!!",
+    "TWENTY CHARS        ",
+    "TWENTY-ONE CHARS     ",
+    "TWENTY-FOUR CHARS       ",
+    "SPHINX OF BLACK QUARTZ, JUDGE MY VOW?",
+    "THE QUICK BROWN FOX JUMPS OVER THE LAZY DOG    ",
+];
+
+/// Returns [`INPUTS`] as `DecSixbit` values.
+#[cfg(feature = "with-struct")]
+pub(crate) fn values() -> Vec<DecSixbit> {
+    INPUTS.iter().map(|input| DecSixbit::new(input).unwrap()).collect()
+}
+
+/// Asserts that `round_trip` gives back every value of [`INPUTS`] unchanged, down to its stored bytes.
+#[cfg(feature = "with-struct")]
+pub(crate) fn assert_round_trips<E: core::fmt::Debug>(mut round_trip: impl FnMut(&DecSixbit) -> Result<DecSixbit, E>) {
+    for input in INPUTS {
+        let sixbit = DecSixbit::new(input).unwrap();
+        let decoded = round_trip(&sixbit).unwrap_or_else(|error| panic!("{input:?}: {error:?}"));
+        assert_eq!(decoded, sixbit, "{input:?}");
+        assert_eq!(decoded.as_bytes(), sixbit.as_bytes(), "{input:?}");
+    }
+}
+
+#[test]
+fn test_inputs_cross_storage_boundaries() {
+    for len in [19, 20, 21, 24] {
+        assert!(INPUTS.iter().any(|input| input.len() == len && input.ends_with(' ')), "{len}");
+    }
+}
@@ -0,0 +1,139 @@
+//! [wasm-bindgen](https://docs.rs/wasm-bindgen) bindings for using the encoder and decoder from
+//! JavaScript.
+//!
+//! The functions are exported to JavaScript as `encode`, `encodedLen`, and `decode`, and the class as
+//! `DecSixbit`. Errors become a JavaScript `Error` carrying the message of the [`Error`](crate::Error).
+//!
+//! ```js
+//! import { encode, encodedLen, decode, DecSixbit } from "dec-sixbit";
+//!
+//! const bytes = encode("HELLO");          // Uint8Array(4)
+//! decode(bytes, 5);                       // "HELLO"
+//! new DecSixbit("HELLO").length;          // 5
+//! ```
+
+use crate::DecSixbit;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use wasm_bindgen::prelude::*;
+
+/// Encodes a string into packed SIXBIT bytes, like [`encode`](crate::encode).
+///
+/// Only the bytes are returned; the length to decode them with is the length of `input`.
+///
+/// # Errors
+/// Throws if the input contains characters outside the range 32-95.
+#[wasm_bindgen(js_name = encode)]
+pub fn js_encode(input: &str) -> Result<Vec<u8>, JsError> {
+    Ok(crate::encode(input)?.0)
+}
+
+/// Returns the number of packed bytes needed to encode `len` characters, like
+/// [`encoded_len`](crate::encoded_len).
+#[wasm_bindgen(js_name = encodedLen)]
+pub fn js_encoded_len(len: usize) -> usize {
+    crate::encoded_len(len)
+}
+
+/// Decodes `len` characters from packed SIXBIT bytes, like [`decode`](crate::decode).
+///
+/// # Errors
+/// Throws if the number of bytes is inconsistent with `len`.
+#[wasm_bindgen(js_name = decode)]
+pub fn js_decode(bytes: &[u8], len: usize) -> Result<String, JsError> {
+    Ok(crate::decode(bytes, len)?)
+}
+
+/// A [`DecSixbit`] exported to JavaScript as the `DecSixbit` class.
+#[wasm_bindgen(js_name = DecSixbit)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JsDecSixbit(DecSixbit);
+
+#[wasm_bindgen(js_class = DecSixbit)]
+impl JsDecSixbit {
+    /// Encodes `input`, like [`DecSixbit::new`].
+    ///
+    /// # Errors
+    /// Throws if the input contains characters outside the range 32-95.
+    #[wasm_bindgen(constructor)]
+    pub fn new(input: &str) -> Result<JsDecSixbit, JsError> {
+        Ok(Self(DecSixbit::new(input)?))
+    }
+
+    /// Wraps `len` characters of packed bytes, like [`DecSixbit::from_parts`].
+    ///
+    /// # Errors
+    /// Throws if the bytes are not the canonical encoding of `len` characters.
+    #[wasm_bindgen(js_name = fromParts)]
+    pub fn from_parts(bytes: Vec<u8>, len: usize) -> Result<JsDecSixbit, JsError> {
+        Ok(Self(DecSixbit::from_parts(bytes, len)?))
+    }
+
+    /// Returns the decoded string.
+    #[wasm_bindgen(js_name = toString)]
+    pub fn to_js_string(&self) -> String {
+        self.0.to_string()
+    }
+
+    /// Returns the number of characters.
+    #[wasm_bindgen(getter)]
+    pub fn length(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns a copy of the packed bytes, including any trailing-space marker byte.
+    #[wasm_bindgen(getter)]
+    pub fn bytes(&self) -> Vec<u8> {
+        self.0.as_bytes().to_vec()
+    }
+}
+
+impl From<DecSixbit> for JsDecSixbit {
+    fn from(sixbit: DecSixbit) -> Self {
+        Self(sixbit)
+    }
+}
+
+impl From<JsDecSixbit> for DecSixbit {
+    fn from(sixbit: JsDecSixbit) -> Self {
+        sixbit.0
+    }
+}
+
+/// Run with `wasm-pack test --node -- --features wasm`. The tests that don't throw also run natively.
+#[cfg(test)]
+mod tests {
+    use super::{js_decode, js_encode, js_encoded_len, JsDecSixbit};
+    #[cfg(target_arch = "wasm32")]
+    use wasm_bindgen_test::wasm_bindgen_test;
+
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn test_round_trip() {
+        for input in ["", "A", "ABC ", "HELLO", "SYS:LOGIN.SAV"] {
+            let bytes = js_encode(input).unwrap();
+            assert_eq!(bytes.len(), js_encoded_len(input.len()));
+            assert_eq!(js_decode(&bytes, input.len()).unwrap(), input);
+        }
+    }
+
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn test_class() {
+        let sixbit = JsDecSixbit::new("ABCD    ").unwrap();
+        assert_eq!(sixbit.to_js_string(), "ABCD    ");
+        assert_eq!(sixbit.length(), 8);
+        let copy = JsDecSixbit::from_parts(sixbit.bytes(), sixbit.length()).unwrap();
+        assert_eq!(copy, sixbit);
+    }
+
+    /// Creating a `JsError` calls into JavaScript, so this only runs under wasm.
+    #[cfg(target_arch = "wasm32")]
+    #[wasm_bindgen_test]
+    fn test_errors() {
+        assert!(js_encode("lower").is_err());
+        assert!(js_decode(&[0, 0], 4).is_err());
+        assert!(JsDecSixbit::new("~").is_err());
+        assert!(JsDecSixbit::from_parts(vec![0xFF], 1).is_err());
+    }
+}
@@ -0,0 +1,379 @@
+//! Provides the `WordLayout` type for PDP-10 36-bit words stored in 64-bit containers.
+//!
+//! Tools that extract PDP-10 memory or tape images into 64-bit integers disagree on where the 36 bits
+//! go: SIMH keeps them right-justified, some formats left-justify them, and others store the left and
+//! right 18-bit halves in separate 32-bit halves of the container. [`WordLayout`] converts between these
+//! and the plain 36-bit value, which holds 6 SIXBIT characters with the first in the most significant bits.
+//!
+//! [`encode_tops10_filename`] and [`decode_tops10_filename`] build the two plain words TOPS-10 uses for a
+//! 6.3 file name, as in the `.RBNAM` and `.RBEXT` words of a `LOOKUP` block.
+
+use crate::{char_to_value, Error};
+#[cfg(feature = "alloc")]
+use crate::value_to_char;
+#[cfg(feature = "with-struct")]
+use crate::{bits, DecSixbit, DecSixbitStr};
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+#[cfg(feature = "with-struct")]
+use alloc::{vec, vec::Vec};
+
+/// The 36 significant bits of a word.
+const WORD_MASK: u64 = (1 << 36) - 1;
+/// The 18 significant bits of a half word.
+const HALF_MASK: u64 = (1 << 18) - 1;
+
+/// Where the 36 bits of a PDP-10 word are stored in a `u64`.
+///
+/// # Examples
+///
+/// ```rust
+/// use dec_sixbit::WordLayout;
+///
+/// // SIXBIT /SYSTAT/ as SIMH stores it, and as two 18-bit halves
+/// let simh = 0o637163644164;
+/// let halves = WordLayout::RightJustified.convert(simh, WordLayout::Halves { big_endian: true }).unwrap();
+/// assert_eq!(halves, 0o637163 << 32 | 0o644164);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum WordLayout {
+    /// The word fills the low 36 bits and the high 28 bits are zero, as in SIMH memory images.
+    RightJustified,
+    /// The word fills the high 36 bits and the low 28 bits are zero.
+    LeftJustified,
+    /// The left and right 18-bit halves are each right-justified in a 32-bit half of the container.
+    Halves {
+        /// Whether the left half of the word is in the high 32 bits of the container.
+        big_endian: bool,
+    },
+}
+
+impl WordLayout {
+    /// Extracts the 36-bit word from a container in this layout.
+    ///
+    /// # Errors
+    /// Returns an [`Error::NonzeroPadding`] if any bit outside the word's position in the layout is set.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use dec_sixbit::{Error, WordLayout};
+    ///
+    /// assert_eq!(WordLayout::LeftJustified.unpack(0o446353 << 46), Ok(0o446353000000));
+    /// assert_eq!(WordLayout::RightJustified.unpack(1 << 36), Err(Error::NonzeroPadding));
+    /// ```
+    pub fn unpack(self, container: u64) -> Result<u64, Error> {
+        let (word, stray) = match self {
+            WordLayout::RightJustified => (container, container & !WORD_MASK),
+            WordLayout::LeftJustified => (container >> 28, container & ((1 << 28) - 1)),
+            WordLayout::Halves { big_endian } => {
+                let (left, right) = if big_endian { (container >> 32, container & 0xFFFF_FFFF) } else { (container & 0xFFFF_FFFF, container >> 32) };
+                ((left & HALF_MASK) << 18 | (right & HALF_MASK), (left | right) & !HALF_MASK)
+            },
+        };
+        if stray != 0 {
+            return Err(Error::NonzeroPadding);
+        }
+        Ok(word)
+    }
+
+    /// Stores a 36-bit word in a container in this layout.
+    ///
+    /// # Errors
+    /// Returns an [`Error::FieldOverflow`] if `word` doesn't fit in 36 bits.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use dec_sixbit::WordLayout;
+    ///
+    /// let layout = WordLayout::Halves { big_endian: false };
+    /// assert_eq!(layout.pack(0o637163644164), Ok(0o644164 << 32 | 0o637163));
+    /// ```
+    pub fn pack(self, word: u64) -> Result<u64, Error> {
+        if word & !WORD_MASK != 0 {
+            return Err(Error::FieldOverflow);
+        }
+        Ok(self.place(word))
+    }
+
+    /// Stores a word that is known to fit in 36 bits.
+    #[inline(always)]
+    fn place(self, word: u64) -> u64 {
+        match self {
+            WordLayout::RightJustified => word,
+            WordLayout::LeftJustified => word << 28,
+            WordLayout::Halves { big_endian: true } => (word >> 18) << 32 | (word & HALF_MASK),
+            WordLayout::Halves { big_endian: false } => (word & HALF_MASK) << 32 | (word >> 18),
+        }
+    }
+
+    /// Moves a word from a container in this layout to one in the layout `to`.
+    ///
+    /// # Errors
+    /// Returns an [`Error::NonzeroPadding`] if any bit outside the word's position in this layout is set.
+    pub fn convert(self, container: u64, to: WordLayout) -> Result<u64, Error> {
+        to.pack(self.unpack(container)?)
+    }
+}
+
+/// Encodes a TOPS-10 file name and extension into their two 36-bit words.
+///
+/// The name fills the first word and the extension the left half of the second, each padded with
+/// trailing spaces; the right half of the extension word is zero. Both words are plain 36-bit values,
+/// which [`WordLayout::pack`] stores in any layout.
+///
+/// Lowercase letters are rejected; [`encode_tops10_filename_uppercase`] folds them to uppercase instead.
+///
+/// # Parameters
+/// - `name`: The file name, up to 6 characters.
+/// - `ext`: The extension, up to 3 characters, without the dot.
+///
+/// # Errors
+/// - Returns an [`Error::TooLong`] if `name` is longer than 6 characters or `ext` longer than 3.
+/// - Returns an [`Error::InvalidCharacter`] if either contains characters outside the valid range.
+///
+/// # Examples
+///
+/// ```rust
+/// use dec_sixbit::encode_tops10_filename;
+///
+/// assert_eq!(encode_tops10_filename("PIP", "SAV"), Ok((0o605160000000, 0o634166000000)));
+/// ```
+pub fn encode_tops10_filename(name: &str, ext: &str) -> Result<(u64, u64), Error> {
+    Ok((pack_field(name, 6, false)?, pack_field(ext, 3, false)? << 18))
+}
+
+/// Encodes a TOPS-10 file name and extension like [`encode_tops10_filename`], folding lowercase
+/// letters to uppercase.
+///
+/// # Errors
+/// - Returns an [`Error::TooLong`] if `name` is longer than 6 characters or `ext` longer than 3.
+/// - Returns an [`Error::InvalidCharacter`] if either contains characters outside the valid range after folding.
+///
+/// # Examples
+///
+/// ```rust
+/// use dec_sixbit::{encode_tops10_filename, encode_tops10_filename_uppercase};
+///
+/// assert_eq!(encode_tops10_filename_uppercase("login", "Exe"), encode_tops10_filename("LOGIN", "EXE"));
+/// ```
+pub fn encode_tops10_filename_uppercase(name: &str, ext: &str) -> Result<(u64, u64), Error> {
+    Ok((pack_field(name, 6, true)?, pack_field(ext, 3, true)? << 18))
+}
+
+/// Decodes the two 36-bit words of a TOPS-10 file name into the name and extension, with the space
+/// padding trimmed.
+///
+/// Only the left half of the extension word is read, since the right half holds other file data in a
+/// `LOOKUP` block.
+///
+/// This function requires the `alloc` feature, which is enabled by default.
+///
+/// # Errors
+/// Returns an [`Error::NonzeroPadding`] if either word has bits set above the low 36.
+///
+/// # Examples
+///
+/// ```rust
+/// use dec_sixbit::decode_tops10_filename;
+///
+/// let (name, ext) = decode_tops10_filename(0o545747515600, 0o457045000123).unwrap();
+/// assert_eq!((name.as_str(), ext.as_str()), ("LOGIN", "EXE"));
+/// ```
+#[cfg(feature = "alloc")]
+pub fn decode_tops10_filename(name: u64, ext: u64) -> Result<(String, String), Error> {
+    let name = WordLayout::RightJustified.unpack(name)?;
+    let ext = WordLayout::RightJustified.unpack(ext)? >> 18;
+    Ok((unpack_field(name, 6), unpack_field(ext, 3)))
+}
+
+/// Packs up to `max` characters into the low `6 * max` bits of a word, padded with trailing spaces.
+fn pack_field(str: &str, max: usize, uppercase: bool) -> Result<u64, Error> {
+    if str.len() > max {
+        return Err(Error::TooLong { len: str.len(), max });
+    }
+    let bytes = str.as_bytes();
+    (0..max).try_fold(0u64, |word, i| {
+        let c = bytes.get(i).map_or(b' ', |&c| if uppercase { c.to_ascii_uppercase() } else { c });
+        let value = char_to_value(c as char).ok_or(Error::InvalidCharacter)?;
+        Ok(word << 6 | value as u64)
+    })
+}
+
+/// Unpacks the `count` characters in the low bits of a word, trimming the trailing spaces.
+#[cfg(feature = "alloc")]
+fn unpack_field(word: u64, count: usize) -> String {
+    let mut str: String = (0..count)
+        .filter_map(|i| value_to_char((word >> (6 * (count - 1 - i))) as u8 & 0b111111))
+        .collect();
+    str.truncate(str.trim_end_matches(' ').len());
+    str
+}
+
+#[cfg(feature = "with-struct")]
+impl DecSixbitStr {
+    /// Packs the characters into 36-bit words of 6 characters each, stored in `layout`.
+    ///
+    /// The first character is in the most significant bits of the first word, and the final word is
+    /// padded with spaces. [`DecSixbit::from_words36`] is the inverse.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use dec_sixbit::{DecSixbit, WordLayout};
+    ///
+    /// let sixbit = DecSixbit::new("DSK").unwrap();
+    /// assert_eq!(sixbit.to_words36(WordLayout::RightJustified), [0o446353000000]);
+    /// ```
+    pub fn to_words36(&self, layout: WordLayout) -> Vec<u64> {
+        let len = self.len();
+        (0..len.div_ceil(6))
+            .map(|word_idx| {
+                let word = (word_idx * 6..word_idx * 6 + 6)
+                    .map(|i| if i < len { bits::value_at(self.as_bytes(), i) } else { 0 })
+                    .fold(0u64, |acc, value| (acc << 6) | value as u64);
+                layout.place(word)
+            })
+            .collect()
+    }
+}
+
+#[cfg(feature = "with-struct")]
+impl DecSixbit {
+    /// Creates a `DecSixbit` from 36-bit words of 6 characters each, stored in `layout`.
+    ///
+    /// This is the inverse of [`DecSixbitStr::to_words36`]; the result is always 6 characters per word,
+    /// including any space padding of the final word.
+    ///
+    /// # Errors
+    /// Returns an [`Error::NonzeroPadding`] if a word has bits set outside its position in `layout`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use dec_sixbit::{DecSixbit, WordLayout};
+    ///
+    /// let sixbit = DecSixbit::from_words36(&[0o637163644164 << 28], WordLayout::LeftJustified).unwrap();
+    /// assert_eq!(sixbit.to_string(), "SYSTAT");
+    /// ```
+    pub fn from_words36(words: &[u64], layout: WordLayout) -> Result<Self, Error> {
+        let len = words.len() * 6;
+        let mut bytes = vec![0u8; bits::bytes_for_chars(len)];
+        for (word_idx, &container) in words.iter().enumerate() {
+            let word = layout.unpack(container)?;
+            for char_idx in 0..6 {
+                let value = (word >> (30 - 6 * char_idx)) as u8 & 0b111111;
+                bits::set_value(&mut bytes, word_idx * 6 + char_idx, value);
+            }
+        }
+        Ok(Self::from_encoded(bytes, len))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{encode_tops10_filename, encode_tops10_filename_uppercase, WordLayout};
+    use crate::Error;
+
+    /// SIXBIT /SYSTAT/ as a 36-bit word.
+    const SYSTAT: u64 = 0o637163644164;
+
+    const LAYOUTS: [WordLayout; 4] = [
+        WordLayout::RightJustified,
+        WordLayout::LeftJustified,
+        WordLayout::Halves { big_endian: true },
+        WordLayout::Halves { big_endian: false },
+    ];
+
+    #[test]
+    fn test_layouts() {
+        assert_eq!(WordLayout::RightJustified.pack(SYSTAT), Ok(SYSTAT));
+        assert_eq!(WordLayout::LeftJustified.pack(SYSTAT), Ok(SYSTAT << 28));
+        assert_eq!(WordLayout::Halves { big_endian: true }.pack(SYSTAT), Ok(0o637163 << 32 | 0o644164));
+        assert_eq!(WordLayout::Halves { big_endian: false }.pack(SYSTAT), Ok(0o644164 << 32 | 0o637163));
+    }
+
+    #[test]
+    fn test_round_trip() {
+        for layout in LAYOUTS {
+            for word in [0, 1, SYSTAT, 1 << 35, (1 << 36) - 1] {
+                assert_eq!(layout.unpack(layout.pack(word).unwrap()), Ok(word), "{layout:?} {word:o}");
+            }
+            assert_eq!(layout.pack(1 << 36), Err(Error::FieldOverflow), "{layout:?}");
+        }
+    }
+
+    #[test]
+    fn test_convert_between_layouts() {
+        let its = WordLayout::Halves { big_endian: true };
+        let simh = WordLayout::RightJustified;
+        assert_eq!(its.convert(0o637163 << 32 | 0o644164, simh), Ok(SYSTAT));
+        for from in LAYOUTS {
+            for to in LAYOUTS {
+                assert_eq!(from.convert(from.pack(SYSTAT).unwrap(), to), to.pack(SYSTAT), "{from:?} -> {to:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_stray_bits_rejected() {
+        assert_eq!(WordLayout::RightJustified.unpack(SYSTAT | 1 << 36), Err(Error::NonzeroPadding));
+        assert_eq!(WordLayout::RightJustified.unpack(1 << 63), Err(Error::NonzeroPadding));
+        assert_eq!(WordLayout::LeftJustified.unpack(SYSTAT << 28 | 1), Err(Error::NonzeroPadding));
+        assert_eq!(WordLayout::LeftJustified.unpack(1 << 27), Err(Error::NonzeroPadding));
+        assert_eq!(WordLayout::Halves { big_endian: true }.unpack(1 << 18), Err(Error::NonzeroPadding));
+        assert_eq!(WordLayout::Halves { big_endian: false }.unpack(1 << 50), Err(Error::NonzeroPadding));
+    }
+
+    #[test]
+    fn test_tops10_filename_words() {
+        assert_eq!(encode_tops10_filename("PIP", "SAV"), Ok((0o605160000000, 0o634166000000)));
+        assert_eq!(encode_tops10_filename("LOGIN", "EXE"), Ok((0o545747515600, 0o457045000000)));
+        assert_eq!(encode_tops10_filename("SYSTAT", ""), Ok((SYSTAT, 0)));
+        assert_eq!(encode_tops10_filename("", ""), Ok((0, 0)));
+        assert_eq!(encode_tops10_filename_uppercase("Pip", "sav"), encode_tops10_filename("PIP", "SAV"));
+    }
+
+    #[test]
+    fn test_tops10_filename_errors() {
+        assert_eq!(encode_tops10_filename("SYSTATS", "EXE"), Err(Error::TooLong { len: 7, max: 6 }));
+        assert_eq!(encode_tops10_filename("LOGIN", "EXEC"), Err(Error::TooLong { len: 4, max: 3 }));
+        assert_eq!(encode_tops10_filename("login", "EXE"), Err(Error::InvalidCharacter));
+        assert_eq!(encode_tops10_filename("PIP", "sav"), Err(Error::InvalidCharacter));
+        assert_eq!(encode_tops10_filename_uppercase("PIP~", "SAV"), Err(Error::InvalidCharacter));
+        assert_eq!(encode_tops10_filename_uppercase("PIPÉ", "SAV"), Err(Error::InvalidCharacter));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_tops10_filename_round_trip() {
+        use super::decode_tops10_filename;
+
+        for (name, ext) in [("PIP", "SAV"), ("LOGIN", "EXE"), ("SYSTAT", ""), ("", "LOW"), ("A B", "C")] {
+            let (name_word, ext_word) = encode_tops10_filename(name, ext).unwrap();
+            assert_eq!(decode_tops10_filename(name_word, ext_word), Ok((name.to_string(), ext.to_string())));
+        }
+        assert_eq!(decode_tops10_filename(0o605160000000, 0o634166777777), Ok(("PIP".to_string(), "SAV".to_string())));
+        assert_eq!(decode_tops10_filename(1 << 36, 0), Err(Error::NonzeroPadding));
+        assert_eq!(decode_tops10_filename(0, 1 << 63), Err(Error::NonzeroPadding));
+    }
+
+    #[cfg(feature = "with-struct")]
+    #[test]
+    fn test_words36_round_trip() {
+        use crate::DecSixbit;
+
+        let sixbit = DecSixbit::new("DSKB:SYSTAT.LOW").unwrap();
+        for layout in LAYOUTS {
+            let words = sixbit.to_words36(layout);
+            assert_eq!(words.len(), 3);
+            assert_eq!(DecSixbit::from_words36(&words, layout).unwrap().to_string(), "DSKB:SYSTAT.LOW   ");
+        }
+        assert_eq!(DecSixbit::new("SYSTAT").unwrap().to_words36(WordLayout::RightJustified), [SYSTAT]);
+        let octal: Vec<u64> = sixbit.to_octal_words().iter().map(|word| u64::from_str_radix(word, 8).unwrap()).collect();
+        assert_eq!(sixbit.to_words36(WordLayout::RightJustified), octal);
+        assert_eq!(DecSixbit::from_words36(&[1 << 36], WordLayout::RightJustified), Err(Error::NonzeroPadding));
+        assert_eq!(DecSixbit::from_words36(&[], WordLayout::LeftJustified), Ok(DecSixbit::default()));
+    }
+}
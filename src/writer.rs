@@ -0,0 +1,174 @@
+//! Implements [`fmt::Write`] for appending formatted text to a `DecSixbit`.
+//!
+//! `write!(sixbit, ...)` packs each fragment as it is formatted, without an intermediate `String`.
+//! A write that fails leaves the value as it was before the call, so it never holds half a fragment.
+//! Since `fmt::Error` carries no payload, [`SixbitWriter`] additionally records the [`Error`] that made
+//! the last write fail.
+
+use crate::{DecSixbit, Error};
+use core::fmt;
+
+/// A [`fmt::Write`] adapter over a `DecSixbit` that remembers why the last write failed.
+///
+/// This struct is created by [`DecSixbit::writer`]. Writing through it behaves exactly like writing to
+/// the `DecSixbit` itself.
+///
+/// # Examples
+///
+/// ```rust
+/// use dec_sixbit::{DecSixbit, Error};
+/// use std::fmt::Write;
+///
+/// let mut sixbit = DecSixbit::new("STEP ").unwrap();
+/// let mut writer = sixbit.writer();
+/// assert!(write!(writer, "{}", "lowercase").is_err());
+/// assert_eq!(writer.last_error(), Some(Error::InvalidCharacter));
+/// assert_eq!(sixbit.to_string(), "STEP ");
+/// ```
+#[derive(Debug)]
+pub struct SixbitWriter<'a> {
+    sixbit: &'a mut DecSixbit,
+    last_error: Option<Error>,
+}
+
+impl SixbitWriter<'_> {
+    /// Returns the error that made the last write fail.
+    ///
+    /// # Returns
+    /// `None` if the last write succeeded, or if it failed because a formatting implementation
+    /// returned an error rather than because of the text it produced.
+    #[inline]
+    pub fn last_error(&self) -> Option<Error> {
+        self.last_error
+    }
+}
+
+impl fmt::Write for SixbitWriter<'_> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        // `push_str` validates the whole fragment before packing any of it
+        self.sixbit.push_str(s).map_err(|error| {
+            self.last_error = Some(error);
+            fmt::Error
+        })
+    }
+
+    fn write_char(&mut self, c: char) -> fmt::Result {
+        self.sixbit.push(c).map_err(|error| {
+            self.last_error = Some(error);
+            fmt::Error
+        })
+    }
+
+    /// Rolls back every fragment written by this call if any of them fails.
+    fn write_fmt(&mut self, args: fmt::Arguments<'_>) -> fmt::Result {
+        let len = self.sixbit.len();
+        self.last_error = None;
+        let result = fmt::write(self, args);
+        if result.is_err() {
+            self.sixbit.truncate(len);
+        }
+        result
+    }
+}
+
+impl DecSixbit {
+    /// Returns a [`fmt::Write`] adapter that records the [`Error`] behind a failed write.
+    ///
+    /// Writing to a `DecSixbit` directly only reports `fmt::Error`; the error is kept in the adapter
+    /// rather than in the value so that `DecSixbit` stays 24 bytes.
+    #[inline]
+    pub fn writer(&mut self) -> SixbitWriter<'_> {
+        SixbitWriter {
+            sixbit: self,
+            last_error: None,
+        }
+    }
+}
+
+impl fmt::Write for DecSixbit {
+    /// Appends `s`, or returns `fmt::Error` and leaves the value unchanged if it contains characters
+    /// outside the range 32-95.
+    #[inline]
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.writer().write_str(s)
+    }
+
+    #[inline]
+    fn write_char(&mut self, c: char) -> fmt::Result {
+        self.writer().write_char(c)
+    }
+
+    /// Rolls back every fragment written by this call if any of them fails.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use dec_sixbit::DecSixbit;
+    /// use std::fmt::Write;
+    ///
+    /// let mut sixbit = DecSixbit::default();
+    /// write!(sixbit, "STEP {:03}", 7).unwrap();
+    /// assert_eq!(sixbit.to_string(), "STEP 007");
+    /// ```
+    #[inline]
+    fn write_fmt(&mut self, args: fmt::Arguments<'_>) -> fmt::Result {
+        self.writer().write_fmt(args)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{DecSixbit, Error};
+    use std::fmt::{self, Write};
+
+    #[test]
+    fn test_write_matches_format() {
+        let mut sixbit = DecSixbit::new("JOB").unwrap();
+        let mut expected = String::from("JOB");
+        for (step, tag) in [(1, 'X'), (42, 'Y'), (999, 'Z')] {
+            write!(sixbit, " STEP {:03} [{:>4}] [{:<3}]", step, step * 2, tag).unwrap();
+            expected += &format!(" STEP {:03} [{:>4}] [{:<3}]", step, step * 2, tag);
+        }
+        assert_eq!(sixbit, DecSixbit::new(&expected).unwrap());
+        assert_eq!(sixbit.as_bytes(), DecSixbit::new(&expected).unwrap().as_bytes());
+    }
+
+    #[test]
+    fn test_failed_write_is_rolled_back() {
+        let mut sixbit = DecSixbit::new("ABC ").unwrap();
+        let before = sixbit.clone();
+        // The valid prefix is written before the lowercase argument fails
+        let (count, word) = (1, "ok");
+        assert_eq!(write!(sixbit, "PREFIX {} {}", count, word), Err(fmt::Error));
+        assert_eq!(sixbit, before);
+        assert_eq!(sixbit.as_bytes(), before.as_bytes());
+        assert_eq!(sixbit.write_char('~'), Err(fmt::Error));
+        assert_eq!(sixbit, before);
+
+        let mut writer = sixbit.writer();
+        let (upper, lower) = ('A', 'a');
+        assert!(write!(writer, "{}{}", upper, lower).is_err());
+        assert_eq!(writer.last_error(), Some(Error::InvalidCharacter));
+        write!(writer, "{}", upper).unwrap();
+        assert_eq!(writer.last_error(), None);
+        assert_eq!(sixbit.to_string(), "ABC A");
+    }
+
+    #[test]
+    fn test_formatting_error_is_rolled_back() {
+        struct Failing;
+
+        impl fmt::Display for Failing {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("PARTIAL")?;
+                Err(fmt::Error)
+            }
+        }
+
+        let mut sixbit = DecSixbit::new("X").unwrap();
+        let mut writer = sixbit.writer();
+        assert!(write!(writer, "{}", Failing).is_err());
+        assert_eq!(writer.last_error(), None);
+        assert_eq!(sixbit.to_string(), "X");
+    }
+}
@@ -0,0 +1,96 @@
+//! A short deterministic run of the fuzz harnesses, so they keep compiling and passing without
+//! cargo-fuzz. Run with `cargo test --features fuzz-smoke --test fuzz_smoke`.
+#![cfg(feature = "fuzz-smoke")]
+
+#[path = "../fuzz/src/harness.rs"]
+mod harness;
+#[path = "../fuzz/src/reference.rs"]
+mod reference;
+
+const ITERATIONS: usize = 20_000;
+
+/// Inputs that exercise the boundaries between 3-byte groups and the trailing-space marker.
+const EDGE_CASES: &[&[u8]] = &[
+    b"",
+    b"\x00",
+    b"\x04\x00\x00\x00",
+    b"\x04\x00\x00\x00\x03",
+    b"\x04\x86\x28\xe4\x03",
+    b"\x01\xff",
+    b"\x40",
+    b"\xff\xff\xff",
+    b"\x05\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00",
+    b"\x08\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00",
+    b"ABC ",
+    b"HELLO",
+    b"hello",
+    "\u{c4}B".as_bytes(),
+];
+
+/// Lengths and payloads for the decode harness around the point where the bit count of the length overflows.
+const DECODE_EDGE_CASES: &[(u64, &[u8])] = &[
+    ((usize::MAX / 6) as u64, b""),
+    ((usize::MAX / 6 + 1) as u64, b"\x80\x00\x00"),
+    ((usize::MAX / 6 + 1) as u64, b"\x80"),
+    (1 << 40, b"\x00"),
+    (u64::MAX, b""),
+];
+
+/// Returns the input of the decode harness for `len` and `bytes`.
+fn length_prefixed(len: u64, bytes: &[u8]) -> Vec<u8> {
+    len.to_le_bytes().iter().chain(bytes).copied().collect()
+}
+
+/// Returns pseudo-random inputs from a fixed xorshift seed, half of them biased towards SIXBIT text.
+fn inputs() -> impl Iterator<Item = Vec<u8>> {
+    let mut state = 0x2545_f491_4f6c_dd1du64;
+    let mut next = move || {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        state
+    };
+    let random = (0..ITERATIONS).map(move |i| {
+        let len = (next() % 48) as usize;
+        (0..len)
+            .map(|_| match i % 2 {
+                0 => next() as u8,
+                _ => 32 + (next() % 66) as u8,
+            })
+            .collect()
+    });
+    EDGE_CASES.iter().map(|input| input.to_vec()).chain(random)
+}
+
+#[test]
+fn smoke_decode() {
+    inputs().for_each(|input| harness::decode(&input));
+    // Short lengths, which random 8-byte prefixes almost never produce
+    for input in inputs() {
+        let (len, bytes) = input.split_first().map_or((0, &[][..]), |(&len, bytes)| (len, bytes));
+        harness::decode(&length_prefixed(len.into(), bytes));
+    }
+    for &(len, bytes) in DECODE_EDGE_CASES {
+        harness::decode(&length_prefixed(len, bytes));
+    }
+}
+
+#[test]
+fn smoke_try_from_slice() {
+    inputs().for_each(|input| harness::try_from_slice(&input));
+}
+
+#[test]
+fn smoke_bincode() {
+    inputs().for_each(|input| harness::bincode(&input));
+    // Well-formed frames, which random bytes rarely produce
+    for text in ["", "A", "ABC ", "SYS:LOGIN.SAV"] {
+        let sixbit = dec_sixbit::DecSixbit::new(text).unwrap();
+        harness::bincode(&bincode::serialize(&sixbit).unwrap());
+    }
+}
+
+#[test]
+fn smoke_encode_differential() {
+    inputs().for_each(|input| harness::encode_differential(&input));
+}
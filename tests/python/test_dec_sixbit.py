@@ -0,0 +1,45 @@
+"""Tests of the Python bindings. Run with `maturin develop && pytest tests/python`."""
+
+import pytest
+
+import dec_sixbit
+from dec_sixbit import DecSixbit
+
+
+@pytest.mark.parametrize("text", ["", "A", "ABC ", "HELLO", "SYS:LOGIN.SAV"])
+def test_round_trip(text):
+    data, length = dec_sixbit.encode(text)
+    assert isinstance(data, bytes)
+    assert length == len(text)
+    assert dec_sixbit.decode(data, length) == text
+
+
+def test_encode_packs_six_bits_per_character():
+    assert dec_sixbit.encode("HELLO") == (b"\xa2\x5b\x2c\xbc", 5)
+
+
+def test_invalid_character_raises_value_error():
+    with pytest.raises(ValueError, match="invalid character"):
+        dec_sixbit.encode("lower")
+    with pytest.raises(ValueError, match="invalid character"):
+        DecSixbit("~")
+
+
+def test_inconsistent_length_raises_value_error():
+    with pytest.raises(ValueError, match="inconsistent"):
+        dec_sixbit.decode(b"\x00\x00", 4)
+
+
+def test_class():
+    sixbit = DecSixbit("ABCD    ")
+    assert str(sixbit) == "ABCD    "
+    assert repr(sixbit) == 'DecSixbit("ABCD    ")'
+    assert len(sixbit) == 8
+    assert sixbit.bytes == dec_sixbit.encode("ABCD    ")[0] + b"\x03"
+
+
+def test_equality_and_hash():
+    assert DecSixbit("HELLO") == DecSixbit("HELLO")
+    assert DecSixbit("HELLO") != DecSixbit("WORLD")
+    assert DecSixbit("HELLO") != "HELLO"
+    assert len({DecSixbit("HELLO"), DecSixbit("HELLO"), DecSixbit("WORLD")}) == 2